@@ -1,22 +1,30 @@
 use floating_ui_utils::{
-    Alignment, Axis, Coords, ElementRects, Placement, Side, get_alignment, get_alignment_axis,
-    get_axis_length, get_side, get_side_axis,
+    Alignment, Coords, ElementRects, Placement, Side, WritingMode, get_alignment,
+    get_alignment_axis, get_axis_length, get_side, is_axis_reversed,
 };
 
 /// Computes the `x` and `y` coordinates that will place the floating element next to a given reference element based on a `placement`.
 pub fn compute_coords_from_placement(
+    rects: &ElementRects,
+    placement: Placement,
+    rtl: Option<bool>,
+) -> Coords {
+    compute_coords_from_placement_with_writing_mode(rects, placement, rtl, None)
+}
+
+/// Version of [`compute_coords_from_placement`] which respects the writing mode.
+pub fn compute_coords_from_placement_with_writing_mode(
     ElementRects {
         reference,
         floating,
     }: &ElementRects,
     placement: Placement,
     rtl: Option<bool>,
+    writing_mode: Option<WritingMode>,
 ) -> Coords {
-    let side_axis = get_side_axis(placement);
     let alignment_axis = get_alignment_axis(placement);
     let align_length = get_axis_length(alignment_axis);
     let side = get_side(placement);
-    let is_vertical = side_axis == Axis::Y;
 
     let common_x = reference.x + reference.width / 2.0 - floating.width / 2.0;
     let common_y = reference.y + reference.height / 2.0 - floating.height / 2.0;
@@ -41,7 +49,7 @@ pub fn compute_coords_from_placement(
         },
     };
 
-    let rtl = rtl.unwrap_or(false);
+    let is_reversed = is_axis_reversed(alignment_axis, rtl, writing_mode);
 
     if let Some(alignment) = get_alignment(placement) {
         coords.update_axis(alignment_axis, |value| {
@@ -52,7 +60,7 @@ pub fn compute_coords_from_placement(
                     } else {
                         -1.0
                     })
-                    * (if rtl && is_vertical { -1.0 } else { 1.0 })
+                    * (if is_reversed { -1.0 } else { 1.0 })
         });
     }
 
@@ -175,4 +183,57 @@ mod tests {
             Coords { x: -50.0, y: 50.0 }
         )
     }
+
+    #[test]
+    fn test_vertical_rl() {
+        assert_eq!(
+            compute_coords_from_placement_with_writing_mode(
+                &ELEMENT_RECTS,
+                Placement::TopStart,
+                None,
+                Some(WritingMode::VerticalRl)
+            ),
+            Coords { x: 50.0, y: -50.0 }
+        );
+        assert_eq!(
+            compute_coords_from_placement_with_writing_mode(
+                &ELEMENT_RECTS,
+                Placement::RightStart,
+                None,
+                Some(WritingMode::VerticalRl)
+            ),
+            Coords { x: 100.0, y: 0.0 }
+        );
+        assert_eq!(
+            compute_coords_from_placement_with_writing_mode(
+                &ELEMENT_RECTS,
+                Placement::RightStart,
+                Some(true),
+                Some(WritingMode::VerticalRl)
+            ),
+            Coords { x: 100.0, y: 50.0 }
+        );
+    }
+
+    #[test]
+    fn test_vertical_lr() {
+        assert_eq!(
+            compute_coords_from_placement_with_writing_mode(
+                &ELEMENT_RECTS,
+                Placement::BottomStart,
+                Some(true),
+                Some(WritingMode::VerticalLr)
+            ),
+            Coords { x: 0.0, y: 100.0 }
+        );
+        assert_eq!(
+            compute_coords_from_placement_with_writing_mode(
+                &ELEMENT_RECTS,
+                Placement::LeftEnd,
+                Some(true),
+                Some(WritingMode::VerticalLr)
+            ),
+            Coords { x: -50.0, y: 0.0 }
+        );
+    }
 }
@@ -1,13 +1,24 @@
-use floating_ui_utils::{Coords, ElementOrVirtual, Placement, Strategy};
+use alloc::{boxed::Box, vec::Vec};
+use core::future::Future;
+use core::pin::pin;
+use core::task::{Context, Poll, Waker};
 
-use crate::compute_coords_from_placement::compute_coords_from_placement;
+use floating_ui_utils::{
+    Coords, ElementOrVirtual, ElementRects, LogicalPlacement, Placement, Strategy, WritingMode,
+};
+
+use crate::compute_coords_from_placement::compute_coords_from_placement_with_writing_mode;
+use crate::error::{ComputePositionError, MiddlewareDataError};
+use crate::fallible_platform::FalliblePlatform;
 use crate::types::{
-    ComputePositionConfig, ComputePositionReturn, Elements, GetElementRectsArgs, MiddlewareData,
-    MiddlewareReturn, MiddlewareState, Reset, ResetRects,
+    AsyncComputePositionConfig, AsyncMiddleware, AsyncMiddlewareState, AsyncPlatform,
+    ComputePositionConfig, ComputePositionReturn, Elements, GetElementRectsArgs, Middleware,
+    MiddlewareData, MiddlewareReturn, MiddlewareState, MiddlewareTraceStep, Platform, Reset,
+    ResetRects,
 };
 
 /// Maximum number of resets that can occur before bailing to avoid infinite reset loops.
-const MAX_RESET_COUNT: i32 = 50;
+const MAX_RESET_COUNT: usize = 50;
 
 /// Computes the `x` and `y` coordinates that will place the floating element next to a given reference element.
 ///
@@ -19,74 +30,312 @@ pub fn compute_position<Element: Clone, Window: Clone>(
     floating: &Element,
     config: ComputePositionConfig<Element, Window>,
 ) -> ComputePositionReturn {
-    let placement = config.placement.unwrap_or(Placement::Bottom);
-    let strategy = config.strategy.unwrap_or(Strategy::Absolute);
-    let platform = config.platform;
-    let middlewares = config.middleware.unwrap_or_default();
+    compute_position_inner(reference, floating, config).unwrap_or_else(|error| panic!("{error}"))
+}
 
-    let rtl = platform.is_rtl(floating);
+/// Computes the `x` and `y` coordinates that will place the floating element next to a given reference element, returning an error instead of panicking.
+///
+/// The fallible `try_*` methods of the platform are used. The first platform error is returned, even if the middleware continued with placeholder measurements.
+///
+/// See [`Platform`][`crate::types::Platform`].
+pub fn try_compute_position<Element: Clone, Window: Clone>(
+    reference: ElementOrVirtual<Element>,
+    floating: &Element,
+    config: ComputePositionConfig<Element, Window>,
+) -> Result<ComputePositionReturn, ComputePositionError> {
+    let platform = FalliblePlatform::new(config.platform);
 
-    let mut rects = platform.get_element_rects(GetElementRectsArgs {
-        reference: reference.clone(),
+    let result = compute_position_inner(
+        reference,
         floating,
-        strategy,
-    });
-    let Coords { mut x, mut y } = compute_coords_from_placement(&rects, placement, rtl);
+        ComputePositionConfig {
+            platform: &platform,
+            placement: config.placement,
+            logical_placement: config.logical_placement,
+            strategy: config.strategy,
+            middleware: config.middleware,
+            trace: config.trace,
+        },
+    );
+
+    if let Some(error) = platform.into_error() {
+        return Err(error.into());
+    }
+
+    Ok(result?)
+}
+
+fn compute_position_inner<Element: Clone, Window: Clone>(
+    reference: ElementOrVirtual<Element>,
+    floating: &Element,
+    config: ComputePositionConfig<Element, Window>,
+) -> Result<ComputePositionReturn, MiddlewareDataError> {
+    let options = LoopOptions {
+        placement: config.placement,
+        logical_placement: config.logical_placement,
+        strategy: config.strategy,
+        trace: config.trace,
+    };
+    let driver = SyncDriver {
+        platform: config.platform,
+        middleware: config.middleware.unwrap_or_default(),
+    };
+
+    // The synchronous driver never suspends, so a single poll completes the loop.
+    let future = pin!(compute_position_loop(reference, floating, options, &driver));
+    match future.poll(&mut Context::from_waker(Waker::noop())) {
+        Poll::Ready(result) => result,
+        Poll::Pending => unreachable!("Synchronous platform should not suspend."),
+    }
+}
+
+/// Computes the `x` and `y` coordinates that will place the floating element next to a given reference element, awaiting the measurements of an asynchronous platform.
+///
+/// This export does not have any `platform` interface logic. You will need to write one for the platform you are using Floating UI with.
+///
+/// See [`AsyncPlatform`][`crate::types::AsyncPlatform`].
+pub async fn compute_position_async<Element: Clone, Window: Clone>(
+    reference: ElementOrVirtual<'_, Element>,
+    floating: &Element,
+    config: AsyncComputePositionConfig<'_, Element, Window>,
+) -> ComputePositionReturn {
+    try_compute_position_async(reference, floating, config)
+        .await
+        .unwrap_or_else(|error| panic!("{error}"))
+}
+
+/// Computes the `x` and `y` coordinates that will place the floating element next to a given reference element, awaiting the measurements of an asynchronous platform and returning an error instead of panicking.
+///
+/// See [`AsyncPlatform`][`crate::types::AsyncPlatform`].
+pub async fn try_compute_position_async<Element: Clone, Window: Clone>(
+    reference: ElementOrVirtual<'_, Element>,
+    floating: &Element,
+    config: AsyncComputePositionConfig<'_, Element, Window>,
+) -> Result<ComputePositionReturn, ComputePositionError> {
+    let options = LoopOptions {
+        placement: config.placement,
+        logical_placement: config.logical_placement,
+        strategy: config.strategy,
+        trace: config.trace,
+    };
+    let driver = AsyncDriver {
+        platform: config.platform,
+        middleware: config.middleware.unwrap_or_default(),
+    };
+
+    Ok(compute_position_loop(reference, floating, options, &driver).await?)
+}
+
+/// Options shared by [`ComputePositionConfig`] and [`AsyncComputePositionConfig`].
+struct LoopOptions {
+    placement: Option<Placement>,
+    logical_placement: Option<LogicalPlacement>,
+    strategy: Option<Strategy>,
+    trace: Option<bool>,
+}
+
+/// State passed to a middleware, without the platform.
+struct LoopState<'a, Element: Clone + 'static> {
+    x: f64,
+    y: f64,
+    initial_placement: Placement,
+    placement: Placement,
+    strategy: Strategy,
+    middleware_data: &'a MiddlewareData,
+    elements: Elements<'a, Element>,
+    rects: &'a ElementRects,
+}
+
+/// Platform and middleware used by [`compute_position_loop`], either synchronous or asynchronous.
+trait Driver<Element: Clone + 'static> {
+    async fn is_rtl(&self, floating: &Element) -> Option<bool>;
+
+    async fn get_writing_mode(&self, floating: &Element) -> Option<WritingMode>;
+
+    async fn get_element_rects(&self, args: GetElementRectsArgs<'_, Element>) -> ElementRects;
+
+    fn middleware_len(&self) -> usize;
+
+    fn middleware_name(&self, index: usize) -> &'static str;
+
+    async fn compute_middleware(
+        &self,
+        index: usize,
+        state: LoopState<'_, Element>,
+    ) -> MiddlewareReturn;
+}
+
+struct SyncDriver<'a, Element: Clone + 'static, Window: Clone + 'static> {
+    platform: &'a dyn Platform<Element, Window>,
+    middleware: Vec<Box<dyn Middleware<Element, Window>>>,
+}
+
+impl<Element: Clone, Window: Clone> Driver<Element> for SyncDriver<'_, Element, Window> {
+    async fn is_rtl(&self, floating: &Element) -> Option<bool> {
+        self.platform.is_rtl(floating)
+    }
+
+    async fn get_writing_mode(&self, floating: &Element) -> Option<WritingMode> {
+        self.platform.get_writing_mode(floating)
+    }
+
+    async fn get_element_rects(&self, args: GetElementRectsArgs<'_, Element>) -> ElementRects {
+        self.platform.get_element_rects(args)
+    }
+
+    fn middleware_len(&self) -> usize {
+        self.middleware.len()
+    }
+
+    fn middleware_name(&self, index: usize) -> &'static str {
+        self.middleware[index].name()
+    }
+
+    async fn compute_middleware(
+        &self,
+        index: usize,
+        state: LoopState<'_, Element>,
+    ) -> MiddlewareReturn {
+        self.middleware[index].compute(MiddlewareState {
+            x: state.x,
+            y: state.y,
+            initial_placement: state.initial_placement,
+            placement: state.placement,
+            strategy: state.strategy,
+            middleware_data: state.middleware_data,
+            elements: state.elements,
+            rects: state.rects,
+            platform: self.platform,
+        })
+    }
+}
+
+struct AsyncDriver<'a, Element: Clone + 'static, Window: Clone + 'static> {
+    platform: &'a dyn AsyncPlatform<Element, Window>,
+    middleware: Vec<Box<dyn AsyncMiddleware<Element, Window>>>,
+}
+
+impl<Element: Clone, Window: Clone> Driver<Element> for AsyncDriver<'_, Element, Window> {
+    async fn is_rtl(&self, floating: &Element) -> Option<bool> {
+        self.platform.is_rtl(floating).await
+    }
+
+    async fn get_writing_mode(&self, floating: &Element) -> Option<WritingMode> {
+        self.platform.get_writing_mode(floating).await
+    }
+
+    async fn get_element_rects(&self, args: GetElementRectsArgs<'_, Element>) -> ElementRects {
+        self.platform.get_element_rects(args).await
+    }
+
+    fn middleware_len(&self) -> usize {
+        self.middleware.len()
+    }
+
+    fn middleware_name(&self, index: usize) -> &'static str {
+        self.middleware[index].name()
+    }
+
+    async fn compute_middleware(
+        &self,
+        index: usize,
+        state: LoopState<'_, Element>,
+    ) -> MiddlewareReturn {
+        self.middleware[index]
+            .compute(AsyncMiddlewareState {
+                x: state.x,
+                y: state.y,
+                initial_placement: state.initial_placement,
+                placement: state.placement,
+                strategy: state.strategy,
+                middleware_data: state.middleware_data,
+                elements: state.elements,
+                rects: state.rects,
+                platform: self.platform,
+            })
+            .await
+    }
+}
+
+async fn compute_position_loop<Element: Clone>(
+    reference: ElementOrVirtual<'_, Element>,
+    floating: &Element,
+    options: LoopOptions,
+    driver: &impl Driver<Element>,
+) -> Result<ComputePositionReturn, MiddlewareDataError> {
+    let strategy = options.strategy.unwrap_or(Strategy::Absolute);
+    let mut trace = options.trace.unwrap_or(false).then(Vec::new);
+
+    let rtl = driver.is_rtl(floating).await;
+    let writing_mode = driver.get_writing_mode(floating).await;
+
+    let placement = match options.logical_placement {
+        Some(logical_placement) => {
+            logical_placement.resolve(writing_mode.unwrap_or_default(), rtl.unwrap_or(false))
+        }
+        None => options.placement.unwrap_or(Placement::Bottom),
+    };
+
+    let mut rects = driver
+        .get_element_rects(GetElementRectsArgs {
+            reference: reference.clone(),
+            floating,
+            strategy,
+        })
+        .await;
+    let Coords { mut x, mut y } =
+        compute_coords_from_placement_with_writing_mode(&rects, placement, rtl, writing_mode);
     let mut stateful_placement = placement;
     let mut middleware_data = MiddlewareData::default();
     let mut reset_count = 0;
 
     let mut i = 0;
-    while i < middlewares.len() {
-        let middleware = &middlewares[i];
+    while i < driver.middleware_len() {
+        let name = driver.middleware_name(i);
+        let input = Coords { x, y };
 
         let MiddlewareReturn {
             x: next_x,
             y: next_y,
             data,
             reset,
-        } = middleware.compute(MiddlewareState {
-            x,
-            y,
-            initial_placement: placement,
-            placement: stateful_placement,
-            strategy,
-            middleware_data: &middleware_data,
-            rects: &rects,
-            platform,
-            elements: Elements {
-                reference: reference.clone(),
-                floating,
-            },
-        });
+        } = driver
+            .compute_middleware(
+                i,
+                LoopState {
+                    x,
+                    y,
+                    initial_placement: placement,
+                    placement: stateful_placement,
+                    strategy,
+                    middleware_data: &middleware_data,
+                    rects: &rects,
+                    elements: Elements {
+                        reference: reference.clone(),
+                        floating,
+                    },
+                },
+            )
+            .await;
 
         x = next_x.unwrap_or(x);
         y = next_y.unwrap_or(y);
 
-        if let Some(data) = data {
-            let existing_data = middleware_data.get(middleware.name());
-
-            let new_data = match existing_data {
-                Some(existing_data) => {
-                    let mut a = existing_data
-                        .as_object()
-                        .expect("Existing data should be an object.")
-                        .to_owned();
-
-                    let mut b = data
-                        .as_object()
-                        .expect("New data should be an object.")
-                        .to_owned();
-
-                    b.retain(|_, v| !v.is_null());
-                    a.extend(b);
-
-                    serde_json::Value::Object(a)
-                }
-                None => data,
-            };
+        if let Some(trace) = trace.as_mut() {
+            trace.push(MiddlewareTraceStep {
+                name,
+                index: i,
+                reset_count,
+                placement: stateful_placement,
+                input,
+                output: Coords { x, y },
+                data: data.clone(),
+                reset: reset.clone(),
+            });
+        }
 
-            middleware_data.set(middleware.name(), new_data);
+        if let Some(data) = data {
+            middleware_data.try_merge(name, data)?;
         }
 
         if let Some(reset) = reset
@@ -103,11 +352,15 @@ pub fn compute_position<Element: Clone, Window: Clone>(
 
                     if let Some(reset_rects) = value.rects {
                         rects = match reset_rects {
-                            ResetRects::True => platform.get_element_rects(GetElementRectsArgs {
-                                reference: reference.clone(),
-                                floating,
-                                strategy,
-                            }),
+                            ResetRects::True => {
+                                driver
+                                    .get_element_rects(GetElementRectsArgs {
+                                        reference: reference.clone(),
+                                        floating,
+                                        strategy,
+                                    })
+                                    .await
+                            }
                             ResetRects::Value(element_rects) => element_rects,
                         }
                     }
@@ -115,7 +368,12 @@ pub fn compute_position<Element: Clone, Window: Clone>(
                     let Coords {
                         x: next_x,
                         y: next_y,
-                    } = compute_coords_from_placement(&rects, stateful_placement, rtl);
+                    } = compute_coords_from_placement_with_writing_mode(
+                        &rects,
+                        stateful_placement,
+                        rtl,
+                        writing_mode,
+                    );
                     x = next_x;
                     y = next_y;
                 }
@@ -128,21 +386,38 @@ pub fn compute_position<Element: Clone, Window: Clone>(
         i += 1;
     }
 
-    ComputePositionReturn {
+    Ok(ComputePositionReturn {
         x,
         y,
         placement: stateful_placement,
         strategy,
         middleware_data,
-    }
+        trace,
+    })
 }
 
 #[cfg(test)]
 mod tests {
+    use std::collections::BTreeMap;
+
     use serde_json::json;
 
-    use crate::test_utils::{FLOATING, PLATFORM, REFERENCE};
-    use crate::types::Middleware;
+    use floating_ui_utils::{Dimensions, ElementRects, Rect, SideObject};
+
+    use crate::detect_overflow::{DetectOverflowOptions, detect_overflow, detect_overflow_async};
+    use crate::error::PlatformError;
+    use crate::middleware::{
+        ARROW_NAME, ApplyState, Arrow, ArrowData, ArrowOptions, Flip, FlipOptions, Offset,
+        OffsetOptions, Shift, ShiftOptions, Size, SizeOptions,
+    };
+    use crate::test_utils::{
+        ASYNC_PLATFORM, Element, FLOATING, FLOATING_RECT, PLATFORM, REFERENCE, Window, block_on,
+        measure,
+    };
+    use crate::types::{
+        AsyncMiddleware, AsyncPlatform, GetClippingRectArgs, LocalBoxFuture, Middleware,
+        MiddlewareDataValue, Platform, ResetValue,
+    };
 
     use super::*;
 
@@ -162,7 +437,7 @@ mod tests {
                 MiddlewareReturn {
                     x: None,
                     y: None,
-                    data: Some(json!({"property": true})),
+                    data: Some(json!({"property": true}).into()),
                     reset: None,
                 }
             }
@@ -174,14 +449,17 @@ mod tests {
             placement,
             strategy,
             middleware_data,
+            ..
         } = compute_position(
             (&REFERENCE).into(),
             &FLOATING,
             ComputePositionConfig {
                 platform: &PLATFORM,
                 placement: Some(Placement::Top),
+                logical_placement: None,
                 strategy: None,
                 middleware: Some(vec![Box::new(CustomMiddleware {})]),
+                trace: None,
             },
         );
 
@@ -190,7 +468,7 @@ mod tests {
         assert_eq!(placement, Placement::Top);
         assert_eq!(strategy, Strategy::Absolute);
         assert_eq!(
-            middleware_data.get("custom"),
+            middleware_data.get_json("custom"),
             Some(&json!({"property": true}))
         );
     }
@@ -226,8 +504,10 @@ mod tests {
             ComputePositionConfig {
                 platform: &PLATFORM,
                 placement: None,
+                logical_placement: None,
                 strategy: None,
                 middleware: None,
+                trace: None,
             },
         );
 
@@ -237,8 +517,10 @@ mod tests {
             ComputePositionConfig {
                 platform: &PLATFORM,
                 placement: None,
+                logical_placement: None,
                 strategy: None,
                 middleware: Some(vec![Box::new(TestMiddleware {})]),
+                trace: None,
             },
         );
 
@@ -261,7 +543,7 @@ mod tests {
                 MiddlewareReturn {
                     x: None,
                     y: None,
-                    data: Some(json!({"hello": true})),
+                    data: Some(json!({"hello": true}).into()),
                     reset: None,
                 }
             }
@@ -275,11 +557,486 @@ mod tests {
             ComputePositionConfig {
                 platform: &PLATFORM,
                 placement: None,
+                logical_placement: None,
                 strategy: None,
                 middleware: Some(vec![Box::new(TestMiddleware {})]),
+                trace: None,
             },
         );
 
-        assert_eq!(middleware_data.get("test"), Some(&json!({"hello": true})));
+        assert_eq!(
+            middleware_data.get_json("test"),
+            Some(&json!({"hello": true}))
+        );
+        assert_eq!(
+            middleware_data.try_get_json_as::<BTreeMap<String, bool>>("test"),
+            Ok(Some(BTreeMap::from([("hello".into(), true)])))
+        );
+        assert!(matches!(
+            middleware_data.try_get_json_as::<bool>("test"),
+            Err(MiddlewareDataError::InvalidData { name, .. }) if name == "test"
+        ));
+        assert_eq!(middleware_data.try_get_json_as::<bool>("missing"), Ok(None));
+    }
+
+    #[test]
+    fn test_try_compute_position() {
+        #[derive(Debug)]
+        struct DetachedPlatform {}
+
+        impl Platform<Element, Window> for DetachedPlatform {
+            fn get_element_rects(&self, args: GetElementRectsArgs<Element>) -> ElementRects {
+                PLATFORM.get_element_rects(args)
+            }
+
+            fn get_clipping_rect(&self, args: GetClippingRectArgs<Element>) -> Rect {
+                PLATFORM.get_clipping_rect(args)
+            }
+
+            fn get_dimensions(&self, element: &Element) -> Dimensions {
+                PLATFORM.get_dimensions(element)
+            }
+
+            fn try_get_clipping_rect(
+                &self,
+                _args: GetClippingRectArgs<Element>,
+            ) -> Result<Rect, PlatformError> {
+                Err(PlatformError::DetachedElement)
+            }
+
+            fn detect_overflow(
+                &self,
+                state: MiddlewareState<Element, Window>,
+                options: DetectOverflowOptions<Element>,
+            ) -> SideObject {
+                detect_overflow(state, options)
+            }
+        }
+
+        #[derive(Clone, PartialEq)]
+        struct InvalidDataMiddleware {}
+
+        impl<Element: Clone + 'static, Window: Clone + 'static> Middleware<Element, Window>
+            for InvalidDataMiddleware
+        {
+            fn name(&self) -> &'static str {
+                "invalid"
+            }
+
+            fn compute(&self, _state: MiddlewareState<Element, Window>) -> MiddlewareReturn {
+                MiddlewareReturn {
+                    x: None,
+                    y: None,
+                    data: Some(json!(true).into()),
+                    reset: None,
+                }
+            }
+        }
+
+        assert_eq!(
+            try_compute_position(
+                (&REFERENCE).into(),
+                &FLOATING,
+                ComputePositionConfig::new(&DetachedPlatform {}).placement(Placement::Top),
+            ),
+            Ok(compute_position(
+                (&REFERENCE).into(),
+                &FLOATING,
+                ComputePositionConfig::new(&PLATFORM).placement(Placement::Top),
+            ))
+        );
+
+        assert_eq!(
+            try_compute_position(
+                (&REFERENCE).into(),
+                &FLOATING,
+                ComputePositionConfig::new(&DetachedPlatform {})
+                    .middleware(vec![Box::new(Flip::new(FlipOptions::default()))]),
+            ),
+            Err(ComputePositionError::Platform(
+                PlatformError::DetachedElement
+            ))
+        );
+
+        assert_eq!(
+            try_compute_position(
+                (&REFERENCE).into(),
+                &FLOATING,
+                ComputePositionConfig::new(&PLATFORM).middleware(vec![
+                    Box::new(InvalidDataMiddleware {}),
+                    Box::new(InvalidDataMiddleware {}),
+                ]),
+            ),
+            Err(ComputePositionError::MiddlewareData(
+                MiddlewareDataError::NotAnObject {
+                    name: "invalid".into()
+                }
+            ))
+        );
+
+        assert_eq!(
+            block_on(try_compute_position_async(
+                (&REFERENCE).into(),
+                &FLOATING,
+                AsyncComputePositionConfig::new(&ASYNC_PLATFORM).middleware(vec![
+                    Box::new(InvalidDataMiddleware {}),
+                    Box::new(InvalidDataMiddleware {}),
+                ]),
+            )),
+            Err(ComputePositionError::MiddlewareData(
+                MiddlewareDataError::NotAnObject {
+                    name: "invalid".into()
+                }
+            ))
+        );
+    }
+
+    #[test]
+    fn test_typed_middleware_data() {
+        #[derive(Clone, Debug, PartialEq)]
+        struct TestData {
+            a: Option<bool>,
+            b: Option<bool>,
+        }
+
+        impl MiddlewareDataValue for TestData {
+            fn merge(&mut self, data: Self) {
+                self.a = data.a.or(self.a);
+                self.b = data.b.or(self.b);
+            }
+        }
+
+        #[derive(Clone, PartialEq)]
+        struct TestMiddleware {
+            data: TestData,
+        }
+
+        impl<Element: Clone + 'static, Window: Clone + 'static> Middleware<Element, Window>
+            for TestMiddleware
+        {
+            fn name(&self) -> &'static str {
+                "test"
+            }
+
+            fn compute(&self, _state: MiddlewareState<Element, Window>) -> MiddlewareReturn {
+                MiddlewareReturn {
+                    x: None,
+                    y: None,
+                    data: Some(self.data.clone().into()),
+                    reset: None,
+                }
+            }
+        }
+
+        let ComputePositionReturn {
+            middleware_data, ..
+        } = compute_position(
+            (&REFERENCE).into(),
+            &FLOATING,
+            ComputePositionConfig::new(&PLATFORM).middleware(vec![
+                Box::new(TestMiddleware {
+                    data: TestData {
+                        a: Some(true),
+                        b: None,
+                    },
+                }),
+                Box::new(TestMiddleware {
+                    data: TestData {
+                        a: None,
+                        b: Some(false),
+                    },
+                }),
+            ]),
+        );
+
+        assert_eq!(
+            middleware_data.get_typed::<TestData>(),
+            Some(&TestData {
+                a: Some(true),
+                b: Some(false)
+            })
+        );
+        assert_eq!(middleware_data.get_json("test"), None);
+    }
+
+    #[test]
+    #[expect(deprecated)]
+    fn test_deprecated_middleware_data() {
+        let ComputePositionReturn {
+            mut middleware_data,
+            ..
+        } = compute_position(
+            (&REFERENCE).into(),
+            &FLOATING,
+            ComputePositionConfig::new(&PLATFORM)
+                .middleware(vec![Box::new(Arrow::new(ArrowOptions::new(FLOATING)))]),
+        );
+
+        assert!(middleware_data.get_typed::<ArrowData>().is_some());
+        assert_eq!(
+            middleware_data.get_as::<ArrowData>(ARROW_NAME).as_ref(),
+            middleware_data.get_typed::<ArrowData>()
+        );
+
+        middleware_data.set("custom", json!({"property": true}));
+        assert_eq!(
+            middleware_data.get("custom"),
+            Some(&json!({"property": true}))
+        );
+        assert_eq!(
+            middleware_data.get_as::<BTreeMap<String, bool>>("custom"),
+            Some(BTreeMap::from([("property".into(), true)]))
+        );
+    }
+
+    #[test]
+    fn test_trace() {
+        let ComputePositionReturn { trace, .. } = compute_position(
+            (&REFERENCE).into(),
+            &FLOATING,
+            ComputePositionConfig::new(&PLATFORM)
+                .placement(Placement::TopStart)
+                .middleware(vec![
+                    Box::new(Offset::new(OffsetOptions::Value(10.0))),
+                    Box::new(Flip::new(FlipOptions::default())),
+                ])
+                .trace(true),
+        );
+
+        let trace = trace.expect("Trace should be recorded.");
+        assert_eq!(
+            trace
+                .iter()
+                .map(|step| (step.name, step.index, step.reset_count, step.placement))
+                .collect::<Vec<_>>(),
+            vec![
+                ("offset", 0, 0, Placement::TopStart),
+                ("flip", 1, 0, Placement::TopStart),
+                ("offset", 0, 1, Placement::TopEnd),
+                ("flip", 1, 1, Placement::TopEnd),
+                ("offset", 0, 2, Placement::BottomStart),
+                ("flip", 1, 2, Placement::BottomStart),
+            ]
+        );
+        assert_eq!(trace[0].input, Coords { x: 0.0, y: -50.0 });
+        assert_eq!(trace[0].output, Coords { x: 0.0, y: -60.0 });
+        assert!(trace[0].reset.is_none());
+        assert!(matches!(
+            trace[1].reset,
+            Some(Reset::Value(ResetValue {
+                placement: Some(Placement::TopEnd),
+                ..
+            }))
+        ));
+        assert!(matches!(
+            trace[3].reset,
+            Some(Reset::Value(ResetValue {
+                placement: Some(Placement::BottomStart),
+                ..
+            }))
+        ));
+        assert_eq!(trace[4].input, Coords { x: 0.0, y: 100.0 });
+        assert_eq!(trace[4].output, Coords { x: 0.0, y: 110.0 });
+        assert!(trace[5].reset.is_none());
+
+        let ComputePositionReturn { trace, .. } = compute_position(
+            (&REFERENCE).into(),
+            &FLOATING,
+            ComputePositionConfig::new(&PLATFORM),
+        );
+        assert!(trace.is_none());
+    }
+
+    #[test]
+    fn test_async() {
+        let sync_return = compute_position(
+            (&REFERENCE).into(),
+            &FLOATING,
+            ComputePositionConfig::new(&PLATFORM).placement(Placement::RightStart),
+        );
+
+        let async_return = block_on(compute_position_async(
+            (&REFERENCE).into(),
+            &FLOATING,
+            AsyncComputePositionConfig::new(&ASYNC_PLATFORM).placement(Placement::RightStart),
+        ));
+
+        assert_eq!(async_return, sync_return);
+    }
+
+    #[test]
+    fn test_async_sync_middleware() {
+        let sync_return = compute_position(
+            (&REFERENCE).into(),
+            &FLOATING,
+            ComputePositionConfig::new(&PLATFORM)
+                .placement(Placement::TopStart)
+                .middleware(vec![
+                    Box::new(Offset::new(OffsetOptions::Value(10.0))),
+                    Box::new(Flip::new(FlipOptions::default())),
+                    Box::new(Shift::new(ShiftOptions::default())),
+                    Box::new(Arrow::new(ArrowOptions::new(FLOATING))),
+                ]),
+        );
+
+        let async_return = block_on(compute_position_async(
+            (&REFERENCE).into(),
+            &FLOATING,
+            AsyncComputePositionConfig::new(&ASYNC_PLATFORM)
+                .placement(Placement::TopStart)
+                .middleware(vec![
+                    Box::new(Offset::new(OffsetOptions::Value(10.0))),
+                    Box::new(Flip::new(FlipOptions::default())),
+                    Box::new(Shift::new(ShiftOptions::default())),
+                    Box::new(Arrow::new(ArrowOptions::new(FLOATING))),
+                ]),
+        ));
+
+        assert_eq!(async_return, sync_return);
+        assert_eq!(async_return.placement, Placement::BottomStart);
+    }
+
+    #[test]
+    fn test_async_sync_middleware_runs() {
+        use core::sync::atomic::{AtomicUsize, Ordering};
+
+        static RUNS: AtomicUsize = AtomicUsize::new(0);
+
+        #[derive(Clone, PartialEq)]
+        struct CountingMiddleware {}
+
+        impl<Element: Clone + PartialEq + 'static, Window: Clone + PartialEq + 'static>
+            Middleware<Element, Window> for CountingMiddleware
+        {
+            fn name(&self) -> &'static str {
+                "counting"
+            }
+
+            fn compute(&self, state: MiddlewareState<Element, Window>) -> MiddlewareReturn {
+                RUNS.fetch_add(1, Ordering::SeqCst);
+
+                let SideObject { top, .. } = state
+                    .platform
+                    .detect_overflow(state.clone(), DetectOverflowOptions::default());
+
+                MiddlewareReturn {
+                    x: None,
+                    y: Some(state.y + top),
+                    data: None,
+                    reset: None,
+                }
+            }
+        }
+
+        let ComputePositionReturn { y, .. } = block_on(compute_position_async(
+            (&REFERENCE).into(),
+            &FLOATING,
+            AsyncComputePositionConfig::new(&ASYNC_PLATFORM)
+                .placement(Placement::Top)
+                .middleware(vec![Box::new(CountingMiddleware {})]),
+        ));
+
+        assert_eq!(y, 0.0);
+        // All independent measurements are awaited after the first run, instead of one per run.
+        assert_eq!(RUNS.load(Ordering::SeqCst), 2);
+    }
+
+    #[test]
+    fn test_async_size_apply() {
+        use std::sync::Mutex;
+
+        static APPLIED: Mutex<Vec<(f64, f64)>> = Mutex::new(vec![]);
+
+        /// Async platform where the floating element keeps its size, so [`Size`] does not reset.
+        #[derive(Debug)]
+        struct SizedAsyncPlatform {}
+
+        impl AsyncPlatform<Element, Window> for SizedAsyncPlatform {
+            fn get_element_rects<'a>(
+                &'a self,
+                args: GetElementRectsArgs<'a, Element>,
+            ) -> LocalBoxFuture<'a, ElementRects> {
+                ASYNC_PLATFORM.get_element_rects(args)
+            }
+
+            fn get_clipping_rect<'a>(
+                &'a self,
+                args: GetClippingRectArgs<'a, Element>,
+            ) -> LocalBoxFuture<'a, Rect> {
+                ASYNC_PLATFORM.get_clipping_rect(args)
+            }
+
+            fn get_dimensions<'a>(
+                &'a self,
+                _element: &'a Element,
+            ) -> LocalBoxFuture<'a, Dimensions> {
+                measure(Dimensions {
+                    width: FLOATING_RECT.width,
+                    height: FLOATING_RECT.height,
+                })
+            }
+        }
+
+        fn apply(state: ApplyState<Element, Window>) {
+            APPLIED
+                .lock()
+                .expect("Lock should not be poisoned.")
+                .push((state.available_width, state.available_height));
+        }
+
+        block_on(compute_position_async(
+            (&REFERENCE).into(),
+            &FLOATING,
+            AsyncComputePositionConfig::new(&SizedAsyncPlatform {})
+                .placement(Placement::Bottom)
+                .middleware(vec![Box::new(Size::new(SizeOptions::new().apply(&apply)))]),
+        ));
+
+        // Runs answered with placeholder geometry must not reach `apply`.
+        assert_eq!(
+            *APPLIED.lock().expect("Lock should not be poisoned."),
+            vec![(100.0, 900.0)]
+        );
+    }
+
+    #[test]
+    fn test_async_middleware() {
+        struct TestMiddleware {}
+
+        impl<Element: Clone + PartialEq + 'static, Window: Clone + PartialEq + 'static>
+            AsyncMiddleware<Element, Window> for TestMiddleware
+        {
+            fn name(&self) -> &'static str {
+                "test"
+            }
+
+            fn compute<'a>(
+                &'a self,
+                state: AsyncMiddlewareState<'a, Element, Window>,
+            ) -> LocalBoxFuture<'a, MiddlewareReturn> {
+                Box::pin(async move {
+                    let SideObject { top, .. } =
+                        detect_overflow_async(state.clone(), DetectOverflowOptions::default())
+                            .await;
+
+                    MiddlewareReturn {
+                        x: None,
+                        y: Some(state.y + top),
+                        data: None,
+                        reset: None,
+                    }
+                })
+            }
+        }
+
+        let ComputePositionReturn { x, y, .. } = block_on(compute_position_async(
+            (&REFERENCE).into(),
+            &FLOATING,
+            AsyncComputePositionConfig::new(&ASYNC_PLATFORM)
+                .placement(Placement::Top)
+                .middleware(vec![Box::new(TestMiddleware {})]),
+        ));
+
+        assert_eq!((x, y), (25.0, 0.0));
     }
 }
@@ -2,18 +2,24 @@ use floating_ui_utils::{
     Coords, ElementOrVirtual, OwnedElementOrWindow, Padding, Rect, SideObject, get_padding_object,
     rect_to_client_rect,
 };
+use serde::{Deserialize, Serialize};
 
+use crate::sync_adapter::{run_sync, to_sync_state};
 use crate::types::{
-    Boundary, ConvertOffsetParentRelativeRectToViewportRelativeRectArgs, ElementContext, Elements,
-    GetClippingRectArgs, MiddlewareState, RootBoundary,
+    AsyncMiddlewareState, Boundary, ConvertOffsetParentRelativeRectToViewportRelativeRectArgs,
+    ElementContext, Elements, GetClippingRectArgs, MiddlewareState, RootBoundary,
 };
 
 /// Options for [`detect_overflow`].
-#[derive(Clone, Debug, PartialEq)]
+///
+/// The [`boundary`][`Self::boundary`] option is not serialized, because elements can not be described declaratively.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(bound = "")]
 pub struct DetectOverflowOptions<Element> {
     /// The clipping element(s) or area in which overflow will be checked.
     ///
     /// Defaults to [`Boundary::ClippingAncestors`].
+    #[serde(skip)]
     pub boundary: Option<Boundary<Element>>,
 
     /// The root clipping area in which overflow will be checked.
@@ -191,3 +197,17 @@ pub fn detect_overflow<Element: Clone + 'static, Window: Clone + 'static>(
             / offset_scale.x,
     }
 }
+
+/// Asynchronous variant of [`detect_overflow`] for use in [`AsyncMiddleware`][`crate::types::AsyncMiddleware`].
+pub async fn detect_overflow_async<
+    Element: Clone + PartialEq + 'static,
+    Window: Clone + PartialEq + 'static,
+>(
+    state: AsyncMiddlewareState<'_, Element, Window>,
+    options: DetectOverflowOptions<Element>,
+) -> SideObject {
+    run_sync(state.platform, |platform| {
+        detect_overflow(to_sync_state(&state, platform), options.clone())
+    })
+    .await
+}
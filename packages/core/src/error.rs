@@ -0,0 +1,91 @@
+use alloc::string::String;
+use core::error::Error;
+use core::fmt::{self, Display};
+
+/// Error returned by the fallible methods of [`Platform`][`crate::types::Platform`].
+#[derive(Clone, Debug, PartialEq)]
+pub enum PlatformError {
+    /// The element is not connected to a document.
+    DetachedElement,
+    /// The window of the element does not exist.
+    MissingWindow,
+    /// Platform specific error.
+    Other(String),
+}
+
+impl Display for PlatformError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PlatformError::DetachedElement => write!(f, "Element is not connected to a document."),
+            PlatformError::MissingWindow => write!(f, "Window should exist."),
+            PlatformError::Other(message) => write!(f, "{message}"),
+        }
+    }
+}
+
+impl Error for PlatformError {}
+
+/// Error returned when JSON data of a custom middleware can not be merged or read.
+#[derive(Clone, Debug, PartialEq)]
+pub enum MiddlewareDataError {
+    /// The data is not a JSON object, so it can not be merged.
+    NotAnObject { name: String },
+    /// The data could not be deserialized.
+    InvalidData { name: String, message: String },
+}
+
+impl Display for MiddlewareDataError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MiddlewareDataError::NotAnObject { name } => {
+                write!(f, "Data of middleware `{name}` should be an object.")
+            }
+            MiddlewareDataError::InvalidData { name, message } => {
+                write!(f, "Data of middleware `{name}` is invalid: {message}")
+            }
+        }
+    }
+}
+
+impl Error for MiddlewareDataError {}
+
+/// Error returned by [`try_compute_position`][`crate::compute_position::try_compute_position`].
+#[derive(Clone, Debug, PartialEq)]
+pub enum ComputePositionError {
+    /// A platform method failed.
+    Platform(PlatformError),
+    /// The data returned by a middleware could not be merged.
+    MiddlewareData(MiddlewareDataError),
+}
+
+impl Display for ComputePositionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ComputePositionError::Platform(error) => write!(f, "Platform error: {error}"),
+            ComputePositionError::MiddlewareData(error) => {
+                write!(f, "Middleware data error: {error}")
+            }
+        }
+    }
+}
+
+impl Error for ComputePositionError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ComputePositionError::Platform(error) => Some(error),
+            ComputePositionError::MiddlewareData(error) => Some(error),
+        }
+    }
+}
+
+impl From<PlatformError> for ComputePositionError {
+    fn from(value: PlatformError) -> Self {
+        ComputePositionError::Platform(value)
+    }
+}
+
+impl From<MiddlewareDataError> for ComputePositionError {
+    fn from(value: MiddlewareDataError) -> Self {
+        ComputePositionError::MiddlewareData(value)
+    }
+}
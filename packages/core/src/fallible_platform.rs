@@ -0,0 +1,149 @@
+use alloc::vec::Vec;
+use core::cell::RefCell;
+use core::fmt::{self, Debug};
+
+use floating_ui_utils::{
+    ClientRectObject, Coords, Dimensions, ElementOrVirtual, ElementRects, Length,
+    OwnedElementOrWindow, Rect, SideObject, WritingMode,
+};
+
+use crate::detect_overflow::DetectOverflowOptions;
+use crate::error::PlatformError;
+use crate::sync_adapter::EMPTY_RECT;
+use crate::types::{
+    ConvertOffsetParentRelativeRectToViewportRelativeRectArgs, GetClippingRectArgs,
+    GetElementRectsArgs, MiddlewareState, Platform,
+};
+
+/// [`Platform`] calling the fallible methods of another platform.
+///
+/// The first error is recorded and answered with a placeholder value.
+pub(crate) struct FalliblePlatform<'a, Element: Clone + 'static, Window: Clone + 'static> {
+    platform: &'a dyn Platform<Element, Window>,
+    error: RefCell<Option<PlatformError>>,
+}
+
+impl<'a, Element: Clone + 'static, Window: Clone + 'static> FalliblePlatform<'a, Element, Window> {
+    pub(crate) fn new(platform: &'a dyn Platform<Element, Window>) -> Self {
+        FalliblePlatform {
+            platform,
+            error: RefCell::new(None),
+        }
+    }
+
+    /// Returns the first error returned by the platform, if any.
+    pub(crate) fn into_error(self) -> Option<PlatformError> {
+        self.error.into_inner()
+    }
+
+    fn unwrap_or<T>(&self, result: Result<T, PlatformError>, placeholder: T) -> T {
+        result.unwrap_or_else(|error| {
+            let mut existing_error = self.error.borrow_mut();
+            if existing_error.is_none() {
+                *existing_error = Some(error);
+            }
+
+            placeholder
+        })
+    }
+}
+
+impl<Element: Clone + 'static, Window: Clone + 'static> Debug
+    for FalliblePlatform<'_, Element, Window>
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FalliblePlatform")
+            .field("platform", &self.platform)
+            .field("error", &self.error)
+            .finish()
+    }
+}
+
+impl<Element: Clone + 'static, Window: Clone + 'static> Platform<Element, Window>
+    for FalliblePlatform<'_, Element, Window>
+{
+    fn get_element_rects(&self, args: GetElementRectsArgs<Element>) -> ElementRects {
+        self.unwrap_or(
+            self.platform.try_get_element_rects(args),
+            ElementRects {
+                reference: EMPTY_RECT,
+                floating: EMPTY_RECT,
+            },
+        )
+    }
+
+    fn get_clipping_rect(&self, args: GetClippingRectArgs<Element>) -> Rect {
+        self.unwrap_or(self.platform.try_get_clipping_rect(args), EMPTY_RECT)
+    }
+
+    fn get_dimensions(&self, element: &Element) -> Dimensions {
+        self.unwrap_or(
+            self.platform.try_get_dimensions(element),
+            Dimensions {
+                width: 0.0,
+                height: 0.0,
+            },
+        )
+    }
+
+    fn convert_offset_parent_relative_rect_to_viewport_relative_rect(
+        &self,
+        args: ConvertOffsetParentRelativeRectToViewportRelativeRectArgs<Element, Window>,
+    ) -> Option<Rect> {
+        self.unwrap_or(
+            self.platform
+                .try_convert_offset_parent_relative_rect_to_viewport_relative_rect(args),
+            None,
+        )
+    }
+
+    fn get_offset_parent(
+        &self,
+        element: &Element,
+    ) -> Option<OwnedElementOrWindow<Element, Window>> {
+        self.unwrap_or(self.platform.try_get_offset_parent(element), None)
+    }
+
+    fn get_document_element(&self, element: &Element) -> Option<Element> {
+        // Any element is a safe placeholder, as the result will be discarded.
+        self.unwrap_or(
+            self.platform.try_get_document_element(element),
+            Some(element.clone()),
+        )
+    }
+
+    fn get_client_rects(
+        &self,
+        element: ElementOrVirtual<Element>,
+    ) -> Option<Vec<ClientRectObject>> {
+        self.unwrap_or(self.platform.try_get_client_rects(element), None)
+    }
+
+    fn is_rtl(&self, element: &Element) -> Option<bool> {
+        self.unwrap_or(self.platform.try_is_rtl(element), None)
+    }
+
+    fn get_writing_mode(&self, element: &Element) -> Option<WritingMode> {
+        self.unwrap_or(self.platform.try_get_writing_mode(element), None)
+    }
+
+    fn get_scale(&self, element: &Element) -> Option<Coords> {
+        self.unwrap_or(self.platform.try_get_scale(element), None)
+    }
+
+    fn get_client_length(&self, element: &Element, length: Length) -> Option<f64> {
+        self.unwrap_or(self.platform.try_get_client_length(element, length), None)
+    }
+
+    fn detect_overflow(
+        &self,
+        state: MiddlewareState<Element, Window>,
+        options: DetectOverflowOptions<Element>,
+    ) -> SideObject {
+        self.platform.detect_overflow(state, options)
+    }
+
+    fn has_placeholders(&self) -> bool {
+        self.platform.has_placeholders()
+    }
+}
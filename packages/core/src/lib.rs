@@ -6,10 +6,20 @@
 //!
 //! See [@floating-ui/core](https://www.npmjs.com/package/@floating-ui/core) for the original package.
 
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+extern crate alloc;
+
 mod compute_coords_from_placement;
 mod compute_position;
 mod detect_overflow;
+mod error;
+mod fallible_platform;
 pub mod middleware;
+pub mod replay;
+#[cfg(feature = "scene-graph")]
+pub mod scene_graph;
+mod sync_adapter;
 mod types;
 
 #[cfg(test)]
@@ -18,4 +28,5 @@ mod test_utils;
 pub use compute_coords_from_placement::*;
 pub use compute_position::*;
 pub use detect_overflow::*;
+pub use error::*;
 pub use types::*;
@@ -7,15 +7,21 @@ mod auto_placement;
 mod flip;
 mod hide;
 mod inline;
+mod inner;
 mod offset;
 mod shift;
 mod size;
+mod spec;
+mod transform_origin;
 
 pub use arrow::*;
 pub use auto_placement::*;
 pub use flip::*;
 pub use hide::*;
 pub use inline::*;
+pub use inner::*;
 pub use offset::*;
 pub use shift::*;
 pub use size::*;
+pub use spec::*;
+pub use transform_origin::*;
@@ -5,7 +5,8 @@ use floating_ui_utils::{
 use serde::{Deserialize, Serialize};
 
 use crate::types::{
-    Derivable, DerivableFn, Middleware, MiddlewareReturn, MiddlewareState, MiddlewareWithOptions,
+    Derivable, DerivableFn, Middleware, MiddlewareDataValue, MiddlewareReturn, MiddlewareState,
+    MiddlewareWithOptions,
 };
 
 /// Name of the [`Arrow`] middleware.
@@ -54,6 +55,17 @@ pub struct ArrowData {
     pub alignment_offset: Option<f64>,
 }
 
+impl MiddlewareDataValue for ArrowData {
+    fn merge(&mut self, data: Self) {
+        *self = ArrowData {
+            x: data.x.or(self.x),
+            y: data.y.or(self.y),
+            center_offset: data.center_offset,
+            alignment_offset: data.alignment_offset.or(self.alignment_offset),
+        };
+    }
+}
+
 /// Arrow middleware.
 ///
 /// Provides data to position an inner element of the floating element so that it appears centered to the reference element.
@@ -116,7 +128,7 @@ impl<Element: Clone + PartialEq, Window: Clone + PartialEq> Middleware<Element,
             ..
         } = state;
 
-        let data: Option<ArrowData> = middleware_data.get_as(self.name());
+        let data = middleware_data.get_typed::<ArrowData>();
 
         let padding_object = get_padding_object(options.padding.unwrap_or(Padding::All(0.0)));
         let coords = Coords { x, y };
@@ -197,7 +209,7 @@ impl<Element: Clone + PartialEq, Window: Clone + PartialEq> Middleware<Element,
                 Axis::Y => Some(coords.axis(axis) + alignment_offset),
             },
             data: Some(
-                serde_json::to_value(ArrowData {
+                ArrowData {
                     x: match axis {
                         Axis::X => Some(offset),
                         Axis::Y => None,
@@ -208,8 +220,8 @@ impl<Element: Clone + PartialEq, Window: Clone + PartialEq> Middleware<Element,
                     },
                     center_offset: center - offset - alignment_offset,
                     alignment_offset: should_add_offset.then_some(alignment_offset),
-                })
-                .expect("Data should be valid JSON."),
+                }
+                .into(),
             ),
             reset: None,
         }
@@ -1,5 +1,7 @@
+use alloc::{vec, vec::Vec};
+
 use floating_ui_utils::{
-    ALL_PLACEMENTS, Alignment, Placement, get_alignment, get_alignment_sides,
+    ALL_PLACEMENTS, Alignment, Placement, get_alignment, get_alignment_sides_with_writing_mode,
     get_opposite_alignment_placement, get_side,
 };
 use serde::{Deserialize, Serialize};
@@ -7,7 +9,7 @@ use serde::{Deserialize, Serialize};
 use crate::{
     detect_overflow::DetectOverflowOptions,
     types::{
-        Derivable, DerivableFn, Middleware, MiddlewareReturn, MiddlewareState,
+        Derivable, DerivableFn, Middleware, MiddlewareDataValue, MiddlewareReturn, MiddlewareState,
         MiddlewareWithOptions, Reset, ResetValue,
     },
 };
@@ -65,7 +67,8 @@ fn get_placement_list(
 pub const AUTO_PLACEMENT_NAME: &str = "autoPlacement";
 
 /// Options for [`AutoPlacement`] middleware.
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(bound = "")]
 pub struct AutoPlacementOptions<Element: Clone> {
     /// Options for [`detect_overflow`].
     ///
@@ -151,6 +154,8 @@ pub struct AutoPlacementData {
     pub overflows: Vec<AutoPlacementDataOverflow>,
 }
 
+impl MiddlewareDataValue for AutoPlacementData {}
+
 /// Auto placement middleware.
 ///
 /// Optimizes the visibility of the floating element by choosing the placement that has the most space available automatically, without needing to specify a preferred placement.
@@ -214,13 +219,13 @@ impl<Element: Clone + PartialEq, Window: Clone + PartialEq> Middleware<Element,
             ..
         } = state;
 
-        let data: AutoPlacementData =
-            middleware_data
-                .get_as(self.name())
-                .unwrap_or(AutoPlacementData {
-                    index: 0,
-                    overflows: vec![],
-                });
+        let data = middleware_data
+            .get_typed::<AutoPlacementData>()
+            .cloned()
+            .unwrap_or(AutoPlacementData {
+                index: 0,
+                overflows: vec![],
+            });
 
         let cross_axis = options.cross_axis.unwrap_or(false);
         let alignment = options.alignment;
@@ -263,8 +268,12 @@ impl<Element: Clone + PartialEq, Window: Clone + PartialEq> Middleware<Element,
                 options.detect_overflow.unwrap_or_default(),
             );
 
-            let alignment_sides =
-                get_alignment_sides(current_placement, rects, platform.is_rtl(elements.floating));
+            let alignment_sides = get_alignment_sides_with_writing_mode(
+                current_placement,
+                rects,
+                platform.is_rtl(elements.floating),
+                platform.get_writing_mode(elements.floating),
+            );
 
             let current_overflows = vec![
                 overflow.side(get_side(current_placement)),
@@ -286,11 +295,11 @@ impl<Element: Clone + PartialEq, Window: Clone + PartialEq> Middleware<Element,
                     x: None,
                     y: None,
                     data: Some(
-                        serde_json::to_value(AutoPlacementData {
+                        AutoPlacementData {
                             index: current_index + 1,
                             overflows: all_overflows.clone(),
-                        })
-                        .expect("Data should be valid JSON."),
+                        }
+                        .into(),
                     ),
                     reset: Some(Reset::Value(ResetValue {
                         placement: Some(*next_placement),
@@ -345,11 +354,11 @@ impl<Element: Clone + PartialEq, Window: Clone + PartialEq> Middleware<Element,
                     x: None,
                     y: None,
                     data: Some(
-                        serde_json::to_value(AutoPlacementData {
+                        AutoPlacementData {
                             index: current_index + 1,
                             overflows: all_overflows,
-                        })
-                        .expect("Data should be valid JSON."),
+                        }
+                        .into(),
                     ),
                     reset: Some(Reset::Value(ResetValue {
                         placement: Some(reset_placement),
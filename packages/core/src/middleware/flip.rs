@@ -1,14 +1,17 @@
+use alloc::{vec, vec::Vec};
+
 use floating_ui_utils::{
-    Alignment, Axis, Placement, get_alignment, get_alignment_sides, get_expanded_placements,
-    get_opposite_axis_placements, get_opposite_placement, get_side, get_side_axis,
+    Alignment, Axis, Placement, get_alignment, get_alignment_sides_with_writing_mode,
+    get_expanded_placements, get_opposite_axis_placements_with_writing_mode,
+    get_opposite_placement, get_side, get_side_axis,
 };
 use serde::{Deserialize, Serialize};
 
 use crate::{
     detect_overflow::DetectOverflowOptions,
-    middleware::arrow::{ARROW_NAME, ArrowData},
+    middleware::arrow::ArrowData,
     types::{
-        Derivable, DerivableFn, Middleware, MiddlewareReturn, MiddlewareState,
+        Derivable, DerivableFn, Middleware, MiddlewareDataValue, MiddlewareReturn, MiddlewareState,
         MiddlewareWithOptions, Reset, ResetValue,
     },
 };
@@ -17,7 +20,8 @@ use crate::{
 pub const FLIP_NAME: &str = "flip";
 
 /// Cross axis option used by [`Flip`] middleware.
-#[derive(Copy, Clone, Debug, PartialEq)]
+#[derive(Copy, Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
 pub enum CrossAxis {
     /// Whether to check cross axis overflow for both side and alignment flipping.
     True,
@@ -28,7 +32,8 @@ pub enum CrossAxis {
 }
 
 /// Fallback strategy used by [`Flip`] middleware.
-#[derive(Copy, Clone, Debug, Default, PartialEq)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
 pub enum FallbackStrategy {
     #[default]
     BestFit,
@@ -36,7 +41,8 @@ pub enum FallbackStrategy {
 }
 
 /// Options for [`Flip`] middleware.
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(bound = "")]
 pub struct FlipOptions<Element: Clone> {
     /// Options for [`detect_overflow`].
     ///
@@ -149,6 +155,8 @@ pub struct FlipData {
     pub overflows: Vec<FlipDataOverflow>,
 }
 
+impl MiddlewareDataValue for FlipData {}
+
 /// Flip middleware.
 ///
 /// Optimizes the visibility of the floating element by flipping the `placement` in order to keep it in view when the preferred placement(s) will overflow the clipping boundary.
@@ -211,10 +219,13 @@ impl<Element: Clone + PartialEq, Window: Clone + PartialEq> Middleware<Element,
             ..
         } = state;
 
-        let data: FlipData = middleware_data.get_as(self.name()).unwrap_or(FlipData {
-            index: 0,
-            overflows: vec![],
-        });
+        let data = middleware_data
+            .get_typed::<FlipData>()
+            .cloned()
+            .unwrap_or(FlipData {
+                index: 0,
+                overflows: vec![],
+            });
 
         let check_main_axis = options.main_axis.unwrap_or(true);
         let check_cross_axis = options.cross_axis.unwrap_or(CrossAxis::True);
@@ -225,7 +236,7 @@ impl<Element: Clone + PartialEq, Window: Clone + PartialEq> Middleware<Element,
 
         // If a reset by the arrow was caused due to an alignment offset being added,
         // we should skip any logic now since `flip()` has already done its work.
-        let arrow_data: Option<ArrowData> = middleware_data.get_as(ARROW_NAME);
+        let arrow_data = middleware_data.get_typed::<ArrowData>();
         if arrow_data
             .and_then(|arrow_data| arrow_data.alignment_offset)
             .is_some()
@@ -242,6 +253,7 @@ impl<Element: Clone + PartialEq, Window: Clone + PartialEq> Middleware<Element,
         let initial_side_axis = get_side_axis(initial_placement);
         let is_base_placement = get_alignment(initial_placement).is_none();
         let rtl = platform.is_rtl(elements.floating);
+        let writing_mode = platform.get_writing_mode(elements.floating);
 
         let has_specified_fallback_placements = specified_fallback_placements.is_some();
         let mut placements =
@@ -254,11 +266,12 @@ impl<Element: Clone + PartialEq, Window: Clone + PartialEq> Middleware<Element,
         let has_fallback_axis_side_direction = fallback_axis_side_direction.is_some();
 
         if !has_specified_fallback_placements && has_fallback_axis_side_direction {
-            placements.append(&mut get_opposite_axis_placements(
+            placements.append(&mut get_opposite_axis_placements_with_writing_mode(
                 initial_placement,
                 flip_alignment,
                 fallback_axis_side_direction,
                 rtl,
+                writing_mode,
             ));
         }
 
@@ -279,7 +292,7 @@ impl<Element: Clone + PartialEq, Window: Clone + PartialEq> Middleware<Element,
             overflows.push(overflow.side(side));
         }
         if check_cross_axis == CrossAxis::True || check_cross_axis == CrossAxis::Alignment {
-            let sides = get_alignment_sides(placement, rects, rtl);
+            let sides = get_alignment_sides_with_writing_mode(placement, rects, rtl, writing_mode);
             overflows.push(overflow.side(sides.0));
             overflows.push(overflow.side(sides.1));
         }
@@ -316,11 +329,11 @@ impl<Element: Clone + PartialEq, Window: Clone + PartialEq> Middleware<Element,
                         x: None,
                         y: None,
                         data: Some(
-                            serde_json::to_value(FlipData {
+                            FlipData {
                                 index: next_index,
                                 overflows: overflows_data,
-                            })
-                            .expect("Data should be valid JSON."),
+                            }
+                            .into(),
                         ),
                         reset: Some(Reset::Value(ResetValue {
                             placement: Some(*next_placement),
@@ -1,11 +1,14 @@
-use floating_ui_utils::{ALL_SIDES, Rect, SideObject};
+use core::fmt::{self, Display};
+use core::str::FromStr;
+
+use floating_ui_utils::{ALL_SIDES, ParseError, Rect, SideObject};
 use serde::{Deserialize, Serialize};
 
 use crate::{
     detect_overflow::DetectOverflowOptions,
     types::{
-        Derivable, DerivableFn, ElementContext, Middleware, MiddlewareReturn, MiddlewareState,
-        MiddlewareWithOptions,
+        Derivable, DerivableFn, ElementContext, Middleware, MiddlewareDataValue, MiddlewareReturn,
+        MiddlewareState, MiddlewareWithOptions,
     },
 };
 
@@ -26,15 +29,48 @@ fn is_any_side_fully_clipped(overflow: &SideObject) -> bool {
 pub const HIDE_NAME: &str = "hide";
 
 /// Fallback strategy used by [`Hide`] middleware.
-#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
 pub enum HideStrategy {
     #[default]
     ReferenceHidden,
     Escaped,
 }
 
+impl HideStrategy {
+    /// The string forms of all variants.
+    pub const VALUES: &'static [&'static str] = &["reference-hidden", "escaped"];
+
+    /// The string form of the variant.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            HideStrategy::ReferenceHidden => "reference-hidden",
+            HideStrategy::Escaped => "escaped",
+        }
+    }
+}
+
+impl Display for HideStrategy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for HideStrategy {
+    type Err = ParseError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "reference-hidden" => Ok(HideStrategy::ReferenceHidden),
+            "escaped" => Ok(HideStrategy::Escaped),
+            _ => Err(ParseError::new("hide strategy", value, Self::VALUES)),
+        }
+    }
+}
+
 /// Options for [`Hide`] middleware.
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(bound = "")]
 pub struct HideOptions<Element: Clone> {
     /// Options for [`detect_overflow`].
     ///
@@ -79,6 +115,23 @@ pub struct HideData {
     pub escaped_offsets: Option<SideObject>,
 }
 
+impl MiddlewareDataValue for HideData {
+    fn merge(&mut self, data: Self) {
+        if data.reference_hidden.is_some() {
+            self.reference_hidden = data.reference_hidden;
+        }
+        if data.reference_hidden_offsets.is_some() {
+            self.reference_hidden_offsets = data.reference_hidden_offsets;
+        }
+        if data.escaped.is_some() {
+            self.escaped = data.escaped;
+        }
+        if data.escaped_offsets.is_some() {
+            self.escaped_offsets = data.escaped_offsets;
+        }
+    }
+}
+
 /// Hide middleware.
 ///
 /// Provides data to hide the floating element in applicable situations,
@@ -159,13 +212,13 @@ impl<Element: Clone + PartialEq, Window: Clone + PartialEq> Middleware<Element,
                     x: None,
                     y: None,
                     data: Some(
-                        serde_json::to_value(HideData {
+                        HideData {
                             reference_hidden: Some(is_any_side_fully_clipped(&offsets)),
                             reference_hidden_offsets: Some(offsets),
                             escaped: None,
                             escaped_offsets: None,
-                        })
-                        .expect("Data should be valid JSON."),
+                        }
+                        .into(),
                     ),
                     reset: None,
                 }
@@ -188,13 +241,13 @@ impl<Element: Clone + PartialEq, Window: Clone + PartialEq> Middleware<Element,
                     x: None,
                     y: None,
                     data: Some(
-                        serde_json::to_value(HideData {
+                        HideData {
                             reference_hidden: None,
                             reference_hidden_offsets: None,
                             escaped: Some(is_any_side_fully_clipped(&offsets)),
                             escaped_offsets: Some(offsets),
-                        })
-                        .expect("Data should be valid JSON."),
+                        }
+                        .into(),
                     ),
                     reset: None,
                 }
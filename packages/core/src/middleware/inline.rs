@@ -1,9 +1,10 @@
-use std::rc::Rc;
+use alloc::{boxed::Box, rc::Rc, vec, vec::Vec};
 
 use floating_ui_utils::{
     Axis, ClientRectObject, Coords, DefaultVirtualElement, ElementOrVirtual, Padding, Rect, Side,
     get_padding_object, get_side_axis, rect_to_client_rect,
 };
+use serde::{Deserialize, Serialize};
 
 use crate::types::{
     Derivable, DerivableFn, GetElementRectsArgs, Middleware, MiddlewareReturn, MiddlewareState,
@@ -69,7 +70,7 @@ fn get_rects_by_line(rects: Vec<ClientRectObject>) -> Vec<ClientRectObject> {
 pub const INLINE_NAME: &str = "inline";
 
 /// Options for [`Inline`].
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
 pub struct InlineOptions {
     /// Viewport-relative `x` coordinate to choose a `ClientRect`.
     ///
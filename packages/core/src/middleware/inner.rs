@@ -0,0 +1,475 @@
+use floating_ui_utils::{ElementRects, Placement, Rect, Side};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    detect_overflow::DetectOverflowOptions,
+    types::{
+        Derivable, DerivableFn, ElementContext, Middleware, MiddlewareDataValue, MiddlewareReturn,
+        MiddlewareState, MiddlewareWithOptions, Reset, ResetValue,
+    },
+};
+
+/// Name of the [`Inner`] middleware.
+pub const INNER_NAME: &str = "inner";
+
+/// Layout of the list inside the floating element.
+///
+/// On the web, this can be read with `floating_ui_dom::get_inner_list_state`.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct InnerListState {
+    /// Distance from the top of the list content to the top of the selected item.
+    pub item_offset_top: f64,
+
+    /// Height of the selected item.
+    pub item_height: f64,
+
+    /// Number of items in the list.
+    pub item_count: usize,
+
+    /// Height of the list content, including the part which is scrolled out of view.
+    pub scroll_height: f64,
+
+    /// Width of the top border of the list.
+    pub client_top: f64,
+}
+
+/// Options for [`Inner`] middleware.
+#[derive(Clone, Debug, PartialEq)]
+pub struct InnerOptions<Element: Clone> {
+    /// Layout of the list and the selected item.
+    pub list: InnerListState,
+
+    /// Options for [`detect_overflow`].
+    ///
+    /// Defaults to [`DetectOverflowOptions::default`].
+    pub detect_overflow: Option<DetectOverflowOptions<Element>>,
+
+    /// Additional vertical offset of the list, e.g. to move it with the scroll wheel while it is anchored.
+    ///
+    /// Defaults to `0`.
+    pub offset: Option<f64>,
+
+    /// The minimum number of items that must be visible, otherwise the floating element falls back to being placed next to the reference element.
+    ///
+    /// Defaults to `4`.
+    pub min_items_visible: Option<usize>,
+
+    /// The distance the reference element must be inside the clipping boundary, otherwise the floating element falls back to being placed next to the reference element.
+    ///
+    /// Defaults to `0`.
+    pub reference_overflow_threshold: Option<f64>,
+}
+
+impl<Element: Clone> InnerOptions<Element> {
+    pub fn new(list: InnerListState) -> Self {
+        InnerOptions {
+            list,
+            detect_overflow: None,
+            offset: None,
+            min_items_visible: None,
+            reference_overflow_threshold: None,
+        }
+    }
+
+    /// Set `list` option.
+    pub fn list(mut self, value: InnerListState) -> Self {
+        self.list = value;
+        self
+    }
+
+    /// Set `detect_overflow` option.
+    pub fn detect_overflow(mut self, value: DetectOverflowOptions<Element>) -> Self {
+        self.detect_overflow = Some(value);
+        self
+    }
+
+    /// Set `offset` option.
+    pub fn offset(mut self, value: f64) -> Self {
+        self.offset = Some(value);
+        self
+    }
+
+    /// Set `min_items_visible` option.
+    pub fn min_items_visible(mut self, value: usize) -> Self {
+        self.min_items_visible = Some(value);
+        self
+    }
+
+    /// Set `reference_overflow_threshold` option.
+    pub fn reference_overflow_threshold(mut self, value: f64) -> Self {
+        self.reference_overflow_threshold = Some(value);
+        self
+    }
+}
+
+/// Data stored by [`Inner`] middleware.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct InnerData {
+    /// Whether there is not enough room to place the selected item over the reference element,
+    /// so the floating element is placed below or above the reference element instead.
+    pub fallback: bool,
+
+    /// The maximum height of the list, including borders, so it fits in the clipping boundary.
+    pub max_height: f64,
+
+    /// The scroll offset of the list.
+    pub scroll_top: f64,
+}
+
+impl MiddlewareDataValue for InnerData {}
+
+/// Inner middleware.
+///
+/// Positions the floating element so that the selected item of a list inside it appears over the reference element,
+/// such as in macOS-style select menus.
+/// Falls back to placing the floating element below or above the reference element, whichever has more space, when there is not enough room.
+/// When placed above, the placement is reset to [`Placement::Top`].
+///
+/// Intended for the [`Placement::Bottom`][`floating_ui_utils::Placement::Bottom`] placement.
+/// The [`InnerData`] should be applied to the list as `max-height` and `scrollTop`.
+///
+/// See [the Rust Floating UI book](https://floating-ui.rustforweb.org/middleware/inner.html) for more documentation.
+#[derive(PartialEq)]
+pub struct Inner<'a, Element: Clone + 'static, Window: Clone> {
+    options: Derivable<'a, Element, Window, InnerOptions<Element>>,
+}
+
+impl<'a, Element: Clone + 'static, Window: Clone> Inner<'a, Element, Window> {
+    /// Constructs a new instance of this middleware.
+    pub fn new(options: InnerOptions<Element>) -> Self {
+        Inner {
+            options: options.into(),
+        }
+    }
+
+    /// Constructs a new instance of this middleware with derivable options.
+    pub fn new_derivable(options: Derivable<'a, Element, Window, InnerOptions<Element>>) -> Self {
+        Inner { options }
+    }
+
+    /// Constructs a new instance of this middleware with derivable options function.
+    pub fn new_derivable_fn(
+        options: DerivableFn<'a, Element, Window, InnerOptions<Element>>,
+    ) -> Self {
+        Inner {
+            options: options.into(),
+        }
+    }
+}
+
+impl<Element: Clone + 'static, Window: Clone> Clone for Inner<'_, Element, Window> {
+    fn clone(&self) -> Self {
+        Self {
+            options: self.options.clone(),
+        }
+    }
+}
+
+impl<Element: Clone + PartialEq, Window: Clone + PartialEq> Middleware<Element, Window>
+    for Inner<'static, Element, Window>
+{
+    fn name(&self) -> &'static str {
+        INNER_NAME
+    }
+
+    fn compute(&self, state: MiddlewareState<Element, Window>) -> MiddlewareReturn {
+        let options = self.options.evaluate(state.clone());
+
+        let MiddlewareState {
+            y,
+            placement,
+            elements,
+            rects,
+            platform,
+            ..
+        } = state;
+
+        let InnerListState {
+            item_offset_top,
+            item_height,
+            item_count,
+            scroll_height,
+            client_top,
+        } = options.list;
+        let detect_overflow_options = options.detect_overflow.unwrap_or_default();
+        let min_items_visible = options.min_items_visible.unwrap_or(4);
+        let reference_overflow_threshold = options.reference_overflow_threshold.unwrap_or(0.0);
+
+        // Measure with the full height of the list, as the floating element may be constrained by a previous `max_height`.
+        let list_height = scroll_height + client_top * 2.0;
+        let list_rects = ElementRects {
+            reference: rects.reference.clone(),
+            floating: Rect {
+                height: list_height,
+                ..rects.floating.clone()
+            },
+        };
+        let detect_list_overflow = |y: f64| {
+            platform.detect_overflow(
+                MiddlewareState {
+                    y,
+                    elements: elements.clone(),
+                    rects: &list_rects,
+                    ..state
+                },
+                detect_overflow_options.clone(),
+            )
+        };
+
+        let next_y = rects.reference.y + rects.reference.height / 2.0
+            - (item_offset_top + client_top + item_height / 2.0)
+            - options.offset.unwrap_or(0.0);
+
+        let overflow = detect_list_overflow(next_y);
+        let reference_overflow = platform.detect_overflow(
+            MiddlewareState {
+                y: next_y,
+                elements: elements.clone(),
+                ..state
+            },
+            detect_overflow_options
+                .clone()
+                .element_context(ElementContext::Reference),
+        );
+
+        let diff_y = overflow.top.max(0.0);
+        let max_height = (list_height - diff_y - overflow.bottom.max(0.0)).max(0.0);
+
+        let should_fallback = max_height
+            < item_height * min_items_visible.min(item_count) as f64 - 1.0
+            || reference_overflow.top >= -reference_overflow_threshold
+            || reference_overflow.bottom >= -reference_overflow_threshold;
+
+        if !should_fallback {
+            return MiddlewareReturn {
+                x: None,
+                y: Some(next_y + diff_y),
+                data: Some(
+                    InnerData {
+                        fallback: false,
+                        max_height,
+                        scroll_top: diff_y,
+                    }
+                    .into(),
+                ),
+                reset: None,
+            };
+        }
+
+        // After a reset to the top placement, `y` is above the reference element.
+        let below_y = match placement.side() {
+            Side::Bottom => y,
+            _ => rects.reference.y + rects.reference.height,
+        };
+        let below_overflow = detect_list_overflow(below_y);
+        let below_height = (list_height - below_overflow.bottom.max(0.0)).max(0.0);
+        let above_overflow = detect_list_overflow(rects.reference.y - list_height);
+        let above_height = (list_height - above_overflow.top.max(0.0)).max(0.0);
+
+        let (fallback_placement, y, max_height) =
+            if below_overflow.bottom > 0.0 && above_height > below_height {
+                (
+                    Placement::Top,
+                    rects.reference.y - above_height,
+                    above_height,
+                )
+            } else {
+                (Placement::Bottom, below_y, below_height)
+            };
+
+        if placement != fallback_placement {
+            return MiddlewareReturn {
+                x: None,
+                y: None,
+                data: None,
+                reset: Some(Reset::Value(ResetValue {
+                    placement: Some(fallback_placement),
+                    rects: None,
+                })),
+            };
+        }
+
+        // Keep the selected item in view.
+        let scroll_top = (item_offset_top + item_height / 2.0 - max_height / 2.0)
+            .min(list_height - max_height)
+            .max(0.0);
+
+        MiddlewareReturn {
+            x: None,
+            y: Some(y),
+            data: Some(
+                InnerData {
+                    fallback: true,
+                    max_height,
+                    scroll_top,
+                }
+                .into(),
+            ),
+            reset: None,
+        }
+    }
+}
+
+impl<Element: Clone, Window: Clone> MiddlewareWithOptions<Element, Window, InnerOptions<Element>>
+    for Inner<'_, Element, Window>
+{
+    fn options(&self) -> &Derivable<'_, Element, Window, InnerOptions<Element>> {
+        &self.options
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use floating_ui_utils::{Dimensions, Placement, SideObject};
+
+    use crate::{
+        compute_position::compute_position,
+        detect_overflow::detect_overflow,
+        test_utils::{Element, FLOATING, FLOATING_RECT, REFERENCE, Window},
+        types::{
+            ComputePositionConfig, ComputePositionReturn, GetClippingRectArgs, GetElementRectsArgs,
+            Platform,
+        },
+    };
+
+    use super::*;
+
+    #[derive(Debug)]
+    struct ListPlatform {
+        reference: Rect,
+    }
+
+    impl Platform<Element, Window> for ListPlatform {
+        fn get_element_rects(&self, _args: GetElementRectsArgs<Element>) -> ElementRects {
+            ElementRects {
+                reference: self.reference.clone(),
+                floating: FLOATING_RECT,
+            }
+        }
+
+        fn get_clipping_rect(&self, _args: GetClippingRectArgs<Element>) -> Rect {
+            Rect {
+                x: 0.0,
+                y: 0.0,
+                width: 1000.0,
+                height: 1000.0,
+            }
+        }
+
+        fn get_dimensions(&self, _element: &Element) -> Dimensions {
+            Dimensions {
+                width: 10.0,
+                height: 10.0,
+            }
+        }
+
+        fn detect_overflow(
+            &self,
+            state: MiddlewareState<Element, Window>,
+            options: DetectOverflowOptions<Element>,
+        ) -> SideObject {
+            detect_overflow(state, options)
+        }
+    }
+
+    fn compute_list_position(reference_y: f64, selected_index: usize) -> ComputePositionReturn {
+        let platform = ListPlatform {
+            reference: Rect {
+                x: 400.0,
+                y: reference_y,
+                width: 100.0,
+                height: 100.0,
+            },
+        };
+        let list = InnerListState {
+            item_offset_top: selected_index as f64 * 20.0,
+            item_height: 20.0,
+            item_count: 10,
+            scroll_height: 200.0,
+            client_top: 0.0,
+        };
+
+        compute_position(
+            (&REFERENCE).into(),
+            &FLOATING,
+            ComputePositionConfig::new(&platform)
+                .placement(Placement::Bottom)
+                .middleware(vec![Box::new(Inner::new(InnerOptions::new(list)))]),
+        )
+    }
+
+    fn compute(reference_y: f64, selected_index: usize) -> (f64, InnerData) {
+        let position = compute_list_position(reference_y, selected_index);
+
+        (
+            position.y,
+            position
+                .middleware_data
+                .get_typed::<InnerData>()
+                .expect("Inner data should exist.")
+                .clone(),
+        )
+    }
+
+    #[test]
+    fn test_selected_item_over_reference() {
+        assert_eq!(
+            compute(400.0, 2),
+            (
+                400.0,
+                InnerData {
+                    fallback: false,
+                    max_height: 200.0,
+                    scroll_top: 0.0,
+                }
+            )
+        );
+    }
+
+    #[test]
+    fn test_scroll_when_list_overflows() {
+        // The list would start at -90, so it is cut off and scrolled to keep the selected item over the reference.
+        assert_eq!(
+            compute(50.0, 9),
+            (
+                0.0,
+                InnerData {
+                    fallback: false,
+                    max_height: 110.0,
+                    scroll_top: 90.0,
+                }
+            )
+        );
+    }
+
+    #[test]
+    fn test_fallback_when_reference_overflows() {
+        assert_eq!(
+            compute(0.0, 2),
+            (
+                100.0,
+                InnerData {
+                    fallback: true,
+                    max_height: 200.0,
+                    scroll_top: 0.0,
+                }
+            )
+        );
+    }
+
+    #[test]
+    fn test_fallback_above_when_below_overflows() {
+        let position = compute_list_position(900.0, 2);
+
+        assert_eq!(position.placement, Placement::Top);
+        assert_eq!(position.y, 700.0);
+        assert_eq!(
+            position.middleware_data.get_typed::<InnerData>(),
+            Some(&InnerData {
+                fallback: true,
+                max_height: 200.0,
+                scroll_top: 0.0,
+            })
+        );
+    }
+}
@@ -1,12 +1,13 @@
 use floating_ui_utils::{
-    Alignment, Axis, Coords, Placement, Side, get_alignment, get_side, get_side_axis,
+    Alignment, Axis, Coords, Placement, Side, get_alignment, get_opposite_axis, get_side,
+    get_side_axis, is_axis_reversed,
 };
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    middleware::{ARROW_NAME, ArrowData},
+    middleware::ArrowData,
     types::{
-        Derivable, DerivableFn, Middleware, MiddlewareReturn, MiddlewareState,
+        Derivable, DerivableFn, Middleware, MiddlewareDataValue, MiddlewareReturn, MiddlewareState,
         MiddlewareWithOptions,
     },
 };
@@ -22,15 +23,21 @@ fn convert_value_to_coords<Element: Clone, Window: Clone>(
         ..
     } = state;
 
-    let rtl = platform.is_rtl(elements.floating).unwrap_or(false);
+    let rtl = platform.is_rtl(elements.floating);
+    let writing_mode = platform.get_writing_mode(elements.floating);
     let side = get_side(placement);
     let alignment = get_alignment(placement);
-    let is_vertical = get_side_axis(placement) == Axis::Y;
+    let side_axis = get_side_axis(placement);
+    let is_vertical = side_axis == Axis::Y;
     let main_axis_multi = match side {
         Side::Left | Side::Top => -1.0,
         Side::Right | Side::Bottom => 1.0,
     };
-    let cross_axis_multi = if rtl && is_vertical { -1.0 } else { 1.0 };
+    let cross_axis_multi = if is_axis_reversed(get_opposite_axis(side_axis), rtl, writing_mode) {
+        -1.0
+    } else {
+        1.0
+    };
 
     let (main_axis, mut cross_axis, alignment_axis): (f64, f64, Option<f64>) = match options {
         OffsetOptions::Value(value) => (*value, 0.0, None),
@@ -67,7 +74,7 @@ fn convert_value_to_coords<Element: Clone, Window: Clone>(
 pub const OFFSET_NAME: &str = "offset";
 
 /// Axes configuration for [`OffsetOptions`].
-#[derive(Clone, Default, Debug, PartialEq)]
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct OffsetOptionsValues {
     /// The axis that runs along the side of the floating element. Represents the distance (gutter or margin) between the reference and floating element.
     ///
@@ -111,7 +118,8 @@ impl OffsetOptionsValues {
 /// Options for [`Offset`] middleware.
 ///
 /// A number (shorthand for [`main_axis`][`OffsetOptionsValues::main_axis`] or distance) or an axes configuration ([`OffsetOptionsValues`]).
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
 pub enum OffsetOptions {
     Value(f64),
     Values(OffsetOptionsValues),
@@ -130,6 +138,8 @@ pub struct OffsetData {
     pub placement: Placement,
 }
 
+impl MiddlewareDataValue for OffsetData {}
+
 /// Offset middleware.
 ///
 /// Modifies the placement by translating the floating element along the specified axes.
@@ -187,7 +197,7 @@ impl<Element: Clone + PartialEq, Window: Clone + PartialEq> Middleware<Element,
             ..
         } = state;
 
-        let data: Option<OffsetData> = middleware_data.get_as(self.name());
+        let data = middleware_data.get_typed::<OffsetData>();
 
         let diff_coords = convert_value_to_coords(state, &options);
 
@@ -195,7 +205,7 @@ impl<Element: Clone + PartialEq, Window: Clone + PartialEq> Middleware<Element,
         if let Some(data_placement) = data.map(|data| data.placement)
             && placement == data_placement
         {
-            let arrow_data: Option<ArrowData> = middleware_data.get_as(ARROW_NAME);
+            let arrow_data = middleware_data.get_typed::<ArrowData>();
             if arrow_data
                 .and_then(|arrow_data| arrow_data.alignment_offset)
                 .is_some()
@@ -213,11 +223,11 @@ impl<Element: Clone + PartialEq, Window: Clone + PartialEq> Middleware<Element,
             x: Some(x + diff_coords.x),
             y: Some(y + diff_coords.y),
             data: Some(
-                serde_json::to_value(OffsetData {
+                OffsetData {
                     diff_coords,
                     placement,
-                })
-                .expect("Data should be valid JSON."),
+                }
+                .into(),
             ),
             reset: None,
         }
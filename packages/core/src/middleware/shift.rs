@@ -1,14 +1,16 @@
-use std::fmt::Debug;
+use alloc::boxed::Box;
+use core::fmt::Debug;
 
-use dyn_derive::dyn_trait;
-use floating_ui_utils::{Axis, Coords, Side, clamp, get_opposite_axis, get_side_axis};
+use floating_ui_utils::{
+    Axis, Coords, DynPartialEq, Side, clamp, get_opposite_axis, get_side_axis,
+};
 use serde::{Deserialize, Serialize};
 
 use crate::{
     detect_overflow::DetectOverflowOptions,
-    middleware::{OFFSET_NAME, OffsetData},
+    middleware::OffsetData,
     types::{
-        Derivable, DerivableFn, Middleware, MiddlewareReturn, MiddlewareState,
+        Derivable, DerivableFn, Middleware, MiddlewareDataValue, MiddlewareReturn, MiddlewareState,
         MiddlewareWithOptions,
     },
 };
@@ -17,13 +19,47 @@ use crate::{
 pub const SHIFT_NAME: &str = "shift";
 
 /// Limiter used by [`Shift`] middleware. Limits the shifting done in order to prevent detachment.
-#[dyn_trait]
-pub trait Limiter<Element: Clone + 'static, Window: Clone + 'static>: Clone + PartialEq {
+pub trait Limiter<Element: Clone + 'static, Window: Clone + 'static>:
+    LimiterClone<Element, Window> + DynPartialEq
+{
     fn compute(&self, state: MiddlewareState<Element, Window>) -> Coords;
 }
 
+/// Object-safe [`Clone`] for [`Limiter`].
+///
+/// Implemented for all limiters which implement [`Clone`].
+pub trait LimiterClone<Element: Clone + 'static, Window: Clone + 'static> {
+    fn clone_box(&self) -> Box<dyn Limiter<Element, Window>>;
+}
+
+impl<Element: Clone + 'static, Window: Clone + 'static, T> LimiterClone<Element, Window> for T
+where
+    T: Limiter<Element, Window> + Clone + 'static,
+{
+    fn clone_box(&self) -> Box<dyn Limiter<Element, Window>> {
+        Box::new(self.clone())
+    }
+}
+
+impl<Element: Clone + 'static, Window: Clone + 'static> Clone
+    for Box<dyn Limiter<Element, Window>>
+{
+    fn clone(&self) -> Self {
+        (**self).clone_box()
+    }
+}
+
+impl<Element: Clone + 'static, Window: Clone + 'static> PartialEq for dyn Limiter<Element, Window> {
+    fn eq(&self, other: &Self) -> bool {
+        self.dyn_eq(other.as_dyn_any())
+    }
+}
+
 /// Options for [`Shift`] middleware.
-#[derive(Clone, PartialEq)]
+///
+/// The [`limiter`][`Self::limiter`] option is not serialized.
+#[derive(Clone, PartialEq, Serialize, Deserialize)]
+#[serde(bound = "")]
 pub struct ShiftOptions<Element: Clone + 'static, Window: Clone + 'static> {
     /// Options for [`detect_overflow`].
     ///
@@ -43,6 +79,7 @@ pub struct ShiftOptions<Element: Clone + 'static, Window: Clone + 'static> {
     /// Accepts a limiter that limits the shifting done in order to prevent detachment.
     ///
     /// Defaults to [`DefaultLimiter`].
+    #[serde(skip)]
     pub limiter: Option<Box<dyn Limiter<Element, Window>>>,
 }
 
@@ -112,6 +149,8 @@ pub struct ShiftData {
     pub enabled: ShiftDataEnabled,
 }
 
+impl MiddlewareDataValue for ShiftData {}
+
 /// Shift middleware.
 ///
 /// Optimizes the visibility of the floating element by shifting it in order to keep it in view when it will overflow the clipping boundary.
@@ -234,14 +273,14 @@ impl<Element: Clone + PartialEq + 'static, Window: Clone + PartialEq + 'static>
             x: Some(limited_coords.x),
             y: Some(limited_coords.y),
             data: Some(
-                serde_json::to_value(ShiftData {
+                ShiftData {
                     x: limited_coords.x - x,
                     y: limited_coords.y - y,
                     enabled: ShiftDataEnabled::default()
                         .set_axis(main_axis, check_main_axis)
                         .set_axis(cross_axis, check_cross_axis),
-                })
-                .expect("Data should be valid JSON."),
+                }
+                .into(),
             ),
             reset: None,
         }
@@ -431,7 +470,7 @@ impl<Element: Clone + PartialEq, Window: Clone + PartialEq> Limiter<Element, Win
                 Side::Bottom | Side::Right => false,
             };
 
-            let data: Option<OffsetData> = middleware_data.get_as(OFFSET_NAME);
+            let data = middleware_data.get_typed::<OffsetData>();
             let data_cross_axis = data.map_or(0.0, |data| data.diff_coords.axis(cross_axis));
 
             let limit_min = rects.reference.axis(cross_axis) - rects.floating.length(len)
@@ -1,10 +1,11 @@
-use std::ptr;
+use core::ptr;
 
-use floating_ui_utils::{Alignment, Axis, Rect, Side, get_side_axis};
+use floating_ui_utils::{Alignment, Axis, Rect, Side, get_side_axis, is_axis_reversed};
+use serde::{Deserialize, Serialize};
 
 use crate::{
     detect_overflow::DetectOverflowOptions,
-    middleware::shift::{SHIFT_NAME, ShiftData},
+    middleware::shift::ShiftData,
     types::{
         Derivable, DerivableFn, Middleware, MiddlewareReturn, MiddlewareState,
         MiddlewareWithOptions, ResetRects, ResetValue,
@@ -25,7 +26,10 @@ pub struct ApplyState<'a, Element: Clone + 'static, Window: Clone> {
 pub type ApplyFn<Element, Window> = dyn Fn(ApplyState<Element, Window>);
 
 /// Options for [`Size`] middleware.
-#[derive(Clone)]
+///
+/// The [`apply`][`Self::apply`] option is not serialized.
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(bound = "")]
 pub struct SizeOptions<'a, Element: Clone + 'static, Window: Clone> {
     /// Options for [`detect_overflow`].
     ///
@@ -33,6 +37,7 @@ pub struct SizeOptions<'a, Element: Clone + 'static, Window: Clone> {
     pub detect_overflow: Option<DetectOverflowOptions<Element>>,
 
     /// Function that is called to perform style mutations to the floating element to change its size.
+    #[serde(skip)]
     pub apply: Option<&'a ApplyFn<Element, Window>>,
 }
 
@@ -154,33 +159,35 @@ impl<Element: Clone + PartialEq, Window: Clone + PartialEq> Middleware<Element,
         let is_y_axis = get_side_axis(placement) == Axis::Y;
         let Rect { width, height, .. } = rects.floating;
 
+        let rtl = platform.is_rtl(elements.floating);
+        let writing_mode = platform.get_writing_mode(elements.floating);
+        let is_end_alignment = |axis| {
+            alignment
+                == Some(if is_axis_reversed(axis, rtl, writing_mode) {
+                    Alignment::Start
+                } else {
+                    Alignment::End
+                })
+        };
+
         let height_side;
         let width_side;
 
         match side {
             Side::Top | Side::Bottom => {
                 height_side = side;
-                width_side = match alignment {
-                    Some(alignment) => {
-                        if alignment
-                            == match platform.is_rtl(elements.floating) {
-                                Some(true) => Alignment::Start,
-                                _ => Alignment::End,
-                            }
-                        {
-                            Side::Left
-                        } else {
-                            Side::Right
-                        }
-                    }
-                    None => Side::Right,
+                width_side = if is_end_alignment(Axis::X) {
+                    Side::Left
+                } else {
+                    Side::Right
                 };
             }
             Side::Right | Side::Left => {
                 width_side = side;
-                height_side = match alignment {
-                    Some(Alignment::End) => Side::Top,
-                    _ => Side::Bottom,
+                height_side = if is_end_alignment(Axis::Y) {
+                    Side::Top
+                } else {
+                    Side::Bottom
                 };
             }
         }
@@ -193,16 +200,16 @@ impl<Element: Clone + PartialEq, Window: Clone + PartialEq> Middleware<Element,
         let overflow_available_width =
             maximum_clipping_width.min(width - overflow.side(width_side));
 
-        let no_shift = middleware_data.get(SHIFT_NAME).is_none();
+        let data = middleware_data.get_typed::<ShiftData>();
+        let no_shift = data.is_none();
 
         let mut available_height = overflow_available_height;
         let mut available_width = overflow_available_width;
 
-        let data: Option<ShiftData> = middleware_data.get_as(SHIFT_NAME);
-        if data.as_ref().is_some_and(|data| data.enabled.x) {
+        if data.is_some_and(|data| data.enabled.x) {
             available_width = maximum_clipping_width;
         }
-        if data.as_ref().is_some_and(|data| data.enabled.y) {
+        if data.is_some_and(|data| data.enabled.y) {
             available_height = maximum_clipping_height;
         }
 
@@ -214,7 +221,10 @@ impl<Element: Clone + PartialEq, Window: Clone + PartialEq> Middleware<Element,
             }
         }
 
-        if let Some(apply) = options.apply {
+        // Placeholder runs of `compute_position_async` are discarded, so only apply the final dimensions.
+        if let Some(apply) = options.apply
+            && !platform.has_placeholders()
+        {
             apply(ApplyState {
                 state: MiddlewareState {
                     elements: elements.clone(),
@@ -0,0 +1,127 @@
+use alloc::boxed::Box;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    middleware::{
+        AutoPlacement, AutoPlacementOptions, Flip, FlipOptions, Hide, HideOptions, Inline,
+        InlineOptions, Offset, OffsetOptions, Shift, ShiftOptions,
+    },
+    types::Middleware,
+};
+
+/// Declarative description of a middleware, e.g. stored in a configuration file.
+///
+/// Serialized as an object with the name of the middleware as key, e.g. `{ "offset": 8 }` or `{ "flip": { "fallback_placements": ["top"] } }`.
+///
+/// Middleware which need elements or functions ([`Arrow`][`crate::middleware::Arrow`] and [`Size`][`crate::middleware::Size`]) can not be described,
+/// options with elements or functions are not serialized.
+#[derive(Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case", bound = "")]
+pub enum MiddlewareSpec<Element: Clone + 'static, Window: Clone + 'static> {
+    AutoPlacement(AutoPlacementOptions<Element>),
+    Flip(FlipOptions<Element>),
+    Hide(HideOptions<Element>),
+    Inline(InlineOptions),
+    Offset(OffsetOptions),
+    Shift(ShiftOptions<Element, Window>),
+}
+
+impl<Element: Clone + PartialEq + 'static, Window: Clone + PartialEq + 'static>
+    MiddlewareSpec<Element, Window>
+{
+    /// Constructs the described middleware.
+    pub fn into_middleware(self) -> Box<dyn Middleware<Element, Window>> {
+        match self {
+            MiddlewareSpec::AutoPlacement(options) => Box::new(AutoPlacement::new(options)),
+            MiddlewareSpec::Flip(options) => Box::new(Flip::new(options)),
+            MiddlewareSpec::Hide(options) => Box::new(Hide::new(options)),
+            MiddlewareSpec::Inline(options) => Box::new(Inline::new(options)),
+            MiddlewareSpec::Offset(options) => Box::new(Offset::new(options)),
+            MiddlewareSpec::Shift(options) => Box::new(Shift::new(options)),
+        }
+    }
+}
+
+impl<Element: Clone + PartialEq + 'static, Window: Clone + PartialEq + 'static>
+    From<MiddlewareSpec<Element, Window>> for Box<dyn Middleware<Element, Window>>
+{
+    fn from(value: MiddlewareSpec<Element, Window>) -> Self {
+        value.into_middleware()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{vec, vec::Vec};
+
+    use floating_ui_utils::{Padding, Placement};
+
+    use crate::{
+        detect_overflow::DetectOverflowOptions,
+        middleware::{CrossAxis, FLIP_NAME, FallbackStrategy, OFFSET_NAME, SHIFT_NAME},
+        test_utils::{Element, Window},
+        types::{ElementContext, RootBoundary},
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_deserialize() {
+        let specs: Vec<MiddlewareSpec<Element, Window>> = serde_json::from_str(
+            r#"[
+                { "offset": 8 },
+                { "flip": { "fallback_placements": ["top", "left"] } },
+                { "shift": { "detect_overflow": { "padding": 4 } } }
+            ]"#,
+        )
+        .unwrap();
+
+        assert!(
+            specs
+                == vec![
+                    MiddlewareSpec::Offset(OffsetOptions::Value(8.0)),
+                    MiddlewareSpec::Flip(
+                        FlipOptions::default()
+                            .fallback_placements(vec![Placement::Top, Placement::Left])
+                    ),
+                    MiddlewareSpec::Shift(ShiftOptions::default().detect_overflow(
+                        DetectOverflowOptions::default().padding(Padding::All(4.0))
+                    )),
+                ]
+        );
+
+        let names: Vec<&str> = specs
+            .into_iter()
+            .map(|spec| spec.into_middleware().name())
+            .collect();
+        assert_eq!(names, vec![OFFSET_NAME, FLIP_NAME, SHIFT_NAME]);
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let specs: Vec<MiddlewareSpec<Element, Window>> = vec![MiddlewareSpec::Flip(
+            FlipOptions::default()
+                .cross_axis(CrossAxis::Alignment)
+                .fallback_strategy(FallbackStrategy::InitialPlacement)
+                .fallback_placements(vec![Placement::TopStart])
+                .detect_overflow(
+                    DetectOverflowOptions::default()
+                        .root_boundary(RootBoundary::LayoutViewport)
+                        .element_context(ElementContext::Floating),
+                ),
+        )];
+
+        let value = serde_json::to_value(&specs).unwrap();
+        let flip = &value[0]["flip"];
+        assert_eq!(flip["cross_axis"], "alignment");
+        assert_eq!(flip["fallback_strategy"], "initial-placement");
+        assert_eq!(flip["fallback_placements"][0], "top-start");
+        assert_eq!(flip["detect_overflow"]["root_boundary"], "layout-viewport");
+        assert_eq!(flip["detect_overflow"]["element_context"], "floating");
+
+        let round_trip: Vec<MiddlewareSpec<Element, Window>> =
+            serde_json::from_value(value).unwrap();
+        assert!(round_trip == specs);
+    }
+}
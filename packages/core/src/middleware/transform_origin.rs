@@ -0,0 +1,367 @@
+use alloc::{borrow::ToOwned, format, string::String};
+
+use floating_ui_utils::{
+    Alignment, Side, get_alignment, get_alignment_axis, get_side, is_axis_reversed,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    middleware::ArrowData,
+    types::{
+        Derivable, DerivableFn, Middleware, MiddlewareDataValue, MiddlewareReturn, MiddlewareState,
+        MiddlewareWithOptions,
+    },
+};
+
+/// Name of the [`TransformOrigin`] middleware.
+pub const TRANSFORM_ORIGIN_NAME: &str = "transformOrigin";
+
+/// Options for [`TransformOrigin`] middleware.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct TransformOriginOptions {
+    /// The width of the arrow element along the edge of the floating element, used to find its center.
+    ///
+    /// Defaults to `0`.
+    pub arrow_width: Option<f64>,
+
+    /// The height of the arrow element, used to move the origin to the tip of the arrow.
+    ///
+    /// Defaults to `0`.
+    pub arrow_height: Option<f64>,
+}
+
+impl TransformOriginOptions {
+    /// Set `arrow_width` option.
+    pub fn arrow_width(mut self, value: f64) -> Self {
+        self.arrow_width = Some(value);
+        self
+    }
+
+    /// Set `arrow_height` option.
+    pub fn arrow_height(mut self, value: f64) -> Self {
+        self.arrow_height = Some(value);
+        self
+    }
+}
+
+/// Data stored by [`TransformOrigin`] middleware.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct TransformOriginData {
+    /// The horizontal component of the origin, e.g. `24px` or `50%`.
+    pub x: String,
+
+    /// The vertical component of the origin, e.g. `-8px` or `100%`.
+    pub y: String,
+}
+
+impl TransformOriginData {
+    /// The value for the CSS `transform-origin` property, e.g. `24px 100%`.
+    pub fn transform_origin(&self) -> String {
+        format!("{} {}", self.x, self.y)
+    }
+}
+
+impl MiddlewareDataValue for TransformOriginData {}
+
+/// Transform origin middleware.
+///
+/// Provides data to set the `transform-origin` of the floating element to the side facing the reference element,
+/// or to the tip of the arrow element if it is centered, so scale animations grow out of the reference element.
+///
+/// Should be placed after all other middleware, so it uses the final placement and [`ArrowData`].
+///
+/// See [the Rust Floating UI book](https://floating-ui.rustforweb.org/middleware/transform-origin.html) for more documentation.
+#[derive(PartialEq)]
+pub struct TransformOrigin<'a, Element: Clone + 'static, Window: Clone> {
+    options: Derivable<'a, Element, Window, TransformOriginOptions>,
+}
+
+impl<'a, Element: Clone, Window: Clone> TransformOrigin<'a, Element, Window> {
+    /// Constructs a new instance of this middleware.
+    pub fn new(options: TransformOriginOptions) -> Self {
+        TransformOrigin {
+            options: options.into(),
+        }
+    }
+
+    /// Constructs a new instance of this middleware with derivable options.
+    pub fn new_derivable(options: Derivable<'a, Element, Window, TransformOriginOptions>) -> Self {
+        TransformOrigin { options }
+    }
+
+    /// Constructs a new instance of this middleware with derivable options function.
+    pub fn new_derivable_fn(
+        options: DerivableFn<'a, Element, Window, TransformOriginOptions>,
+    ) -> Self {
+        TransformOrigin {
+            options: options.into(),
+        }
+    }
+}
+
+impl<Element: Clone + 'static, Window: Clone> Clone for TransformOrigin<'_, Element, Window> {
+    fn clone(&self) -> Self {
+        Self {
+            options: self.options.clone(),
+        }
+    }
+}
+
+impl<Element: Clone + PartialEq, Window: Clone + PartialEq> Middleware<Element, Window>
+    for TransformOrigin<'static, Element, Window>
+{
+    fn name(&self) -> &'static str {
+        TRANSFORM_ORIGIN_NAME
+    }
+
+    fn compute(&self, state: MiddlewareState<Element, Window>) -> MiddlewareReturn {
+        let options = self.options.evaluate(state.clone());
+
+        let MiddlewareState {
+            placement,
+            middleware_data,
+            rects,
+            elements,
+            platform,
+            ..
+        } = state;
+
+        let rtl = platform.is_rtl(elements.floating);
+        let writing_mode = platform.get_writing_mode(elements.floating);
+        let is_reversed = is_axis_reversed(get_alignment_axis(placement), rtl, writing_mode);
+
+        // An arrow which can not be centered to the reference element is usually hidden, so fall back to the alignment.
+        let arrow_data = middleware_data
+            .get_typed::<ArrowData>()
+            .filter(|arrow_data| arrow_data.center_offset == 0.0);
+        let (arrow_width, arrow_height) = match arrow_data {
+            Some(_) => (
+                options.arrow_width.unwrap_or(0.0),
+                options.arrow_height.unwrap_or(0.0),
+            ),
+            None => (0.0, 0.0),
+        };
+
+        let alignment_origin = || {
+            match (get_alignment(placement), is_reversed) {
+                (Some(Alignment::Start), false) | (Some(Alignment::End), true) => "0%",
+                (Some(Alignment::End), false) | (Some(Alignment::Start), true) => "100%",
+                (None, _) => "50%",
+            }
+            .to_owned()
+        };
+        let cross_origin = |arrow_coord: Option<f64>, arrow_length: f64| {
+            arrow_coord.map_or_else(alignment_origin, |arrow_coord| {
+                format!("{}px", arrow_coord + arrow_length / 2.0)
+            })
+        };
+        let main_origin = |near: bool, length: f64| match (near, arrow_height == 0.0) {
+            (true, true) => "0%".to_owned(),
+            (false, true) => "100%".to_owned(),
+            (true, false) => format!("{}px", -arrow_height),
+            (false, false) => format!("{}px", length + arrow_height),
+        };
+
+        let (x, y) = match get_side(placement) {
+            Side::Top => (
+                cross_origin(arrow_data.and_then(|data| data.x), arrow_width),
+                main_origin(false, rects.floating.height),
+            ),
+            Side::Bottom => (
+                cross_origin(arrow_data.and_then(|data| data.x), arrow_width),
+                main_origin(true, rects.floating.height),
+            ),
+            Side::Left => (
+                main_origin(false, rects.floating.width),
+                cross_origin(arrow_data.and_then(|data| data.y), arrow_width),
+            ),
+            Side::Right => (
+                main_origin(true, rects.floating.width),
+                cross_origin(arrow_data.and_then(|data| data.y), arrow_width),
+            ),
+        };
+
+        MiddlewareReturn {
+            x: None,
+            y: None,
+            data: Some(TransformOriginData { x, y }.into()),
+            reset: None,
+        }
+    }
+}
+
+impl<Element: Clone, Window: Clone> MiddlewareWithOptions<Element, Window, TransformOriginOptions>
+    for TransformOrigin<'_, Element, Window>
+{
+    fn options(&self) -> &Derivable<'_, Element, Window, TransformOriginOptions> {
+        &self.options
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use floating_ui_utils::{Dimensions, ElementRects, Placement, Rect, SideObject};
+
+    use crate::{
+        compute_position::compute_position,
+        detect_overflow::DetectOverflowOptions,
+        middleware::{Arrow, ArrowOptions},
+        test_utils::{Element, FLOATING, PLATFORM, REFERENCE, Window},
+        types::{
+            ComputePositionConfig, GetClippingRectArgs, GetElementRectsArgs, Middleware, Platform,
+        },
+    };
+
+    use super::*;
+
+    #[derive(Debug)]
+    struct RtlPlatform {}
+
+    impl Platform<Element, Window> for RtlPlatform {
+        fn get_element_rects(&self, args: GetElementRectsArgs<Element>) -> ElementRects {
+            PLATFORM.get_element_rects(args)
+        }
+
+        fn get_clipping_rect(&self, args: GetClippingRectArgs<Element>) -> Rect {
+            PLATFORM.get_clipping_rect(args)
+        }
+
+        fn get_dimensions(&self, element: &Element) -> Dimensions {
+            PLATFORM.get_dimensions(element)
+        }
+
+        fn detect_overflow(
+            &self,
+            state: MiddlewareState<Element, Window>,
+            options: DetectOverflowOptions<Element>,
+        ) -> SideObject {
+            PLATFORM.detect_overflow(state, options)
+        }
+
+        fn is_rtl(&self, _element: &Element) -> Option<bool> {
+            Some(true)
+        }
+    }
+
+    fn compute(
+        placement: Placement,
+        middleware: Vec<Box<dyn Middleware<Element, Window>>>,
+    ) -> String {
+        compute_with_platform(&PLATFORM, placement, middleware)
+    }
+
+    fn compute_with_platform(
+        platform: &dyn Platform<Element, Window>,
+        placement: Placement,
+        middleware: Vec<Box<dyn Middleware<Element, Window>>>,
+    ) -> String {
+        compute_position(
+            (&REFERENCE).into(),
+            &FLOATING,
+            ComputePositionConfig::new(platform)
+                .placement(placement)
+                .middleware(middleware),
+        )
+        .middleware_data
+        .get_typed::<TransformOriginData>()
+        .expect("Transform origin data should exist.")
+        .transform_origin()
+    }
+
+    #[test]
+    fn test_alignment_without_arrow() {
+        assert_eq!(
+            compute(
+                Placement::Top,
+                vec![Box::new(TransformOrigin::new(
+                    TransformOriginOptions::default()
+                ))]
+            ),
+            "50% 100%"
+        );
+        assert_eq!(
+            compute(
+                Placement::BottomStart,
+                vec![Box::new(TransformOrigin::new(
+                    TransformOriginOptions::default()
+                ))]
+            ),
+            "0% 0%"
+        );
+        assert_eq!(
+            compute(
+                Placement::LeftEnd,
+                vec![Box::new(TransformOrigin::new(
+                    TransformOriginOptions::default()
+                ))]
+            ),
+            "100% 100%"
+        );
+    }
+
+    #[test]
+    fn test_alignment_without_arrow_rtl() {
+        // The start of the inline axis is the right edge, while the vertical axis is unaffected.
+        assert_eq!(
+            compute_with_platform(
+                &RtlPlatform {},
+                Placement::TopStart,
+                vec![Box::new(TransformOrigin::new(
+                    TransformOriginOptions::default()
+                ))]
+            ),
+            "100% 100%"
+        );
+        assert_eq!(
+            compute_with_platform(
+                &RtlPlatform {},
+                Placement::BottomEnd,
+                vec![Box::new(TransformOrigin::new(
+                    TransformOriginOptions::default()
+                ))]
+            ),
+            "0% 0%"
+        );
+        assert_eq!(
+            compute_with_platform(
+                &RtlPlatform {},
+                Placement::RightStart,
+                vec![Box::new(TransformOrigin::new(
+                    TransformOriginOptions::default()
+                ))]
+            ),
+            "0% 0%"
+        );
+    }
+
+    #[test]
+    fn test_arrow() {
+        // The arrow is 10px wide and centered on the reference at `x = 50`, while the floating element starts at `x = 25`.
+        assert_eq!(
+            compute(
+                Placement::Top,
+                vec![
+                    Box::new(Arrow::new(ArrowOptions::new(Element {}))),
+                    Box::new(TransformOrigin::new(
+                        TransformOriginOptions::default().arrow_width(10.0)
+                    )),
+                ]
+            ),
+            "25px 100%"
+        );
+        assert_eq!(
+            compute(
+                Placement::Bottom,
+                vec![
+                    Box::new(Arrow::new(ArrowOptions::new(Element {}))),
+                    Box::new(TransformOrigin::new(
+                        TransformOriginOptions::default()
+                            .arrow_width(10.0)
+                            .arrow_height(5.0)
+                    )),
+                ]
+            ),
+            "25px -5px"
+        );
+    }
+}
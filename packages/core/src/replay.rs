@@ -0,0 +1,830 @@
+//! Recorded platform answers for regression testing.
+//!
+//! A [`RecordingPlatform`] wraps another [`Platform`] and records every answer during a [`compute_position`][`crate::compute_position::compute_position`] call into a [`PlatformFixture`].
+//! The fixture can be stored as JSON and fed back by a [`ReplayPlatform`], which positions the same elements deterministically without the original platform.
+//!
+//! See [the Rust Floating UI book](https://floating-ui.rustforweb.org/platform.html#replay-fixtures) for more documentation.
+
+use alloc::{format, string::String, vec, vec::Vec};
+use core::cell::RefCell;
+use core::fmt::{self, Debug};
+
+use floating_ui_utils::{
+    ClientRectObject, Coords, Dimensions, ElementOrVirtual, ElementOrWindow, ElementRects, Length,
+    OwnedElementOrVirtual, OwnedElementOrWindow, Rect, SideObject, Strategy, WritingMode,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    detect_overflow::{DetectOverflowOptions, detect_overflow},
+    error::PlatformError,
+    types::{
+        Boundary, ConvertOffsetParentRelativeRectToViewportRelativeRectArgs, GetClippingRectArgs,
+        GetElementRectsArgs, MiddlewareState, Platform, RootBoundary,
+    },
+};
+
+/// Identifier of an element in a [`PlatformFixture`]. Used as element type of the [`ReplayPlatform`].
+///
+/// The reference element is always [`FixtureElement::REFERENCE`] and the floating element is always [`FixtureElement::FLOATING`].
+/// Other elements, e.g. an arrow element or boundary elements, are numbered in the order the platform was first asked about them.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct FixtureElement(pub usize);
+
+impl FixtureElement {
+    /// The reference element of the recorded call.
+    pub const REFERENCE: FixtureElement = FixtureElement(0);
+
+    /// The floating element of the recorded call.
+    pub const FLOATING: FixtureElement = FixtureElement(1);
+}
+
+/// Arguments of a recorded [`Platform`] method call.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(
+    tag = "method",
+    rename_all = "camelCase",
+    rename_all_fields = "camelCase"
+)]
+pub enum FixtureQuery {
+    ElementRects {
+        reference: FixtureElement,
+        floating: FixtureElement,
+        strategy: Strategy,
+    },
+    ClippingRect {
+        element: FixtureElement,
+        boundary: Boundary<FixtureElement>,
+        root_boundary: RootBoundary,
+        strategy: Strategy,
+    },
+    Dimensions {
+        element: FixtureElement,
+    },
+    ViewportRelativeRect {
+        elements: Option<(FixtureElement, FixtureElement)>,
+        rect: Rect,
+        offset_parent: Option<OwnedElementOrWindow<FixtureElement, ()>>,
+        strategy: Strategy,
+    },
+    OffsetParent {
+        element: FixtureElement,
+    },
+    DocumentElement {
+        element: FixtureElement,
+    },
+    ClientRects {
+        element: FixtureElement,
+    },
+    IsRtl {
+        element: FixtureElement,
+    },
+    WritingMode {
+        element: FixtureElement,
+    },
+    Scale {
+        element: FixtureElement,
+    },
+    ClientLength {
+        element: FixtureElement,
+        length: Length,
+    },
+}
+
+/// Return value of a recorded [`Platform`] method call.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum FixtureAnswer {
+    ElementRects(ElementRects),
+    ClippingRect(Rect),
+    Dimensions(Dimensions),
+    ViewportRelativeRect(Option<Rect>),
+    OffsetParent(Option<OwnedElementOrWindow<FixtureElement, ()>>),
+    DocumentElement(Option<FixtureElement>),
+    ClientRects(Option<Vec<ClientRectObject>>),
+    IsRtl(Option<bool>),
+    WritingMode(Option<WritingMode>),
+    Scale(Option<Coords>),
+    ClientLength(Option<f64>),
+}
+
+/// Recorded [`Platform`] method call.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct FixtureCall {
+    pub query: FixtureQuery,
+    pub answer: FixtureAnswer,
+}
+
+/// Everything a [`Platform`] answered during one [`compute_position`][`crate::compute_position::compute_position`] call.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct PlatformFixture {
+    pub calls: Vec<FixtureCall>,
+}
+
+impl PlatformFixture {
+    /// Parse a fixture from JSON.
+    pub fn from_json(json: &str) -> Result<Self, serde_json::Error> {
+        serde_json::from_str(json)
+    }
+
+    /// Serialize the fixture to JSON.
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("Fixture should be serializable.")
+    }
+
+    /// The recorded answer to a query.
+    pub fn answer(&self, query: &FixtureQuery) -> Option<&FixtureAnswer> {
+        self.calls
+            .iter()
+            .find(|call| call.query == *query)
+            .map(|call| &call.answer)
+    }
+}
+
+/// [`Platform`] recording the answers of another platform into a [`PlatformFixture`].
+///
+/// Only successful answers are recorded. Repeated queries are recorded once.
+pub struct RecordingPlatform<'a, Element: Clone + 'static, Window: Clone + 'static> {
+    platform: &'a dyn Platform<Element, Window>,
+    elements: RefCell<Vec<OwnedElementOrVirtual<Element>>>,
+    fixture: RefCell<PlatformFixture>,
+}
+
+impl<'a, Element: Clone + PartialEq + 'static, Window: Clone + 'static>
+    RecordingPlatform<'a, Element, Window>
+{
+    /// Create a recording platform for the given reference and floating element.
+    pub fn new(
+        platform: &'a dyn Platform<Element, Window>,
+        reference: ElementOrVirtual<Element>,
+        floating: &Element,
+    ) -> Self {
+        RecordingPlatform {
+            platform,
+            elements: RefCell::new(vec![reference.into(), floating.clone().into()]),
+            fixture: RefCell::new(PlatformFixture::default()),
+        }
+    }
+
+    /// The answers recorded so far.
+    pub fn fixture(&self) -> PlatformFixture {
+        self.fixture.borrow().clone()
+    }
+
+    /// Consumes the platform, returning the recorded answers.
+    pub fn into_fixture(self) -> PlatformFixture {
+        self.fixture.into_inner()
+    }
+
+    fn element(&self, element: ElementOrVirtual<Element>) -> FixtureElement {
+        let element: OwnedElementOrVirtual<Element> = element.into();
+        let mut elements = self.elements.borrow_mut();
+
+        FixtureElement(
+            match elements.iter().position(|existing| *existing == element) {
+                Some(index) => index,
+                None => {
+                    elements.push(element);
+                    elements.len() - 1
+                }
+            },
+        )
+    }
+
+    fn id(&self, element: &Element) -> FixtureElement {
+        self.element(element.into())
+    }
+
+    fn offset_parent(
+        &self,
+        offset_parent: ElementOrWindow<Element, Window>,
+    ) -> OwnedElementOrWindow<FixtureElement, ()> {
+        match offset_parent {
+            ElementOrWindow::Element(element) => OwnedElementOrWindow::Element(self.id(element)),
+            ElementOrWindow::Window(_) => OwnedElementOrWindow::Window(()),
+        }
+    }
+
+    fn record<T>(
+        &self,
+        query: FixtureQuery,
+        result: Result<T, PlatformError>,
+        answer: impl FnOnce(&T) -> FixtureAnswer,
+    ) -> Result<T, PlatformError> {
+        if let Ok(value) = &result {
+            let mut fixture = self.fixture.borrow_mut();
+            if fixture.answer(&query).is_none() {
+                fixture.calls.push(FixtureCall {
+                    query,
+                    answer: answer(value),
+                });
+            }
+        }
+
+        result
+    }
+}
+
+impl<Element: Clone + 'static, Window: Clone + 'static> Debug
+    for RecordingPlatform<'_, Element, Window>
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RecordingPlatform")
+            .field("platform", &self.platform)
+            .field("fixture", &self.fixture)
+            .finish()
+    }
+}
+
+impl<Element: Clone + PartialEq + 'static, Window: Clone + 'static> Platform<Element, Window>
+    for RecordingPlatform<'_, Element, Window>
+{
+    fn get_element_rects(&self, args: GetElementRectsArgs<Element>) -> ElementRects {
+        self.try_get_element_rects(args)
+            .unwrap_or_else(|error| panic!("{error}"))
+    }
+
+    fn get_clipping_rect(&self, args: GetClippingRectArgs<Element>) -> Rect {
+        self.try_get_clipping_rect(args)
+            .unwrap_or_else(|error| panic!("{error}"))
+    }
+
+    fn get_dimensions(&self, element: &Element) -> Dimensions {
+        self.try_get_dimensions(element)
+            .unwrap_or_else(|error| panic!("{error}"))
+    }
+
+    fn convert_offset_parent_relative_rect_to_viewport_relative_rect(
+        &self,
+        args: ConvertOffsetParentRelativeRectToViewportRelativeRectArgs<Element, Window>,
+    ) -> Option<Rect> {
+        self.try_convert_offset_parent_relative_rect_to_viewport_relative_rect(args)
+            .unwrap_or_else(|error| panic!("{error}"))
+    }
+
+    fn get_offset_parent(
+        &self,
+        element: &Element,
+    ) -> Option<OwnedElementOrWindow<Element, Window>> {
+        self.try_get_offset_parent(element)
+            .unwrap_or_else(|error| panic!("{error}"))
+    }
+
+    fn get_document_element(&self, element: &Element) -> Option<Element> {
+        self.try_get_document_element(element)
+            .unwrap_or_else(|error| panic!("{error}"))
+    }
+
+    fn get_client_rects(
+        &self,
+        element: ElementOrVirtual<Element>,
+    ) -> Option<Vec<ClientRectObject>> {
+        self.try_get_client_rects(element)
+            .unwrap_or_else(|error| panic!("{error}"))
+    }
+
+    fn is_rtl(&self, element: &Element) -> Option<bool> {
+        self.try_is_rtl(element)
+            .unwrap_or_else(|error| panic!("{error}"))
+    }
+
+    fn get_writing_mode(&self, element: &Element) -> Option<WritingMode> {
+        self.try_get_writing_mode(element)
+            .unwrap_or_else(|error| panic!("{error}"))
+    }
+
+    fn get_scale(&self, element: &Element) -> Option<Coords> {
+        self.try_get_scale(element)
+            .unwrap_or_else(|error| panic!("{error}"))
+    }
+
+    fn get_client_length(&self, element: &Element, length: Length) -> Option<f64> {
+        self.try_get_client_length(element, length)
+            .unwrap_or_else(|error| panic!("{error}"))
+    }
+
+    fn try_get_element_rects(
+        &self,
+        args: GetElementRectsArgs<Element>,
+    ) -> Result<ElementRects, PlatformError> {
+        let query = FixtureQuery::ElementRects {
+            reference: self.element(args.reference.clone()),
+            floating: self.id(args.floating),
+            strategy: args.strategy,
+        };
+
+        self.record(query, self.platform.try_get_element_rects(args), |rects| {
+            FixtureAnswer::ElementRects(rects.clone())
+        })
+    }
+
+    fn try_get_clipping_rect(
+        &self,
+        args: GetClippingRectArgs<Element>,
+    ) -> Result<Rect, PlatformError> {
+        let query = FixtureQuery::ClippingRect {
+            element: self.id(args.element),
+            boundary: match &args.boundary {
+                Boundary::ClippingAncestors => Boundary::ClippingAncestors,
+                Boundary::Element(element) => Boundary::Element(self.id(element)),
+                Boundary::Elements(elements) => {
+                    Boundary::Elements(elements.iter().map(|element| self.id(element)).collect())
+                }
+            },
+            root_boundary: args.root_boundary.clone(),
+            strategy: args.strategy,
+        };
+
+        self.record(query, self.platform.try_get_clipping_rect(args), |rect| {
+            FixtureAnswer::ClippingRect(rect.clone())
+        })
+    }
+
+    fn try_get_dimensions(&self, element: &Element) -> Result<Dimensions, PlatformError> {
+        let query = FixtureQuery::Dimensions {
+            element: self.id(element),
+        };
+
+        self.record(
+            query,
+            self.platform.try_get_dimensions(element),
+            |dimensions| FixtureAnswer::Dimensions(dimensions.clone()),
+        )
+    }
+
+    fn try_convert_offset_parent_relative_rect_to_viewport_relative_rect(
+        &self,
+        args: ConvertOffsetParentRelativeRectToViewportRelativeRectArgs<Element, Window>,
+    ) -> Result<Option<Rect>, PlatformError> {
+        let query = FixtureQuery::ViewportRelativeRect {
+            elements: args.elements.as_ref().map(|elements| {
+                (
+                    self.element(elements.reference.clone()),
+                    self.id(elements.floating),
+                )
+            }),
+            rect: args.rect.clone(),
+            offset_parent: args
+                .offset_parent
+                .clone()
+                .map(|offset_parent| self.offset_parent(offset_parent)),
+            strategy: args.strategy,
+        };
+
+        self.record(
+            query,
+            self.platform
+                .try_convert_offset_parent_relative_rect_to_viewport_relative_rect(args),
+            |rect| FixtureAnswer::ViewportRelativeRect(rect.clone()),
+        )
+    }
+
+    fn try_get_offset_parent(
+        &self,
+        element: &Element,
+    ) -> Result<Option<OwnedElementOrWindow<Element, Window>>, PlatformError> {
+        let query = FixtureQuery::OffsetParent {
+            element: self.id(element),
+        };
+
+        self.record(
+            query,
+            self.platform.try_get_offset_parent(element),
+            |offset_parent| {
+                FixtureAnswer::OffsetParent(
+                    offset_parent
+                        .as_ref()
+                        .map(|offset_parent| self.offset_parent(offset_parent.into())),
+                )
+            },
+        )
+    }
+
+    fn try_get_document_element(
+        &self,
+        element: &Element,
+    ) -> Result<Option<Element>, PlatformError> {
+        let query = FixtureQuery::DocumentElement {
+            element: self.id(element),
+        };
+
+        self.record(
+            query,
+            self.platform.try_get_document_element(element),
+            |document_element| {
+                FixtureAnswer::DocumentElement(
+                    document_element
+                        .as_ref()
+                        .map(|document_element| self.id(document_element)),
+                )
+            },
+        )
+    }
+
+    fn try_get_client_rects(
+        &self,
+        element: ElementOrVirtual<Element>,
+    ) -> Result<Option<Vec<ClientRectObject>>, PlatformError> {
+        let query = FixtureQuery::ClientRects {
+            element: self.element(element.clone()),
+        };
+
+        self.record(
+            query,
+            self.platform.try_get_client_rects(element),
+            |rects| FixtureAnswer::ClientRects(rects.clone()),
+        )
+    }
+
+    fn try_is_rtl(&self, element: &Element) -> Result<Option<bool>, PlatformError> {
+        let query = FixtureQuery::IsRtl {
+            element: self.id(element),
+        };
+
+        self.record(query, self.platform.try_is_rtl(element), |is_rtl| {
+            FixtureAnswer::IsRtl(*is_rtl)
+        })
+    }
+
+    fn try_get_writing_mode(
+        &self,
+        element: &Element,
+    ) -> Result<Option<WritingMode>, PlatformError> {
+        let query = FixtureQuery::WritingMode {
+            element: self.id(element),
+        };
+
+        self.record(
+            query,
+            self.platform.try_get_writing_mode(element),
+            |writing_mode| FixtureAnswer::WritingMode(*writing_mode),
+        )
+    }
+
+    fn try_get_scale(&self, element: &Element) -> Result<Option<Coords>, PlatformError> {
+        let query = FixtureQuery::Scale {
+            element: self.id(element),
+        };
+
+        self.record(query, self.platform.try_get_scale(element), |scale| {
+            FixtureAnswer::Scale(scale.clone())
+        })
+    }
+
+    fn try_get_client_length(
+        &self,
+        element: &Element,
+        length: Length,
+    ) -> Result<Option<f64>, PlatformError> {
+        let query = FixtureQuery::ClientLength {
+            element: self.id(element),
+            length,
+        };
+
+        self.record(
+            query,
+            self.platform.try_get_client_length(element, length),
+            |client_length| FixtureAnswer::ClientLength(*client_length),
+        )
+    }
+
+    fn detect_overflow(
+        &self,
+        state: MiddlewareState<Element, Window>,
+        options: DetectOverflowOptions<Element>,
+    ) -> SideObject {
+        detect_overflow(state, options)
+    }
+
+    fn has_placeholders(&self) -> bool {
+        self.platform.has_placeholders()
+    }
+}
+
+/// [`Platform`] answering from a [`PlatformFixture`].
+///
+/// Queries which were not recorded fail with [`PlatformError::Other`], so the fixture must be replayed with the same options as it was recorded with.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ReplayPlatform {
+    fixture: PlatformFixture,
+}
+
+impl ReplayPlatform {
+    pub fn new(fixture: PlatformFixture) -> Self {
+        ReplayPlatform { fixture }
+    }
+
+    /// The fixture of this platform.
+    pub fn fixture(&self) -> &PlatformFixture {
+        &self.fixture
+    }
+
+    fn answer(&self, query: FixtureQuery) -> Result<FixtureAnswer, PlatformError> {
+        self.fixture
+            .answer(&query)
+            .cloned()
+            .ok_or_else(|| PlatformError::Other(format!("Fixture has no answer to {query:?}.")))
+    }
+
+    fn element(element: ElementOrVirtual<FixtureElement>) -> Result<FixtureElement, PlatformError> {
+        match element {
+            ElementOrVirtual::Element(element) => Ok(*element),
+            ElementOrVirtual::VirtualElement(_) => Err(PlatformError::Other(
+                "Virtual elements are recorded as fixture elements.".into(),
+            )),
+        }
+    }
+}
+
+fn unexpected_answer<T>(answer: FixtureAnswer) -> Result<T, PlatformError> {
+    Err(PlatformError::Other(format!(
+        "Fixture answer {answer:?} does not match the query."
+    )))
+}
+
+impl Platform<FixtureElement, ()> for ReplayPlatform {
+    fn get_element_rects(&self, args: GetElementRectsArgs<FixtureElement>) -> ElementRects {
+        self.try_get_element_rects(args)
+            .unwrap_or_else(|error| panic!("{error}"))
+    }
+
+    fn get_clipping_rect(&self, args: GetClippingRectArgs<FixtureElement>) -> Rect {
+        self.try_get_clipping_rect(args)
+            .unwrap_or_else(|error| panic!("{error}"))
+    }
+
+    fn get_dimensions(&self, element: &FixtureElement) -> Dimensions {
+        self.try_get_dimensions(element)
+            .unwrap_or_else(|error| panic!("{error}"))
+    }
+
+    fn convert_offset_parent_relative_rect_to_viewport_relative_rect(
+        &self,
+        args: ConvertOffsetParentRelativeRectToViewportRelativeRectArgs<FixtureElement, ()>,
+    ) -> Option<Rect> {
+        self.try_convert_offset_parent_relative_rect_to_viewport_relative_rect(args)
+            .unwrap_or_else(|error| panic!("{error}"))
+    }
+
+    fn get_offset_parent(
+        &self,
+        element: &FixtureElement,
+    ) -> Option<OwnedElementOrWindow<FixtureElement, ()>> {
+        self.try_get_offset_parent(element)
+            .unwrap_or_else(|error| panic!("{error}"))
+    }
+
+    fn get_document_element(&self, element: &FixtureElement) -> Option<FixtureElement> {
+        self.try_get_document_element(element)
+            .unwrap_or_else(|error| panic!("{error}"))
+    }
+
+    fn get_client_rects(
+        &self,
+        element: ElementOrVirtual<FixtureElement>,
+    ) -> Option<Vec<ClientRectObject>> {
+        self.try_get_client_rects(element)
+            .unwrap_or_else(|error| panic!("{error}"))
+    }
+
+    fn is_rtl(&self, element: &FixtureElement) -> Option<bool> {
+        self.try_is_rtl(element)
+            .unwrap_or_else(|error| panic!("{error}"))
+    }
+
+    fn get_writing_mode(&self, element: &FixtureElement) -> Option<WritingMode> {
+        self.try_get_writing_mode(element)
+            .unwrap_or_else(|error| panic!("{error}"))
+    }
+
+    fn get_scale(&self, element: &FixtureElement) -> Option<Coords> {
+        self.try_get_scale(element)
+            .unwrap_or_else(|error| panic!("{error}"))
+    }
+
+    fn get_client_length(&self, element: &FixtureElement, length: Length) -> Option<f64> {
+        self.try_get_client_length(element, length)
+            .unwrap_or_else(|error| panic!("{error}"))
+    }
+
+    fn try_get_element_rects(
+        &self,
+        args: GetElementRectsArgs<FixtureElement>,
+    ) -> Result<ElementRects, PlatformError> {
+        match self.answer(FixtureQuery::ElementRects {
+            reference: Self::element(args.reference)?,
+            floating: *args.floating,
+            strategy: args.strategy,
+        })? {
+            FixtureAnswer::ElementRects(rects) => Ok(rects),
+            answer => unexpected_answer(answer),
+        }
+    }
+
+    fn try_get_clipping_rect(
+        &self,
+        args: GetClippingRectArgs<FixtureElement>,
+    ) -> Result<Rect, PlatformError> {
+        match self.answer(FixtureQuery::ClippingRect {
+            element: *args.element,
+            boundary: args.boundary,
+            root_boundary: args.root_boundary,
+            strategy: args.strategy,
+        })? {
+            FixtureAnswer::ClippingRect(rect) => Ok(rect),
+            answer => unexpected_answer(answer),
+        }
+    }
+
+    fn try_get_dimensions(&self, element: &FixtureElement) -> Result<Dimensions, PlatformError> {
+        match self.answer(FixtureQuery::Dimensions { element: *element })? {
+            FixtureAnswer::Dimensions(dimensions) => Ok(dimensions),
+            answer => unexpected_answer(answer),
+        }
+    }
+
+    fn try_convert_offset_parent_relative_rect_to_viewport_relative_rect(
+        &self,
+        args: ConvertOffsetParentRelativeRectToViewportRelativeRectArgs<FixtureElement, ()>,
+    ) -> Result<Option<Rect>, PlatformError> {
+        let elements = match args.elements {
+            Some(elements) => Some((Self::element(elements.reference)?, *elements.floating)),
+            None => None,
+        };
+
+        match self.answer(FixtureQuery::ViewportRelativeRect {
+            elements,
+            rect: args.rect,
+            offset_parent: args.offset_parent.map(|offset_parent| offset_parent.into()),
+            strategy: args.strategy,
+        })? {
+            FixtureAnswer::ViewportRelativeRect(rect) => Ok(rect),
+            answer => unexpected_answer(answer),
+        }
+    }
+
+    fn try_get_offset_parent(
+        &self,
+        element: &FixtureElement,
+    ) -> Result<Option<OwnedElementOrWindow<FixtureElement, ()>>, PlatformError> {
+        match self.answer(FixtureQuery::OffsetParent { element: *element })? {
+            FixtureAnswer::OffsetParent(offset_parent) => Ok(offset_parent),
+            answer => unexpected_answer(answer),
+        }
+    }
+
+    fn try_get_document_element(
+        &self,
+        element: &FixtureElement,
+    ) -> Result<Option<FixtureElement>, PlatformError> {
+        match self.answer(FixtureQuery::DocumentElement { element: *element })? {
+            FixtureAnswer::DocumentElement(document_element) => Ok(document_element),
+            answer => unexpected_answer(answer),
+        }
+    }
+
+    fn try_get_client_rects(
+        &self,
+        element: ElementOrVirtual<FixtureElement>,
+    ) -> Result<Option<Vec<ClientRectObject>>, PlatformError> {
+        match self.answer(FixtureQuery::ClientRects {
+            element: Self::element(element)?,
+        })? {
+            FixtureAnswer::ClientRects(rects) => Ok(rects),
+            answer => unexpected_answer(answer),
+        }
+    }
+
+    fn try_is_rtl(&self, element: &FixtureElement) -> Result<Option<bool>, PlatformError> {
+        match self.answer(FixtureQuery::IsRtl { element: *element })? {
+            FixtureAnswer::IsRtl(is_rtl) => Ok(is_rtl),
+            answer => unexpected_answer(answer),
+        }
+    }
+
+    fn try_get_writing_mode(
+        &self,
+        element: &FixtureElement,
+    ) -> Result<Option<WritingMode>, PlatformError> {
+        match self.answer(FixtureQuery::WritingMode { element: *element })? {
+            FixtureAnswer::WritingMode(writing_mode) => Ok(writing_mode),
+            answer => unexpected_answer(answer),
+        }
+    }
+
+    fn try_get_scale(&self, element: &FixtureElement) -> Result<Option<Coords>, PlatformError> {
+        match self.answer(FixtureQuery::Scale { element: *element })? {
+            FixtureAnswer::Scale(scale) => Ok(scale),
+            answer => unexpected_answer(answer),
+        }
+    }
+
+    fn try_get_client_length(
+        &self,
+        element: &FixtureElement,
+        length: Length,
+    ) -> Result<Option<f64>, PlatformError> {
+        match self.answer(FixtureQuery::ClientLength {
+            element: *element,
+            length,
+        })? {
+            FixtureAnswer::ClientLength(client_length) => Ok(client_length),
+            answer => unexpected_answer(answer),
+        }
+    }
+
+    fn detect_overflow(
+        &self,
+        state: MiddlewareState<FixtureElement, ()>,
+        options: DetectOverflowOptions<FixtureElement>,
+    ) -> SideObject {
+        detect_overflow(state, options)
+    }
+}
+
+#[cfg(all(test, feature = "scene-graph"))]
+mod tests {
+    use crate::{
+        compute_position::compute_position,
+        middleware::{Flip, FlipOptions, Shift, ShiftOptions},
+        scene_graph::{SceneGraphPlatform, SceneNode},
+        types::ComputePositionConfig,
+    };
+    use floating_ui_utils::Placement;
+
+    use super::*;
+
+    #[test]
+    fn test_record_and_replay() {
+        let mut platform = SceneGraphPlatform::new(
+            Dimensions {
+                width: 400.0,
+                height: 300.0,
+            },
+            Dimensions {
+                width: 400.0,
+                height: 300.0,
+            },
+        );
+        let root = platform.document_element();
+        let reference = platform.add_node(
+            root,
+            SceneNode::new(Rect {
+                x: 150.0,
+                y: 260.0,
+                width: 100.0,
+                height: 20.0,
+            }),
+        );
+        let floating = platform.add_node(
+            root,
+            SceneNode::new(Rect {
+                x: 0.0,
+                y: 0.0,
+                width: 80.0,
+                height: 60.0,
+            }),
+        );
+
+        let recording = RecordingPlatform::new(&platform, (&reference).into(), &floating);
+        let recorded = compute_position(
+            (&reference).into(),
+            &floating,
+            ComputePositionConfig::new(&recording)
+                .placement(Placement::BottomStart)
+                .middleware(vec![
+                    Box::new(Flip::new(FlipOptions::default())),
+                    Box::new(Shift::new(ShiftOptions::default())),
+                ]),
+        );
+        assert_eq!(recorded.placement, Placement::TopStart);
+
+        let json = recording.into_fixture().to_json();
+        let replay = ReplayPlatform::new(PlatformFixture::from_json(&json).unwrap());
+        let replayed = compute_position(
+            (&FixtureElement::REFERENCE).into(),
+            &FixtureElement::FLOATING,
+            ComputePositionConfig::new(&replay)
+                .placement(Placement::BottomStart)
+                .middleware(vec![
+                    Box::new(Flip::new(FlipOptions::default())),
+                    Box::new(Shift::new(ShiftOptions::default())),
+                ]),
+        );
+        assert_eq!(
+            (replayed.x, replayed.y, replayed.placement),
+            (recorded.x, recorded.y, recorded.placement)
+        );
+    }
+
+    #[test]
+    fn test_replay_missing_answer() {
+        let replay = ReplayPlatform::new(PlatformFixture::default());
+
+        assert!(matches!(
+            replay.try_get_dimensions(&FixtureElement::FLOATING),
+            Err(PlatformError::Other(_))
+        ));
+    }
+}
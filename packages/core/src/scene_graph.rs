@@ -0,0 +1,945 @@
+//! In-memory [`Platform`] for native GUIs and tests.
+//!
+//! The [`SceneGraphPlatform`] stores a tree of nodes with layout rects, scroll offsets, borders, overflow clipping, offset parents, scale and direction.
+//! Positions are computed the same way as in the DOM, without the need for a browser.
+//!
+//! See [the Rust Floating UI book](https://floating-ui.rustforweb.org/platform.html#scene-graph-platform) for more documentation.
+
+use alloc::{vec, vec::Vec};
+
+use floating_ui_utils::{
+    ClientRectObject, Coords, Dimensions, ElementOrVirtual, ElementOrWindow, ElementRects, Length,
+    OwnedElementOrWindow, Rect, SideObject, Strategy, WritingMode, rect_to_client_rect,
+};
+
+use crate::{
+    detect_overflow::{DetectOverflowOptions, detect_overflow},
+    error::PlatformError,
+    types::{
+        Boundary, ConvertOffsetParentRelativeRectToViewportRelativeRectArgs, GetClippingRectArgs,
+        GetElementRectsArgs, MiddlewareState, Platform, RootBoundary,
+    },
+};
+
+/// Identifier of a node in a [`SceneGraphPlatform`]. Used as element type of the platform.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct SceneNodeId(usize);
+
+/// Window of a [`SceneGraphPlatform`].
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct SceneWindow;
+
+/// Node in a [`SceneGraphPlatform`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct SceneNode {
+    /// The border box of the node, relative to the content of the parent node.
+    ///
+    /// For fixed nodes, the rect is relative to the viewport.
+    pub rect: Rect,
+
+    /// The scroll offset of the content of the node.
+    ///
+    /// The scroll offset of the document element is the scroll offset of the viewport.
+    ///
+    /// Defaults to `0.0` on both axes.
+    pub scroll: Coords,
+
+    /// The border widths of the node. The content of the node starts inside the border.
+    ///
+    /// Defaults to `0.0` on all sides.
+    pub border: SideObject,
+
+    /// Whether the content of the node is clipped to its client area, similar to `overflow: hidden`.
+    ///
+    /// Defaults to `false`.
+    pub clip: bool,
+
+    /// Whether the node is positioned, similar to `position: relative`. Positioned nodes are offset parents of their descendants.
+    ///
+    /// Defaults to `false`.
+    pub positioned: bool,
+
+    /// Whether the node is positioned relative to the viewport, similar to `position: fixed`.
+    ///
+    /// Defaults to `false`.
+    pub fixed: bool,
+
+    /// The scale of the node and its descendants, relative to the top left corner of the node.
+    ///
+    /// Defaults to `1.0` on both axes.
+    pub scale: Coords,
+
+    /// Whether the direction of the node is right-to-left.
+    ///
+    /// Defaults to the direction of the parent node, or `false` for the document element.
+    pub rtl: Option<bool>,
+
+    /// The writing mode of the node.
+    ///
+    /// Defaults to the writing mode of the parent node, or [`WritingMode::HorizontalTb`] for the document element.
+    pub writing_mode: Option<WritingMode>,
+
+    /// The line boxes of an inline node, relative to the border box of the node.
+    ///
+    /// Defaults to an empty vector, in which case the border box is the only client rect.
+    pub client_rects: Vec<Rect>,
+}
+
+impl SceneNode {
+    pub fn new(rect: Rect) -> Self {
+        SceneNode {
+            rect,
+            scroll: Coords::new(0.0),
+            border: SideObject {
+                top: 0.0,
+                right: 0.0,
+                bottom: 0.0,
+                left: 0.0,
+            },
+            clip: false,
+            positioned: false,
+            fixed: false,
+            scale: Coords::new(1.0),
+            rtl: None,
+            writing_mode: None,
+            client_rects: vec![],
+        }
+    }
+
+    /// Set `scroll` option.
+    pub fn scroll(mut self, value: Coords) -> Self {
+        self.scroll = value;
+        self
+    }
+
+    /// Set `border` option.
+    pub fn border(mut self, value: SideObject) -> Self {
+        self.border = value;
+        self
+    }
+
+    /// Set `clip` option.
+    pub fn clip(mut self, value: bool) -> Self {
+        self.clip = value;
+        self
+    }
+
+    /// Set `positioned` option.
+    pub fn positioned(mut self, value: bool) -> Self {
+        self.positioned = value;
+        self
+    }
+
+    /// Set `fixed` option.
+    pub fn fixed(mut self, value: bool) -> Self {
+        self.fixed = value;
+        self
+    }
+
+    /// Set `scale` option.
+    pub fn scale(mut self, value: Coords) -> Self {
+        self.scale = value;
+        self
+    }
+
+    /// Set `rtl` option.
+    pub fn rtl(mut self, value: bool) -> Self {
+        self.rtl = Some(value);
+        self
+    }
+
+    /// Set `writing_mode` option.
+    pub fn writing_mode(mut self, value: WritingMode) -> Self {
+        self.writing_mode = Some(value);
+        self
+    }
+
+    /// Set `client_rects` option.
+    pub fn client_rects(mut self, value: Vec<Rect>) -> Self {
+        self.client_rects = value;
+        self
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct SceneGraphEntry {
+    parent: Option<SceneNodeId>,
+    node: SceneNode,
+}
+
+/// Maps local coordinates of a node to viewport coordinates.
+#[derive(Clone, Debug, PartialEq)]
+struct Transform {
+    origin: Coords,
+    scale: Coords,
+}
+
+impl Transform {
+    fn apply(&self, rect: &Rect) -> Rect {
+        Rect {
+            x: self.origin.x + rect.x * self.scale.x,
+            y: self.origin.y + rect.y * self.scale.y,
+            width: rect.width * self.scale.x,
+            height: rect.height * self.scale.y,
+        }
+    }
+
+    fn invert(&self, rect: &Rect) -> Rect {
+        Rect {
+            x: (rect.x - self.origin.x) / self.scale.x,
+            y: (rect.y - self.origin.y) / self.scale.y,
+            width: rect.width / self.scale.x,
+            height: rect.height / self.scale.y,
+        }
+    }
+}
+
+/// Platform backed by an in-memory tree of [`SceneNode`]s.
+///
+/// The root of the tree is the document element, which is created with the platform.
+#[derive(Clone, Debug)]
+pub struct SceneGraphPlatform {
+    viewport: Dimensions,
+    entries: Vec<Option<SceneGraphEntry>>,
+}
+
+impl SceneGraphPlatform {
+    /// Create a new scene graph with the given viewport size and a document element of the given size.
+    pub fn new(viewport: Dimensions, document: Dimensions) -> Self {
+        SceneGraphPlatform {
+            viewport,
+            entries: vec![Some(SceneGraphEntry {
+                parent: None,
+                node: SceneNode::new(Rect {
+                    x: 0.0,
+                    y: 0.0,
+                    width: document.width,
+                    height: document.height,
+                }),
+            })],
+        }
+    }
+
+    /// The document element, which is the root of the tree.
+    pub fn document_element(&self) -> SceneNodeId {
+        SceneNodeId(0)
+    }
+
+    /// The size of the viewport.
+    pub fn viewport(&self) -> &Dimensions {
+        &self.viewport
+    }
+
+    /// Set the size of the viewport.
+    pub fn set_viewport(&mut self, viewport: Dimensions) {
+        self.viewport = viewport;
+    }
+
+    /// Add a node as child of the parent node.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the parent node was removed.
+    pub fn add_node(&mut self, parent: SceneNodeId, node: SceneNode) -> SceneNodeId {
+        self.node(parent);
+
+        self.entries.push(Some(SceneGraphEntry {
+            parent: Some(parent),
+            node,
+        }));
+        SceneNodeId(self.entries.len() - 1)
+    }
+
+    /// Remove a node from the tree. The node and its descendants are detached, so the fallible platform methods return [`PlatformError::DetachedElement`] for them.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the node is the document element.
+    pub fn remove_node(&mut self, id: SceneNodeId) -> Option<SceneNode> {
+        assert!(
+            id != self.document_element(),
+            "Document element can not be removed."
+        );
+
+        self.entries
+            .get_mut(id.0)
+            .and_then(Option::take)
+            .map(|entry| entry.node)
+    }
+
+    /// Get a node, if it was not removed.
+    pub fn get_node(&self, id: SceneNodeId) -> Option<&SceneNode> {
+        self.get_entry(id).map(|entry| &entry.node)
+    }
+
+    /// Get a mutable reference to a node, if it was not removed.
+    pub fn get_node_mut(&mut self, id: SceneNodeId) -> Option<&mut SceneNode> {
+        self.entries
+            .get_mut(id.0)
+            .and_then(Option::as_mut)
+            .map(|entry| &mut entry.node)
+    }
+
+    /// Get a node.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the node was removed.
+    pub fn node(&self, id: SceneNodeId) -> &SceneNode {
+        self.get_node(id).expect("Node should exist.")
+    }
+
+    /// Get a mutable reference to a node.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the node was removed.
+    pub fn node_mut(&mut self, id: SceneNodeId) -> &mut SceneNode {
+        self.get_node_mut(id).expect("Node should exist.")
+    }
+
+    /// Get the parent of a node. Returns `None` for the document element.
+    pub fn parent(&self, id: SceneNodeId) -> Option<SceneNodeId> {
+        self.get_entry(id).and_then(|entry| entry.parent)
+    }
+
+    /// Whether the node and all of its ancestors are part of the tree.
+    pub fn is_connected(&self, id: SceneNodeId) -> bool {
+        let mut current = Some(id);
+        while let Some(id) = current {
+            match self.get_entry(id) {
+                Some(entry) => current = entry.parent,
+                None => return false,
+            }
+        }
+        true
+    }
+
+    /// The border box of the node, relative to the viewport.
+    pub fn get_bounding_client_rect(&self, id: SceneNodeId) -> Rect {
+        let node = self.node(id);
+        self.border_box_transform(id).apply(&Rect {
+            x: 0.0,
+            y: 0.0,
+            width: node.rect.width,
+            height: node.rect.height,
+        })
+    }
+
+    fn get_entry(&self, id: SceneNodeId) -> Option<&SceneGraphEntry> {
+        self.entries.get(id.0).and_then(Option::as_ref)
+    }
+
+    fn check_connected(&self, id: SceneNodeId) -> Result<(), PlatformError> {
+        match self.is_connected(id) {
+            true => Ok(()),
+            false => Err(PlatformError::DetachedElement),
+        }
+    }
+
+    fn ancestors(&self, id: SceneNodeId) -> impl Iterator<Item = SceneNodeId> + '_ {
+        let mut current = self.parent(id);
+        core::iter::from_fn(move || {
+            let id = current?;
+            current = self.parent(id);
+            Some(id)
+        })
+    }
+
+    fn border_box_transform(&self, id: SceneNodeId) -> Transform {
+        let node = self.node(id);
+
+        let (origin, scale) = if node.fixed {
+            (
+                Coords {
+                    x: node.rect.x,
+                    y: node.rect.y,
+                },
+                Coords::new(1.0),
+            )
+        } else {
+            match self.parent(id) {
+                Some(parent) => {
+                    let transform = self.content_transform(parent);
+                    (
+                        Coords {
+                            x: transform.origin.x + node.rect.x * transform.scale.x,
+                            y: transform.origin.y + node.rect.y * transform.scale.y,
+                        },
+                        transform.scale,
+                    )
+                }
+                // The document element is scrolled by the viewport.
+                None => (
+                    Coords {
+                        x: node.rect.x - node.scroll.x,
+                        y: node.rect.y - node.scroll.y,
+                    },
+                    Coords::new(1.0),
+                ),
+            }
+        };
+
+        Transform {
+            origin,
+            scale: Coords {
+                x: scale.x * node.scale.x,
+                y: scale.y * node.scale.y,
+            },
+        }
+    }
+
+    fn content_transform(&self, id: SceneNodeId) -> Transform {
+        let node = self.node(id);
+        let transform = self.border_box_transform(id);
+        let scroll = match self.parent(id) {
+            Some(_) => node.scroll.clone(),
+            None => Coords::new(0.0),
+        };
+
+        Transform {
+            origin: Coords {
+                x: transform.origin.x + (node.border.left - scroll.x) * transform.scale.x,
+                y: transform.origin.y + (node.border.top - scroll.y) * transform.scale.y,
+            },
+            scale: transform.scale,
+        }
+    }
+
+    fn client_rect(&self, id: SceneNodeId) -> Rect {
+        let node = self.node(id);
+        self.border_box_transform(id).apply(&Rect {
+            x: node.border.left,
+            y: node.border.top,
+            width: node.rect.width - node.border.left - node.border.right,
+            height: node.rect.height - node.border.top - node.border.bottom,
+        })
+    }
+
+    fn element_or_virtual_rect(&self, element: ElementOrVirtual<SceneNodeId>) -> Rect {
+        match element {
+            ElementOrVirtual::Element(element) => self.get_bounding_client_rect(*element),
+            ElementOrVirtual::VirtualElement(virtual_element) => {
+                let rect = virtual_element.get_bounding_client_rect();
+                Rect {
+                    x: rect.x,
+                    y: rect.y,
+                    width: rect.width,
+                    height: rect.height,
+                }
+            }
+        }
+    }
+
+    fn root_boundary_rect(&self, root_boundary: RootBoundary) -> Rect {
+        match root_boundary {
+            RootBoundary::Viewport | RootBoundary::LayoutViewport => Rect {
+                x: 0.0,
+                y: 0.0,
+                width: self.viewport.width,
+                height: self.viewport.height,
+            },
+            RootBoundary::Document => {
+                let document = self.node(self.document_element());
+                Rect {
+                    x: -document.scroll.x,
+                    y: -document.scroll.y,
+                    width: document.rect.width.max(self.viewport.width),
+                    height: document.rect.height.max(self.viewport.height),
+                }
+            }
+            RootBoundary::Rect(rect) => rect,
+        }
+    }
+}
+
+impl Platform<SceneNodeId, SceneWindow> for SceneGraphPlatform {
+    fn get_element_rects(&self, args: GetElementRectsArgs<SceneNodeId>) -> ElementRects {
+        let offset_parent = self
+            .get_offset_parent(args.floating)
+            .expect("Platform implements get_offset_parent.");
+        let rect = self.element_or_virtual_rect(args.reference);
+        let dimensions = self.get_dimensions(args.floating);
+
+        ElementRects {
+            reference: match offset_parent {
+                OwnedElementOrWindow::Element(offset_parent) => {
+                    self.content_transform(offset_parent).invert(&rect)
+                }
+                OwnedElementOrWindow::Window(_) => match args.strategy {
+                    Strategy::Absolute => {
+                        let scroll = &self.node(self.document_element()).scroll;
+                        Rect {
+                            x: rect.x + scroll.x,
+                            y: rect.y + scroll.y,
+                            ..rect
+                        }
+                    }
+                    Strategy::Fixed => rect,
+                },
+            },
+            floating: Rect {
+                x: 0.0,
+                y: 0.0,
+                width: dimensions.width,
+                height: dimensions.height,
+            },
+        }
+    }
+
+    fn get_clipping_rect(&self, args: GetClippingRectArgs<SceneNodeId>) -> Rect {
+        let clipping_elements: Vec<SceneNodeId> = match args.boundary {
+            Boundary::ClippingAncestors => {
+                if self.node(*args.element).fixed {
+                    vec![]
+                } else {
+                    self.ancestors(*args.element)
+                        .take_while(|id| *id != self.document_element())
+                        .filter(|id| self.node(*id).clip)
+                        .collect()
+                }
+            }
+            Boundary::Element(element) => vec![element],
+            Boundary::Elements(elements) => elements,
+        };
+
+        clipping_elements
+            .into_iter()
+            .map(|id| self.client_rect(id))
+            .fold(
+                self.root_boundary_rect(args.root_boundary),
+                |accumulator, rect| {
+                    let left = accumulator.x.max(rect.x);
+                    let top = accumulator.y.max(rect.y);
+                    let right = (accumulator.x + accumulator.width).min(rect.x + rect.width);
+                    let bottom = (accumulator.y + accumulator.height).min(rect.y + rect.height);
+
+                    Rect {
+                        x: left,
+                        y: top,
+                        width: right - left,
+                        height: bottom - top,
+                    }
+                },
+            )
+    }
+
+    fn get_dimensions(&self, element: &SceneNodeId) -> Dimensions {
+        let node = self.node(*element);
+        Dimensions {
+            width: node.rect.width,
+            height: node.rect.height,
+        }
+    }
+
+    fn convert_offset_parent_relative_rect_to_viewport_relative_rect(
+        &self,
+        args: ConvertOffsetParentRelativeRectToViewportRelativeRectArgs<SceneNodeId, SceneWindow>,
+    ) -> Option<Rect> {
+        Some(match args.offset_parent {
+            Some(ElementOrWindow::Element(offset_parent)) => {
+                self.content_transform(*offset_parent).apply(&args.rect)
+            }
+            Some(ElementOrWindow::Window(_)) => match args.strategy {
+                Strategy::Absolute => {
+                    let scroll = &self.node(self.document_element()).scroll;
+                    Rect {
+                        x: args.rect.x - scroll.x,
+                        y: args.rect.y - scroll.y,
+                        ..args.rect
+                    }
+                }
+                Strategy::Fixed => args.rect,
+            },
+            None => args.rect,
+        })
+    }
+
+    fn get_offset_parent(
+        &self,
+        element: &SceneNodeId,
+    ) -> Option<OwnedElementOrWindow<SceneNodeId, SceneWindow>> {
+        if self.node(*element).fixed {
+            return Some(OwnedElementOrWindow::Window(SceneWindow));
+        }
+
+        Some(
+            self.ancestors(*element)
+                .take_while(|id| *id != self.document_element())
+                .find(|id| {
+                    let node = self.node(*id);
+                    node.positioned || node.fixed || node.scale != Coords::new(1.0)
+                })
+                .map_or(
+                    OwnedElementOrWindow::Window(SceneWindow),
+                    OwnedElementOrWindow::Element,
+                ),
+        )
+    }
+
+    fn get_document_element(&self, _element: &SceneNodeId) -> Option<SceneNodeId> {
+        Some(self.document_element())
+    }
+
+    fn get_client_rects(
+        &self,
+        element: ElementOrVirtual<SceneNodeId>,
+    ) -> Option<Vec<ClientRectObject>> {
+        Some(match element {
+            ElementOrVirtual::Element(element) => {
+                let node = self.node(*element);
+                if node.client_rects.is_empty() {
+                    vec![rect_to_client_rect(self.get_bounding_client_rect(*element))]
+                } else {
+                    let transform = self.border_box_transform(*element);
+                    node.client_rects
+                        .iter()
+                        .map(|rect| rect_to_client_rect(transform.apply(rect)))
+                        .collect()
+                }
+            }
+            ElementOrVirtual::VirtualElement(virtual_element) => virtual_element
+                .get_client_rects()
+                .unwrap_or_else(|| vec![virtual_element.get_bounding_client_rect()]),
+        })
+    }
+
+    fn is_rtl(&self, element: &SceneNodeId) -> Option<bool> {
+        Some(
+            core::iter::once(*element)
+                .chain(self.ancestors(*element))
+                .find_map(|id| self.node(id).rtl)
+                .unwrap_or(false),
+        )
+    }
+
+    fn get_writing_mode(&self, element: &SceneNodeId) -> Option<WritingMode> {
+        Some(
+            core::iter::once(*element)
+                .chain(self.ancestors(*element))
+                .find_map(|id| self.node(id).writing_mode)
+                .unwrap_or_default(),
+        )
+    }
+
+    fn get_scale(&self, element: &SceneNodeId) -> Option<Coords> {
+        Some(self.border_box_transform(*element).scale)
+    }
+
+    fn get_client_length(&self, element: &SceneNodeId, length: Length) -> Option<f64> {
+        let node = self.node(*element);
+        Some(match length {
+            Length::Width => node.rect.width - node.border.left - node.border.right,
+            Length::Height => node.rect.height - node.border.top - node.border.bottom,
+        })
+    }
+
+    fn try_get_element_rects(
+        &self,
+        args: GetElementRectsArgs<SceneNodeId>,
+    ) -> Result<ElementRects, PlatformError> {
+        if let ElementOrVirtual::Element(reference) = &args.reference {
+            self.check_connected(**reference)?;
+        }
+        self.check_connected(*args.floating)?;
+        Ok(self.get_element_rects(args))
+    }
+
+    fn try_get_clipping_rect(
+        &self,
+        args: GetClippingRectArgs<SceneNodeId>,
+    ) -> Result<Rect, PlatformError> {
+        self.check_connected(*args.element)?;
+        match &args.boundary {
+            Boundary::ClippingAncestors => {}
+            Boundary::Element(element) => self.check_connected(*element)?,
+            Boundary::Elements(elements) => elements
+                .iter()
+                .try_for_each(|element| self.check_connected(*element))?,
+        }
+        Ok(self.get_clipping_rect(args))
+    }
+
+    fn try_get_dimensions(&self, element: &SceneNodeId) -> Result<Dimensions, PlatformError> {
+        self.check_connected(*element)?;
+        Ok(self.get_dimensions(element))
+    }
+
+    fn try_convert_offset_parent_relative_rect_to_viewport_relative_rect(
+        &self,
+        args: ConvertOffsetParentRelativeRectToViewportRelativeRectArgs<SceneNodeId, SceneWindow>,
+    ) -> Result<Option<Rect>, PlatformError> {
+        if let Some(ElementOrWindow::Element(offset_parent)) = &args.offset_parent {
+            self.check_connected(**offset_parent)?;
+        }
+        Ok(self.convert_offset_parent_relative_rect_to_viewport_relative_rect(args))
+    }
+
+    fn try_get_offset_parent(
+        &self,
+        element: &SceneNodeId,
+    ) -> Result<Option<OwnedElementOrWindow<SceneNodeId, SceneWindow>>, PlatformError> {
+        self.check_connected(*element)?;
+        Ok(self.get_offset_parent(element))
+    }
+
+    fn try_get_document_element(
+        &self,
+        element: &SceneNodeId,
+    ) -> Result<Option<SceneNodeId>, PlatformError> {
+        self.check_connected(*element)?;
+        Ok(self.get_document_element(element))
+    }
+
+    fn try_get_client_rects(
+        &self,
+        element: ElementOrVirtual<SceneNodeId>,
+    ) -> Result<Option<Vec<ClientRectObject>>, PlatformError> {
+        if let ElementOrVirtual::Element(element) = &element {
+            self.check_connected(**element)?;
+        }
+        Ok(self.get_client_rects(element))
+    }
+
+    fn try_is_rtl(&self, element: &SceneNodeId) -> Result<Option<bool>, PlatformError> {
+        self.check_connected(*element)?;
+        Ok(self.is_rtl(element))
+    }
+
+    fn try_get_writing_mode(
+        &self,
+        element: &SceneNodeId,
+    ) -> Result<Option<WritingMode>, PlatformError> {
+        self.check_connected(*element)?;
+        Ok(self.get_writing_mode(element))
+    }
+
+    fn try_get_scale(&self, element: &SceneNodeId) -> Result<Option<Coords>, PlatformError> {
+        self.check_connected(*element)?;
+        Ok(self.get_scale(element))
+    }
+
+    fn try_get_client_length(
+        &self,
+        element: &SceneNodeId,
+        length: Length,
+    ) -> Result<Option<f64>, PlatformError> {
+        self.check_connected(*element)?;
+        Ok(self.get_client_length(element, length))
+    }
+
+    fn detect_overflow(
+        &self,
+        state: MiddlewareState<SceneNodeId, SceneWindow>,
+        options: DetectOverflowOptions<SceneNodeId>,
+    ) -> SideObject {
+        detect_overflow(state, options)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use floating_ui_utils::{LogicalPlacement, Placement};
+
+    use super::*;
+    use crate::{
+        compute_position::{compute_position, try_compute_position},
+        error::ComputePositionError,
+        middleware::{Flip, FlipOptions, Offset, OffsetOptions},
+        types::ComputePositionConfig,
+    };
+
+    fn rect(x: f64, y: f64, width: f64, height: f64) -> Rect {
+        Rect {
+            x,
+            y,
+            width,
+            height,
+        }
+    }
+
+    fn platform() -> SceneGraphPlatform {
+        SceneGraphPlatform::new(
+            Dimensions {
+                width: 1000.0,
+                height: 800.0,
+            },
+            Dimensions {
+                width: 1000.0,
+                height: 2000.0,
+            },
+        )
+    }
+
+    #[test]
+    fn test_document() {
+        let mut platform = platform();
+        let root = platform.document_element();
+        let reference = platform.add_node(root, SceneNode::new(rect(100.0, 100.0, 50.0, 20.0)));
+        let floating = platform.add_node(root, SceneNode::new(rect(0.0, 0.0, 30.0, 10.0)));
+
+        let position = compute_position(
+            (&reference).into(),
+            &floating,
+            ComputePositionConfig::new(&platform),
+        );
+        assert_eq!((position.x, position.y), (110.0, 120.0));
+
+        // Absolute positions are relative to the document, so scrolling the viewport has no effect.
+        platform.node_mut(root).scroll = Coords { x: 0.0, y: 50.0 };
+        let position = compute_position(
+            (&reference).into(),
+            &floating,
+            ComputePositionConfig::new(&platform),
+        );
+        assert_eq!((position.x, position.y), (110.0, 120.0));
+
+        let position = compute_position(
+            (&reference).into(),
+            &floating,
+            ComputePositionConfig::new(&platform).strategy(Strategy::Fixed),
+        );
+        assert_eq!((position.x, position.y), (110.0, 70.0));
+    }
+
+    #[test]
+    fn test_offset_parent() {
+        let mut platform = platform();
+        let root = platform.document_element();
+        let container = platform.add_node(
+            root,
+            SceneNode::new(rect(200.0, 200.0, 300.0, 300.0))
+                .border(SideObject {
+                    top: 10.0,
+                    right: 10.0,
+                    bottom: 10.0,
+                    left: 10.0,
+                })
+                .scroll(Coords { x: 0.0, y: 20.0 })
+                .scale(Coords::new(2.0))
+                .positioned(true),
+        );
+        let reference = platform.add_node(container, SceneNode::new(rect(10.0, 50.0, 40.0, 20.0)));
+        let floating = platform.add_node(container, SceneNode::new(rect(0.0, 0.0, 30.0, 10.0)));
+
+        assert_eq!(
+            platform.get_offset_parent(&floating),
+            Some(OwnedElementOrWindow::Element(container))
+        );
+        assert_eq!(platform.get_scale(&floating), Some(Coords::new(2.0)));
+        assert_eq!(
+            platform.get_bounding_client_rect(reference),
+            rect(240.0, 280.0, 80.0, 40.0)
+        );
+
+        let position = compute_position(
+            (&reference).into(),
+            &floating,
+            ComputePositionConfig::new(&platform),
+        );
+        assert_eq!((position.x, position.y), (15.0, 70.0));
+    }
+
+    #[test]
+    fn test_clipping() {
+        let mut platform = platform();
+        let root = platform.document_element();
+        let container = platform.add_node(
+            root,
+            SceneNode::new(rect(0.0, 0.0, 200.0, 200.0)).clip(true),
+        );
+        let reference = platform.add_node(container, SceneNode::new(rect(50.0, 170.0, 50.0, 20.0)));
+        let floating = platform.add_node(container, SceneNode::new(rect(0.0, 0.0, 30.0, 20.0)));
+
+        assert_eq!(
+            platform.get_clipping_rect(GetClippingRectArgs {
+                element: &floating,
+                boundary: Boundary::ClippingAncestors,
+                root_boundary: RootBoundary::Viewport,
+                strategy: Strategy::Absolute,
+            }),
+            rect(0.0, 0.0, 200.0, 200.0)
+        );
+
+        let position = compute_position(
+            (&reference).into(),
+            &floating,
+            ComputePositionConfig::new(&platform)
+                .middleware(vec![Box::new(Flip::new(FlipOptions::default()))]),
+        );
+        assert_eq!(position.placement, Placement::Top);
+        assert_eq!((position.x, position.y), (60.0, 150.0));
+    }
+
+    #[test]
+    fn test_rtl() {
+        let mut platform = platform();
+        let root = platform.document_element();
+        let container =
+            platform.add_node(root, SceneNode::new(rect(0.0, 0.0, 200.0, 200.0)).rtl(true));
+        let child = platform.add_node(container, SceneNode::new(rect(0.0, 0.0, 10.0, 10.0)));
+
+        assert_eq!(platform.is_rtl(&root), Some(false));
+        assert_eq!(platform.is_rtl(&child), Some(true));
+    }
+
+    #[test]
+    fn test_writing_mode() {
+        let mut platform = platform();
+        let root = platform.document_element();
+        platform.node_mut(root).writing_mode = Some(WritingMode::VerticalRl);
+        let reference = platform.add_node(root, SceneNode::new(rect(20.0, 100.0, 50.0, 20.0)));
+        let floating = platform.add_node(root, SceneNode::new(rect(0.0, 0.0, 30.0, 10.0)));
+
+        assert_eq!(
+            platform.get_writing_mode(&floating),
+            Some(WritingMode::VerticalRl)
+        );
+
+        // The inline axis is vertical, so the start of the block axis is on the right.
+        let position = compute_position(
+            (&reference).into(),
+            &floating,
+            ComputePositionConfig::new(&platform)
+                .logical_placement(LogicalPlacement::InlineStartEnd),
+        );
+        assert_eq!(position.placement, Placement::TopEnd);
+        assert_eq!((position.x, position.y), (20.0, 90.0));
+
+        // Block end is on the left, which overflows the viewport.
+        let position = compute_position(
+            (&reference).into(),
+            &floating,
+            ComputePositionConfig::new(&platform)
+                .logical_placement(LogicalPlacement::BlockEndStart)
+                .middleware(vec![
+                    Box::new(Offset::new(OffsetOptions::Value(5.0))),
+                    Box::new(Flip::new(FlipOptions::default())),
+                ]),
+        );
+        assert_eq!(position.placement, Placement::RightStart);
+        assert_eq!((position.x, position.y), (75.0, 100.0));
+    }
+
+    #[test]
+    fn test_detached() {
+        let mut platform = platform();
+        let root = platform.document_element();
+        let container = platform.add_node(root, SceneNode::new(rect(0.0, 0.0, 200.0, 200.0)));
+        let reference = platform.add_node(root, SceneNode::new(rect(0.0, 0.0, 50.0, 20.0)));
+        let floating = platform.add_node(container, SceneNode::new(rect(0.0, 0.0, 30.0, 10.0)));
+
+        platform.remove_node(container);
+        assert!(!platform.is_connected(floating));
+        assert_eq!(
+            try_compute_position(
+                (&reference).into(),
+                &floating,
+                ComputePositionConfig::new(&platform),
+            ),
+            Err(ComputePositionError::Platform(
+                PlatformError::DetachedElement
+            ))
+        );
+    }
+}
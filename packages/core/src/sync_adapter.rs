@@ -0,0 +1,449 @@
+use alloc::{boxed::Box, vec, vec::Vec};
+use core::cell::RefCell;
+use core::fmt::{self, Debug};
+
+use floating_ui_utils::{
+    ClientRectObject, Coords, Dimensions, ElementOrVirtual, ElementRects, Length,
+    OwnedElementOrVirtual, OwnedElementOrWindow, Rect, SideObject, Strategy, WritingMode,
+};
+
+use crate::detect_overflow::{DetectOverflowOptions, detect_overflow};
+use crate::types::{
+    AsyncMiddleware, AsyncMiddlewareState, AsyncPlatform, Boundary,
+    ConvertOffsetParentRelativeRectToViewportRelativeRectArgs, Elements, GetClippingRectArgs,
+    GetElementRectsArgs, LocalBoxFuture, Middleware, MiddlewareReturn, MiddlewareState, Platform,
+    RootBoundary,
+};
+
+pub(crate) const EMPTY_RECT: Rect = Rect {
+    x: 0.0,
+    y: 0.0,
+    width: 0.0,
+    height: 0.0,
+};
+
+/// Owned arguments of a [`Platform`] method call.
+#[derive(Clone, PartialEq)]
+enum PlatformQuery<Element: Clone + 'static, Window: Clone> {
+    ElementRects {
+        reference: OwnedElementOrVirtual<Element>,
+        floating: Element,
+        strategy: Strategy,
+    },
+    ClippingRect {
+        element: Element,
+        boundary: Boundary<Element>,
+        root_boundary: RootBoundary,
+        strategy: Strategy,
+    },
+    Dimensions {
+        element: Element,
+    },
+    ViewportRelativeRect {
+        elements: Option<(OwnedElementOrVirtual<Element>, Element)>,
+        rect: Rect,
+        offset_parent: Option<OwnedElementOrWindow<Element, Window>>,
+        strategy: Strategy,
+    },
+    OffsetParent {
+        element: Element,
+    },
+    DocumentElement {
+        element: Element,
+    },
+    ClientRects {
+        element: OwnedElementOrVirtual<Element>,
+    },
+    IsRtl {
+        element: Element,
+    },
+    WritingMode {
+        element: Element,
+    },
+    Scale {
+        element: Element,
+    },
+    ClientLength {
+        element: Element,
+        length: Length,
+    },
+}
+
+impl<Element: Clone + 'static, Window: Clone> PlatformQuery<Element, Window> {
+    async fn resolve(
+        &self,
+        platform: &dyn AsyncPlatform<Element, Window>,
+    ) -> PlatformAnswer<Element, Window> {
+        match self {
+            PlatformQuery::ElementRects {
+                reference,
+                floating,
+                strategy,
+            } => PlatformAnswer::ElementRects(
+                platform
+                    .get_element_rects(GetElementRectsArgs {
+                        reference: reference.into(),
+                        floating,
+                        strategy: *strategy,
+                    })
+                    .await,
+            ),
+            PlatformQuery::ClippingRect {
+                element,
+                boundary,
+                root_boundary,
+                strategy,
+            } => PlatformAnswer::ClippingRect(
+                platform
+                    .get_clipping_rect(GetClippingRectArgs {
+                        element,
+                        boundary: boundary.clone(),
+                        root_boundary: root_boundary.clone(),
+                        strategy: *strategy,
+                    })
+                    .await,
+            ),
+            PlatformQuery::Dimensions { element } => {
+                PlatformAnswer::Dimensions(platform.get_dimensions(element).await)
+            }
+            PlatformQuery::ViewportRelativeRect {
+                elements,
+                rect,
+                offset_parent,
+                strategy,
+            } => PlatformAnswer::ViewportRelativeRect(
+                platform
+                    .convert_offset_parent_relative_rect_to_viewport_relative_rect(
+                        ConvertOffsetParentRelativeRectToViewportRelativeRectArgs {
+                            elements: elements.as_ref().map(|(reference, floating)| Elements {
+                                reference: reference.into(),
+                                floating,
+                            }),
+                            rect: rect.clone(),
+                            offset_parent: offset_parent
+                                .as_ref()
+                                .map(|offset_parent| offset_parent.into()),
+                            strategy: *strategy,
+                        },
+                    )
+                    .await,
+            ),
+            PlatformQuery::OffsetParent { element } => {
+                PlatformAnswer::OffsetParent(platform.get_offset_parent(element).await)
+            }
+            PlatformQuery::DocumentElement { element } => {
+                PlatformAnswer::DocumentElement(platform.get_document_element(element).await)
+            }
+            PlatformQuery::ClientRects { element } => {
+                PlatformAnswer::ClientRects(platform.get_client_rects(element.into()).await)
+            }
+            PlatformQuery::IsRtl { element } => {
+                PlatformAnswer::IsRtl(platform.is_rtl(element).await)
+            }
+            PlatformQuery::WritingMode { element } => {
+                PlatformAnswer::WritingMode(platform.get_writing_mode(element).await)
+            }
+            PlatformQuery::Scale { element } => {
+                PlatformAnswer::Scale(platform.get_scale(element).await)
+            }
+            PlatformQuery::ClientLength { element, length } => {
+                PlatformAnswer::ClientLength(platform.get_client_length(element, *length).await)
+            }
+        }
+    }
+}
+
+/// Return value of a [`Platform`] method call.
+#[derive(Clone)]
+enum PlatformAnswer<Element, Window> {
+    ElementRects(ElementRects),
+    ClippingRect(Rect),
+    Dimensions(Dimensions),
+    ViewportRelativeRect(Option<Rect>),
+    OffsetParent(Option<OwnedElementOrWindow<Element, Window>>),
+    DocumentElement(Option<Element>),
+    ClientRects(Option<Vec<ClientRectObject>>),
+    IsRtl(Option<bool>),
+    WritingMode(Option<WritingMode>),
+    Scale(Option<Coords>),
+    ClientLength(Option<f64>),
+}
+
+type CachedAnswer<Element, Window> = (
+    PlatformQuery<Element, Window>,
+    PlatformAnswer<Element, Window>,
+);
+
+/// Synchronous [`Platform`] answering from measurements previously resolved by an [`AsyncPlatform`].
+///
+/// Every query which has not been resolved yet is recorded and answered with a placeholder value,
+/// so a single run discovers all measurements which do not depend on another missing measurement.
+struct CachedPlatform<'a, Element: Clone + 'static, Window: Clone> {
+    platform: &'a dyn AsyncPlatform<Element, Window>,
+    answers: RefCell<Vec<CachedAnswer<Element, Window>>>,
+    missing: RefCell<Vec<PlatformQuery<Element, Window>>>,
+}
+
+impl<'a, Element: Clone + PartialEq + 'static, Window: Clone + PartialEq>
+    CachedPlatform<'a, Element, Window>
+{
+    fn new(platform: &'a dyn AsyncPlatform<Element, Window>) -> Self {
+        CachedPlatform {
+            platform,
+            answers: RefCell::new(vec![]),
+            missing: RefCell::new(vec![]),
+        }
+    }
+
+    fn query(
+        &self,
+        query: PlatformQuery<Element, Window>,
+    ) -> Option<PlatformAnswer<Element, Window>> {
+        // A middleware performs a handful of distinct queries, so a linear search is cheaper than hashing or ordering them.
+        let answer = self
+            .answers
+            .borrow()
+            .iter()
+            .find(|(cached_query, _)| *cached_query == query)
+            .map(|(_, answer)| answer.clone());
+
+        if answer.is_none() {
+            let mut missing = self.missing.borrow_mut();
+            if !missing.contains(&query) {
+                missing.push(query);
+            }
+        }
+
+        answer
+    }
+
+    /// Resolves all missing queries. Returns `false` if all queries were answered.
+    async fn resolve_missing(&self) -> bool {
+        let missing = self.missing.take();
+        if missing.is_empty() {
+            return false;
+        }
+
+        for query in missing {
+            let answer = query.resolve(self.platform).await;
+            self.answers.borrow_mut().push((query, answer));
+        }
+
+        true
+    }
+}
+
+impl<Element: Clone + 'static, Window: Clone> Debug for CachedPlatform<'_, Element, Window> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CachedPlatform")
+            .field("platform", &self.platform)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<Element: Clone + PartialEq + 'static, Window: Clone + PartialEq + 'static>
+    Platform<Element, Window> for CachedPlatform<'_, Element, Window>
+{
+    fn get_element_rects(&self, args: GetElementRectsArgs<Element>) -> ElementRects {
+        match self.query(PlatformQuery::ElementRects {
+            reference: args.reference.into(),
+            floating: args.floating.clone(),
+            strategy: args.strategy,
+        }) {
+            Some(PlatformAnswer::ElementRects(element_rects)) => element_rects,
+            _ => ElementRects {
+                reference: EMPTY_RECT,
+                floating: EMPTY_RECT,
+            },
+        }
+    }
+
+    fn get_clipping_rect(&self, args: GetClippingRectArgs<Element>) -> Rect {
+        match self.query(PlatformQuery::ClippingRect {
+            element: args.element.clone(),
+            boundary: args.boundary,
+            root_boundary: args.root_boundary,
+            strategy: args.strategy,
+        }) {
+            Some(PlatformAnswer::ClippingRect(rect)) => rect,
+            _ => EMPTY_RECT,
+        }
+    }
+
+    fn get_dimensions(&self, element: &Element) -> Dimensions {
+        match self.query(PlatformQuery::Dimensions {
+            element: element.clone(),
+        }) {
+            Some(PlatformAnswer::Dimensions(dimensions)) => dimensions,
+            _ => Dimensions {
+                width: 0.0,
+                height: 0.0,
+            },
+        }
+    }
+
+    fn convert_offset_parent_relative_rect_to_viewport_relative_rect(
+        &self,
+        args: ConvertOffsetParentRelativeRectToViewportRelativeRectArgs<Element, Window>,
+    ) -> Option<Rect> {
+        match self.query(PlatformQuery::ViewportRelativeRect {
+            elements: args
+                .elements
+                .map(|elements| (elements.reference.into(), elements.floating.clone())),
+            rect: args.rect,
+            offset_parent: args.offset_parent.map(|offset_parent| offset_parent.into()),
+            strategy: args.strategy,
+        }) {
+            Some(PlatformAnswer::ViewportRelativeRect(rect)) => rect,
+            _ => None,
+        }
+    }
+
+    fn get_offset_parent(
+        &self,
+        element: &Element,
+    ) -> Option<OwnedElementOrWindow<Element, Window>> {
+        match self.query(PlatformQuery::OffsetParent {
+            element: element.clone(),
+        }) {
+            Some(PlatformAnswer::OffsetParent(offset_parent)) => offset_parent,
+            _ => None,
+        }
+    }
+
+    fn get_document_element(&self, element: &Element) -> Option<Element> {
+        match self.query(PlatformQuery::DocumentElement {
+            element: element.clone(),
+        }) {
+            Some(PlatformAnswer::DocumentElement(document_element)) => document_element,
+            // Any element is a safe placeholder, as the result will be discarded.
+            _ => Some(element.clone()),
+        }
+    }
+
+    fn get_client_rects(
+        &self,
+        element: ElementOrVirtual<Element>,
+    ) -> Option<Vec<ClientRectObject>> {
+        match self.query(PlatformQuery::ClientRects {
+            element: element.into(),
+        }) {
+            Some(PlatformAnswer::ClientRects(client_rects)) => client_rects,
+            _ => None,
+        }
+    }
+
+    fn is_rtl(&self, element: &Element) -> Option<bool> {
+        match self.query(PlatformQuery::IsRtl {
+            element: element.clone(),
+        }) {
+            Some(PlatformAnswer::IsRtl(is_rtl)) => is_rtl,
+            _ => None,
+        }
+    }
+
+    fn get_writing_mode(&self, element: &Element) -> Option<WritingMode> {
+        match self.query(PlatformQuery::WritingMode {
+            element: element.clone(),
+        }) {
+            Some(PlatformAnswer::WritingMode(writing_mode)) => writing_mode,
+            _ => None,
+        }
+    }
+
+    fn get_scale(&self, element: &Element) -> Option<Coords> {
+        match self.query(PlatformQuery::Scale {
+            element: element.clone(),
+        }) {
+            Some(PlatformAnswer::Scale(scale)) => scale,
+            _ => None,
+        }
+    }
+
+    fn get_client_length(&self, element: &Element, length: Length) -> Option<f64> {
+        match self.query(PlatformQuery::ClientLength {
+            element: element.clone(),
+            length,
+        }) {
+            Some(PlatformAnswer::ClientLength(client_length)) => client_length,
+            _ => None,
+        }
+    }
+
+    fn detect_overflow(
+        &self,
+        state: MiddlewareState<Element, Window>,
+        options: DetectOverflowOptions<Element>,
+    ) -> SideObject {
+        detect_overflow(state, options)
+    }
+
+    fn has_placeholders(&self) -> bool {
+        !self.missing.borrow().is_empty()
+    }
+}
+
+/// Runs synchronous code against an [`AsyncPlatform`].
+///
+/// The closure is executed until every platform method it called could be answered from resolved measurements.
+/// Each run resolves all measurements it requested, so the number of runs is one more than the longest chain of measurements
+/// depending on each other (e.g. the scale of the offset parent), not the number of measurements.
+/// Only the value of the last run, which saw real measurements only, is returned.
+pub(crate) async fn run_sync<Element, Window, T>(
+    platform: &dyn AsyncPlatform<Element, Window>,
+    f: impl Fn(&dyn Platform<Element, Window>) -> T,
+) -> T
+where
+    Element: Clone + PartialEq + 'static,
+    Window: Clone + PartialEq + 'static,
+{
+    let cached_platform = CachedPlatform::new(platform);
+
+    loop {
+        let value = f(&cached_platform);
+
+        if !cached_platform.resolve_missing().await {
+            return value;
+        }
+    }
+}
+
+pub(crate) fn to_sync_state<'a, Element: Clone + 'static, Window: Clone>(
+    state: &AsyncMiddlewareState<'a, Element, Window>,
+    platform: &'a dyn Platform<Element, Window>,
+) -> MiddlewareState<'a, Element, Window> {
+    MiddlewareState {
+        x: state.x,
+        y: state.y,
+        initial_placement: state.initial_placement,
+        placement: state.placement,
+        strategy: state.strategy,
+        middleware_data: state.middleware_data,
+        elements: state.elements.clone(),
+        rects: state.rects,
+        platform,
+    }
+}
+
+impl<Element, Window, M> AsyncMiddleware<Element, Window> for M
+where
+    Element: Clone + PartialEq + 'static,
+    Window: Clone + PartialEq + 'static,
+    M: Middleware<Element, Window>,
+{
+    fn name(&self) -> &'static str {
+        Middleware::name(self)
+    }
+
+    fn compute<'a>(
+        &'a self,
+        state: AsyncMiddlewareState<'a, Element, Window>,
+    ) -> LocalBoxFuture<'a, MiddlewareReturn> {
+        Box::pin(async move {
+            run_sync(state.platform, |platform| {
+                Middleware::compute(self, to_sync_state(&state, platform))
+            })
+            .await
+        })
+    }
+}
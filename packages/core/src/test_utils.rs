@@ -1,14 +1,21 @@
+use std::future::Future;
+use std::pin::{Pin, pin};
+use std::task::{Context, Poll, Waker};
+
 use floating_ui_utils::{Dimensions, ElementRects, Rect, SideObject};
 
 use crate::{
     detect_overflow::{DetectOverflowOptions, detect_overflow},
-    types::{GetClippingRectArgs, GetElementRectsArgs, MiddlewareState, Platform},
+    types::{
+        AsyncPlatform, GetClippingRectArgs, GetElementRectsArgs, LocalBoxFuture, MiddlewareState,
+        Platform,
+    },
 };
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct Element {}
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct Window {}
 
 pub const REFERENCE: Element = Element {};
@@ -63,3 +70,65 @@ impl Platform<Element, Window> for TestPlatform {
 }
 
 pub const PLATFORM: TestPlatform = TestPlatform {};
+
+/// Future which is pending once before resolving, to simulate an asynchronous measurement.
+struct Measure<T>(Option<T>, bool);
+
+impl<T: Unpin> Future for Measure<T> {
+    type Output = T;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        if self.1 {
+            Poll::Ready(
+                self.0
+                    .take()
+                    .expect("Future should not be polled after completion."),
+            )
+        } else {
+            self.1 = true;
+            cx.waker().wake_by_ref();
+            Poll::Pending
+        }
+    }
+}
+
+pub fn measure<'a, T: Unpin + 'a>(value: T) -> LocalBoxFuture<'a, T> {
+    Box::pin(Measure(Some(value), false))
+}
+
+#[derive(Debug)]
+pub struct TestAsyncPlatform {}
+
+impl AsyncPlatform<Element, Window> for TestAsyncPlatform {
+    fn get_element_rects<'a>(
+        &'a self,
+        args: GetElementRectsArgs<'a, Element>,
+    ) -> LocalBoxFuture<'a, ElementRects> {
+        measure(PLATFORM.get_element_rects(args))
+    }
+
+    fn get_clipping_rect<'a>(
+        &'a self,
+        args: GetClippingRectArgs<'a, Element>,
+    ) -> LocalBoxFuture<'a, Rect> {
+        measure(PLATFORM.get_clipping_rect(args))
+    }
+
+    fn get_dimensions<'a>(&'a self, element: &'a Element) -> LocalBoxFuture<'a, Dimensions> {
+        measure(PLATFORM.get_dimensions(element))
+    }
+}
+
+pub const ASYNC_PLATFORM: TestAsyncPlatform = TestAsyncPlatform {};
+
+/// Polls a future to completion on the current thread.
+pub fn block_on<F: Future>(future: F) -> F::Output {
+    let mut future = pin!(future);
+    let mut cx = Context::from_waker(Waker::noop());
+
+    loop {
+        if let Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+            return output;
+        }
+    }
+}
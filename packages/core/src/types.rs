@@ -1,15 +1,25 @@
-use std::fmt::Debug;
-use std::{collections::HashMap, ptr};
+use alloc::{
+    boxed::Box,
+    collections::BTreeMap,
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::any::{Any, TypeId};
+use core::fmt::Debug;
+use core::pin::Pin;
+use core::ptr;
 
-use dyn_derive::dyn_trait;
-use serde::{Serialize, de::DeserializeOwned};
+use serde::{Deserialize, Serialize, de::DeserializeOwned};
 
 use floating_ui_utils::{
-    ClientRectObject, Coords, Dimensions, ElementOrVirtual, ElementOrWindow, ElementRects, Length,
-    OwnedElementOrWindow, Placement, Rect, SideObject, Strategy,
+    ClientRectObject, Coords, Dimensions, DynPartialEq, ElementOrVirtual, ElementOrWindow,
+    ElementRects, Length, LogicalPlacement, OwnedElementOrWindow, Placement, Rect, SideObject,
+    Strategy, WritingMode,
 };
 
 use crate::detect_overflow::DetectOverflowOptions;
+use crate::error::{MiddlewareDataError, PlatformError};
+use crate::middleware::MiddlewareSpec;
 
 pub type DerivableFn<'a, Element, Window, T> = &'a dyn Fn(MiddlewareState<Element, Window>) -> T;
 
@@ -128,6 +138,10 @@ pub trait Platform<Element: Clone, Window: Clone>: Debug {
         None
     }
 
+    fn get_writing_mode(&self, _element: &Element) -> Option<WritingMode> {
+        None
+    }
+
     fn get_scale(&self, _element: &Element) -> Option<Coords> {
         None
     }
@@ -136,39 +150,428 @@ pub trait Platform<Element: Clone, Window: Clone>: Debug {
         None
     }
 
+    /// Fallible version of [`Platform::get_element_rects`], used by [`try_compute_position`][`crate::compute_position::try_compute_position`].
+    fn try_get_element_rects(
+        &self,
+        args: GetElementRectsArgs<Element>,
+    ) -> Result<ElementRects, PlatformError> {
+        Ok(self.get_element_rects(args))
+    }
+
+    /// Fallible version of [`Platform::get_clipping_rect`], used by [`try_compute_position`][`crate::compute_position::try_compute_position`].
+    fn try_get_clipping_rect(
+        &self,
+        args: GetClippingRectArgs<Element>,
+    ) -> Result<Rect, PlatformError> {
+        Ok(self.get_clipping_rect(args))
+    }
+
+    /// Fallible version of [`Platform::get_dimensions`], used by [`try_compute_position`][`crate::compute_position::try_compute_position`].
+    fn try_get_dimensions(&self, element: &Element) -> Result<Dimensions, PlatformError> {
+        Ok(self.get_dimensions(element))
+    }
+
+    /// Fallible version of [`Platform::convert_offset_parent_relative_rect_to_viewport_relative_rect`], used by [`try_compute_position`][`crate::compute_position::try_compute_position`].
+    fn try_convert_offset_parent_relative_rect_to_viewport_relative_rect(
+        &self,
+        args: ConvertOffsetParentRelativeRectToViewportRelativeRectArgs<Element, Window>,
+    ) -> Result<Option<Rect>, PlatformError> {
+        Ok(self.convert_offset_parent_relative_rect_to_viewport_relative_rect(args))
+    }
+
+    /// Fallible version of [`Platform::get_offset_parent`], used by [`try_compute_position`][`crate::compute_position::try_compute_position`].
+    fn try_get_offset_parent(
+        &self,
+        element: &Element,
+    ) -> Result<Option<OwnedElementOrWindow<Element, Window>>, PlatformError> {
+        Ok(self.get_offset_parent(element))
+    }
+
+    /// Fallible version of [`Platform::get_document_element`], used by [`try_compute_position`][`crate::compute_position::try_compute_position`].
+    fn try_get_document_element(
+        &self,
+        element: &Element,
+    ) -> Result<Option<Element>, PlatformError> {
+        Ok(self.get_document_element(element))
+    }
+
+    /// Fallible version of [`Platform::get_client_rects`], used by [`try_compute_position`][`crate::compute_position::try_compute_position`].
+    fn try_get_client_rects(
+        &self,
+        element: ElementOrVirtual<Element>,
+    ) -> Result<Option<Vec<ClientRectObject>>, PlatformError> {
+        Ok(self.get_client_rects(element))
+    }
+
+    /// Fallible version of [`Platform::is_rtl`], used by [`try_compute_position`][`crate::compute_position::try_compute_position`].
+    fn try_is_rtl(&self, element: &Element) -> Result<Option<bool>, PlatformError> {
+        Ok(self.is_rtl(element))
+    }
+
+    /// Fallible version of [`Platform::get_writing_mode`], used by [`try_compute_position`][`crate::compute_position::try_compute_position`].
+    fn try_get_writing_mode(
+        &self,
+        element: &Element,
+    ) -> Result<Option<WritingMode>, PlatformError> {
+        Ok(self.get_writing_mode(element))
+    }
+
+    /// Fallible version of [`Platform::get_scale`], used by [`try_compute_position`][`crate::compute_position::try_compute_position`].
+    fn try_get_scale(&self, element: &Element) -> Result<Option<Coords>, PlatformError> {
+        Ok(self.get_scale(element))
+    }
+
+    /// Fallible version of [`Platform::get_client_length`], used by [`try_compute_position`][`crate::compute_position::try_compute_position`].
+    fn try_get_client_length(
+        &self,
+        element: &Element,
+        length: Length,
+    ) -> Result<Option<f64>, PlatformError> {
+        Ok(self.get_client_length(element, length))
+    }
+
     fn detect_overflow(
         &self,
         state: MiddlewareState<Element, Window>,
         options: DetectOverflowOptions<Element>,
     ) -> SideObject;
+
+    /// Whether a method call of the current run was answered with a placeholder, see [`AsyncMiddleware`].
+    ///
+    /// The return value of such a run is discarded, so middleware should skip side effects while this is `true`.
+    fn has_placeholders(&self) -> bool {
+        false
+    }
+}
+
+/// Boxed future returned by [`AsyncPlatform`] and [`AsyncMiddleware`] methods.
+pub type LocalBoxFuture<'a, T> = Pin<Box<dyn Future<Output = T> + 'a>>;
+
+/// Asynchronous platform interface methods to work with the current platform.
+///
+/// Mirrors [`Platform`] for platforms which can only measure asynchronously. Used by [`compute_position_async`][`crate::compute_position::compute_position_async`].
+///
+/// See [the Rust Floating UI book](https://floating-ui.rustforweb.org/platform.html) for more documentation.
+pub trait AsyncPlatform<Element: Clone, Window: Clone>: Debug {
+    fn get_element_rects<'a>(
+        &'a self,
+        args: GetElementRectsArgs<'a, Element>,
+    ) -> LocalBoxFuture<'a, ElementRects>;
+
+    fn get_clipping_rect<'a>(
+        &'a self,
+        args: GetClippingRectArgs<'a, Element>,
+    ) -> LocalBoxFuture<'a, Rect>;
+
+    fn get_dimensions<'a>(&'a self, element: &'a Element) -> LocalBoxFuture<'a, Dimensions>;
+
+    fn convert_offset_parent_relative_rect_to_viewport_relative_rect<'a>(
+        &'a self,
+        _args: ConvertOffsetParentRelativeRectToViewportRelativeRectArgs<'a, Element, Window>,
+    ) -> LocalBoxFuture<'a, Option<Rect>> {
+        Box::pin(async { None })
+    }
+
+    fn get_offset_parent<'a>(
+        &'a self,
+        _element: &'a Element,
+    ) -> LocalBoxFuture<'a, Option<OwnedElementOrWindow<Element, Window>>> {
+        Box::pin(async { None })
+    }
+
+    fn get_document_element<'a>(
+        &'a self,
+        _element: &'a Element,
+    ) -> LocalBoxFuture<'a, Option<Element>> {
+        Box::pin(async { None })
+    }
+
+    fn get_client_rects<'a>(
+        &'a self,
+        _element: ElementOrVirtual<'a, Element>,
+    ) -> LocalBoxFuture<'a, Option<Vec<ClientRectObject>>> {
+        Box::pin(async { None })
+    }
+
+    fn is_rtl<'a>(&'a self, _element: &'a Element) -> LocalBoxFuture<'a, Option<bool>> {
+        Box::pin(async { None })
+    }
+
+    fn get_writing_mode<'a>(
+        &'a self,
+        _element: &'a Element,
+    ) -> LocalBoxFuture<'a, Option<WritingMode>> {
+        Box::pin(async { None })
+    }
+
+    fn get_scale<'a>(&'a self, _element: &'a Element) -> LocalBoxFuture<'a, Option<Coords>> {
+        Box::pin(async { None })
+    }
+
+    fn get_client_length<'a>(
+        &'a self,
+        _element: &'a Element,
+        _length: Length,
+    ) -> LocalBoxFuture<'a, Option<f64>> {
+        Box::pin(async { None })
+    }
+}
+
+/// Data returned by a middleware, stored in [`MiddlewareData`] by its type.
+///
+/// See [`MiddlewareData::get_typed`].
+pub trait MiddlewareDataValue: Any + Clone + Debug + PartialEq + Send + Sync {
+    /// Merges data returned by a later run of the same middleware into the stored data.
+    ///
+    /// Defaults to replacing the stored data.
+    fn merge(&mut self, data: Self) {
+        *self = data;
+    }
+}
+
+/// Object-safe counterpart of [`MiddlewareDataValue`].
+trait DynMiddlewareDataValue: Debug + Send + Sync {
+    fn as_any(&self) -> &dyn Any;
+
+    fn clone_box(&self) -> Box<dyn DynMiddlewareDataValue>;
+
+    fn dyn_eq(&self, other: &dyn DynMiddlewareDataValue) -> bool;
+
+    fn dyn_merge(&mut self, data: Box<dyn DynMiddlewareDataValue>);
+
+    fn into_any(self: Box<Self>) -> Box<dyn Any>;
+}
+
+impl<D: MiddlewareDataValue> DynMiddlewareDataValue for D {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn clone_box(&self) -> Box<dyn DynMiddlewareDataValue> {
+        Box::new(self.clone())
+    }
+
+    fn dyn_eq(&self, other: &dyn DynMiddlewareDataValue) -> bool {
+        other.as_any().downcast_ref::<D>() == Some(self)
+    }
+
+    fn dyn_merge(&mut self, data: Box<dyn DynMiddlewareDataValue>) {
+        if let Ok(data) = data.into_any().downcast::<D>() {
+            self.merge(*data);
+        }
+    }
+
+    fn into_any(self: Box<Self>) -> Box<dyn Any> {
+        self
+    }
+}
+
+impl Clone for Box<dyn DynMiddlewareDataValue> {
+    fn clone(&self) -> Self {
+        self.as_ref().clone_box()
+    }
+}
+
+impl PartialEq for Box<dyn DynMiddlewareDataValue> {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_ref().dyn_eq(other.as_ref())
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum MiddlewareReturnDataValue {
+    Typed(TypeId, Box<dyn DynMiddlewareDataValue>),
+    Json(serde_json::Value),
+}
+
+/// Data returned by [`Middleware::compute`].
+///
+/// Typed data is created from any [`MiddlewareDataValue`] and stored by its type.
+/// JSON data is created from a [`serde_json::Value`] object and stored by the middleware name.
+#[derive(Clone, Debug, PartialEq)]
+pub struct MiddlewareReturnData(MiddlewareReturnDataValue);
+
+impl<D: MiddlewareDataValue> From<D> for MiddlewareReturnData {
+    fn from(value: D) -> Self {
+        MiddlewareReturnData(MiddlewareReturnDataValue::Typed(
+            TypeId::of::<D>(),
+            Box::new(value),
+        ))
+    }
+}
+
+impl From<serde_json::Value> for MiddlewareReturnData {
+    fn from(value: serde_json::Value) -> Self {
+        MiddlewareReturnData(MiddlewareReturnDataValue::Json(value))
+    }
 }
 
 /// Data stored by middleware.
-#[derive(Clone, Debug, Default, PartialEq)]
+///
+/// Data of built-in middleware is stored by its type, e.g. [`ArrowData`][`crate::middleware::ArrowData`], and read with [`MiddlewareData::get_typed`].
+/// Custom middleware can also store JSON data by name, read with [`MiddlewareData::get_json`].
+///
+/// Typed data is boxed once when a middleware returns it. Reading it does not allocate, clone or deserialize.
+#[derive(Clone, Debug, Default)]
 pub struct MiddlewareData {
-    values: HashMap<String, serde_json::Value>,
+    values: Vec<(TypeId, Box<dyn DynMiddlewareDataValue>)>,
+    json_values: BTreeMap<String, serde_json::Value>,
 }
 
 impl MiddlewareData {
+    /// Get data of type `D`.
+    pub fn get_typed<D: MiddlewareDataValue>(&self) -> Option<&D> {
+        let type_id = TypeId::of::<D>();
+
+        self.values
+            .iter()
+            .find(|(value_type_id, _)| *value_type_id == type_id)
+            .and_then(|(_, value)| value.as_any().downcast_ref::<D>())
+    }
+
+    /// Set data of type `D`, replacing any existing data of the same type.
+    pub fn set_typed<D: MiddlewareDataValue>(&mut self, value: D) {
+        let type_id = TypeId::of::<D>();
+
+        match self
+            .values
+            .iter_mut()
+            .find(|(value_type_id, _)| *value_type_id == type_id)
+        {
+            Some((_, existing_value)) => *existing_value = Box::new(value),
+            None => self.values.push((type_id, Box::new(value))),
+        }
+    }
+
+    /// Get JSON data stored by a custom middleware.
+    pub fn get_json(&self, key: &str) -> Option<&serde_json::Value> {
+        self.json_values.get(key)
+    }
+
+    /// Get JSON data stored by a custom middleware, deserialized as `D`.
+    ///
+    /// Panics if the data can not be deserialized, see [`MiddlewareData::try_get_json_as`].
+    pub fn get_json_as<D: DeserializeOwned>(&self, key: &str) -> Option<D> {
+        self.try_get_json_as(key)
+            .expect("JSON should be valid data.")
+    }
+
+    /// Get JSON data stored by a custom middleware, deserialized as `D`.
+    pub fn try_get_json_as<D: DeserializeOwned>(
+        &self,
+        key: &str,
+    ) -> Result<Option<D>, MiddlewareDataError> {
+        self.json_values
+            .get(key)
+            .map(|value| {
+                D::deserialize(value).map_err(|error| MiddlewareDataError::InvalidData {
+                    name: key.into(),
+                    message: error.to_string(),
+                })
+            })
+            .transpose()
+    }
+
+    /// Set JSON data for a custom middleware.
+    pub fn set_json(&mut self, key: &str, value: serde_json::Value) {
+        self.json_values.insert(key.into(), value);
+    }
+
+    /// Set JSON data for a custom middleware, serialized from `S`.
+    pub fn set_json_as<S: Serialize>(&mut self, key: &str, value: S) {
+        self.json_values.insert(
+            key.into(),
+            serde_json::to_value(value).expect("Data should be valid JSON."),
+        );
+    }
+
+    /// Get JSON data stored by a custom middleware.
+    ///
+    /// Data of built-in middleware is no longer stored as JSON, use [`MiddlewareData::get_typed`] instead.
+    #[deprecated(
+        note = "Use `get_json` for custom middleware or `get_typed` for built-in middleware."
+    )]
     pub fn get(&self, key: &str) -> Option<&serde_json::Value> {
-        self.values.get(key)
+        self.get_json(key)
     }
 
-    pub fn get_as<D: DeserializeOwned>(&self, key: &str) -> Option<D> {
-        self.values.get(key).map(|value| {
-            serde_json::from_value::<D>(value.clone()).expect("JSON should be valid data.")
+    /// Get data stored by a middleware as `D`.
+    ///
+    /// JSON data stored under `key` is deserialized. Otherwise, typed data of type `D` is cloned,
+    /// so e.g. `get_as::<ArrowData>(ARROW_NAME)` keeps working for built-in middleware.
+    #[deprecated(
+        note = "Use `get_json_as` for custom middleware or `get_typed` for built-in middleware."
+    )]
+    pub fn get_as<D: DeserializeOwned + 'static>(&self, key: &str) -> Option<D> {
+        self.get_json_as(key).or_else(|| {
+            let type_id = TypeId::of::<D>();
+
+            self.values
+                .iter()
+                .find(|(value_type_id, _)| *value_type_id == type_id)
+                .and_then(|(_, value)| value.clone_box().into_any().downcast::<D>().ok())
+                .map(|value| *value)
         })
     }
 
+    /// Set JSON data for a custom middleware.
+    #[deprecated(note = "Use `set_json` for custom middleware or `set_typed` for typed data.")]
     pub fn set(&mut self, key: &str, value: serde_json::Value) {
-        self.values.insert(key.into(), value);
+        self.set_json(key, value);
     }
 
+    /// Set JSON data for a custom middleware, serialized from `S`.
+    #[deprecated(note = "Use `set_json_as` for custom middleware or `set_typed` for typed data.")]
     pub fn set_as<S: Serialize>(&mut self, key: &str, value: S) {
-        self.values.insert(
-            key.into(),
-            serde_json::to_value(value).expect("Data should be valid JSON."),
-        );
+        self.set_json_as(key, value);
+    }
+
+    /// Merges data returned by a middleware into the stored data.
+    pub(crate) fn try_merge(
+        &mut self,
+        name: &str,
+        data: MiddlewareReturnData,
+    ) -> Result<(), MiddlewareDataError> {
+        match data.0 {
+            MiddlewareReturnDataValue::Typed(type_id, value) => {
+                match self
+                    .values
+                    .iter_mut()
+                    .find(|(value_type_id, _)| *value_type_id == type_id)
+                {
+                    Some((_, existing_value)) => existing_value.dyn_merge(value),
+                    None => self.values.push((type_id, value)),
+                }
+            }
+            MiddlewareReturnDataValue::Json(value) => match self.json_values.get_mut(name) {
+                Some(existing_value) => {
+                    let (Some(existing_value), serde_json::Value::Object(value)) =
+                        (existing_value.as_object_mut(), value)
+                    else {
+                        return Err(MiddlewareDataError::NotAnObject { name: name.into() });
+                    };
+
+                    existing_value.extend(value.into_iter().filter(|(_, v)| !v.is_null()));
+                }
+                None => {
+                    self.json_values.insert(name.into(), value);
+                }
+            },
+        }
+
+        Ok(())
+    }
+}
+
+impl PartialEq for MiddlewareData {
+    fn eq(&self, other: &Self) -> bool {
+        self.values.len() == other.values.len()
+            && self.values.iter().all(|(type_id, value)| {
+                other.values.iter().any(|(other_type_id, other_value)| {
+                    other_type_id == type_id && other_value == value
+                })
+            })
+            && self.json_values == other.json_values
     }
 }
 
@@ -183,6 +586,13 @@ pub struct ComputePositionConfig<'a, Element: 'static, Window: 'static> {
     /// Defaults to [`Placement::Bottom`].
     pub placement: Option<Placement>,
 
+    /// Writing-mode relative placement, which takes precedence over `placement`.
+    ///
+    /// Resolved with the writing mode and text direction of the floating element, see [`Platform::get_writing_mode`] and [`Platform::is_rtl`].
+    ///
+    /// Defaults to `None`.
+    pub logical_placement: Option<LogicalPlacement>,
+
     /// The strategy to use when positioning the floating element.
     ///
     /// Defaults to [`Strategy::Absolute`].
@@ -192,6 +602,11 @@ pub struct ComputePositionConfig<'a, Element: 'static, Window: 'static> {
     ///
     /// Defaults to an empty vector.
     pub middleware: Option<Vec<Box<dyn Middleware<Element, Window>>>>,
+
+    /// Whether to record every middleware invocation in [`ComputePositionReturn::trace`].
+    ///
+    /// Defaults to `false`.
+    pub trace: Option<bool>,
 }
 
 impl<'a, Element, Window> ComputePositionConfig<'a, Element, Window> {
@@ -199,8 +614,10 @@ impl<'a, Element, Window> ComputePositionConfig<'a, Element, Window> {
         ComputePositionConfig {
             platform,
             placement: None,
+            logical_placement: None,
             strategy: None,
             middleware: None,
+            trace: None,
         }
     }
 
@@ -216,6 +633,12 @@ impl<'a, Element, Window> ComputePositionConfig<'a, Element, Window> {
         self
     }
 
+    /// Set `logical_placement` option.
+    pub fn logical_placement(mut self, value: LogicalPlacement) -> Self {
+        self.logical_placement = Some(value);
+        self
+    }
+
     /// Set `strategy` option.
     pub fn strategy(mut self, value: Strategy) -> Self {
         self.strategy = Some(value);
@@ -227,6 +650,184 @@ impl<'a, Element, Window> ComputePositionConfig<'a, Element, Window> {
         self.middleware = Some(value);
         self
     }
+
+    /// Set `trace` option.
+    pub fn trace(mut self, value: bool) -> Self {
+        self.trace = Some(value);
+        self
+    }
+}
+
+/// Declarative options for [`compute_position`][crate::compute_position::compute_position], e.g. stored in a configuration file.
+///
+/// Converted into [`ComputePositionConfig`] with [`ComputePositionSpec::into_config`].
+#[derive(Clone, PartialEq, Serialize, Deserialize)]
+#[serde(bound = "")]
+pub struct ComputePositionSpec<Element: Clone + 'static, Window: Clone + 'static> {
+    /// Where to place the floating element relative to the reference element.
+    ///
+    /// Defaults to [`Placement::Bottom`].
+    pub placement: Option<Placement>,
+
+    /// Writing-mode relative placement, which takes precedence over `placement`.
+    ///
+    /// Defaults to `None`.
+    pub logical_placement: Option<LogicalPlacement>,
+
+    /// The strategy to use when positioning the floating element.
+    ///
+    /// Defaults to [`Strategy::Absolute`].
+    pub strategy: Option<Strategy>,
+
+    /// Declarative middleware stack.
+    ///
+    /// Defaults to an empty vector.
+    pub middleware: Option<Vec<MiddlewareSpec<Element, Window>>>,
+}
+
+impl<Element: Clone + PartialEq + 'static, Window: Clone + PartialEq + 'static>
+    ComputePositionSpec<Element, Window>
+{
+    /// Set `placement` option.
+    pub fn placement(mut self, value: Placement) -> Self {
+        self.placement = Some(value);
+        self
+    }
+
+    /// Set `logical_placement` option.
+    pub fn logical_placement(mut self, value: LogicalPlacement) -> Self {
+        self.logical_placement = Some(value);
+        self
+    }
+
+    /// Set `strategy` option.
+    pub fn strategy(mut self, value: Strategy) -> Self {
+        self.strategy = Some(value);
+        self
+    }
+
+    /// Set `middleware` option.
+    pub fn middleware(mut self, value: Vec<MiddlewareSpec<Element, Window>>) -> Self {
+        self.middleware = Some(value);
+        self
+    }
+
+    /// Converts the options into [`ComputePositionConfig`] for the given platform.
+    pub fn into_config(
+        self,
+        platform: &dyn Platform<Element, Window>,
+    ) -> ComputePositionConfig<'_, Element, Window> {
+        ComputePositionConfig {
+            platform,
+            placement: self.placement,
+            logical_placement: self.logical_placement,
+            strategy: self.strategy,
+            middleware: self.middleware.map(|middleware| {
+                middleware
+                    .into_iter()
+                    .map(MiddlewareSpec::into_middleware)
+                    .collect()
+            }),
+            trace: None,
+        }
+    }
+}
+
+impl<Element: Clone + 'static, Window: Clone + 'static> Default
+    for ComputePositionSpec<Element, Window>
+{
+    fn default() -> Self {
+        Self {
+            placement: Default::default(),
+            logical_placement: Default::default(),
+            strategy: Default::default(),
+            middleware: Default::default(),
+        }
+    }
+}
+
+/// Options for [`compute_position_async`][crate::compute_position::compute_position_async].
+pub struct AsyncComputePositionConfig<'a, Element: 'static, Window: 'static> {
+    /// Object to interface with the current platform.
+    pub platform: &'a dyn AsyncPlatform<Element, Window>,
+
+    /// Where to place the floating element relative to the reference element.
+    ///
+    /// Defaults to [`Placement::Bottom`].
+    pub placement: Option<Placement>,
+
+    /// Writing-mode relative placement, which takes precedence over `placement`.
+    ///
+    /// Resolved with the writing mode and text direction of the floating element, see [`AsyncPlatform::get_writing_mode`] and [`AsyncPlatform::is_rtl`].
+    ///
+    /// Defaults to `None`.
+    pub logical_placement: Option<LogicalPlacement>,
+
+    /// The strategy to use when positioning the floating element.
+    ///
+    /// Defaults to [`Strategy::Absolute`].
+    pub strategy: Option<Strategy>,
+
+    /// Array of middleware objects to modify the positioning or provide data for rendering.
+    ///
+    /// Any [`Middleware`] can be used here, see [`AsyncMiddleware`].
+    ///
+    /// Defaults to an empty vector.
+    pub middleware: Option<Vec<Box<dyn AsyncMiddleware<Element, Window>>>>,
+
+    /// Whether to record every middleware invocation in [`ComputePositionReturn::trace`].
+    ///
+    /// Defaults to `false`.
+    pub trace: Option<bool>,
+}
+
+impl<'a, Element, Window> AsyncComputePositionConfig<'a, Element, Window> {
+    pub fn new(platform: &'a dyn AsyncPlatform<Element, Window>) -> Self {
+        AsyncComputePositionConfig {
+            platform,
+            placement: None,
+            logical_placement: None,
+            strategy: None,
+            middleware: None,
+            trace: None,
+        }
+    }
+
+    /// Set `platform` option.
+    pub fn platform(mut self, value: &'a dyn AsyncPlatform<Element, Window>) -> Self {
+        self.platform = value;
+        self
+    }
+
+    /// Set `placement` option.
+    pub fn placement(mut self, value: Placement) -> Self {
+        self.placement = Some(value);
+        self
+    }
+
+    /// Set `logical_placement` option.
+    pub fn logical_placement(mut self, value: LogicalPlacement) -> Self {
+        self.logical_placement = Some(value);
+        self
+    }
+
+    /// Set `strategy` option.
+    pub fn strategy(mut self, value: Strategy) -> Self {
+        self.strategy = Some(value);
+        self
+    }
+
+    /// Set `middleware` option.
+    pub fn middleware(mut self, value: Vec<Box<dyn AsyncMiddleware<Element, Window>>>) -> Self {
+        self.middleware = Some(value);
+        self
+    }
+
+    /// Set `trace` option.
+    pub fn trace(mut self, value: bool) -> Self {
+        self.trace = Some(value);
+        self
+    }
 }
 
 /// Return of [`compute_position`][crate::compute_position::compute_position].
@@ -241,8 +842,41 @@ pub struct ComputePositionReturn {
     /// The strategy used to position the floating element.
     pub strategy: Strategy,
 
-    /// Object containing data returned from all middleware, keyed by their name.
+    /// Object containing data returned from all middleware, keyed by their data type.
     pub middleware_data: MiddlewareData,
+
+    /// Every middleware invocation, in order, if [`trace`][`ComputePositionConfig::trace`] was enabled.
+    pub trace: Option<Vec<MiddlewareTraceStep>>,
+}
+
+/// Middleware invocation recorded by [`compute_position`][crate::compute_position::compute_position] when tracing is enabled.
+#[derive(Clone, Debug, PartialEq)]
+pub struct MiddlewareTraceStep {
+    /// The name of the middleware.
+    pub name: &'static str,
+
+    /// The index of the middleware in the middleware vector.
+    pub index: usize,
+
+    /// The number of resets performed before this invocation.
+    ///
+    /// A returned reset is ignored once the maximum of 50 resets has been reached.
+    pub reset_count: usize,
+
+    /// The placement passed to the middleware.
+    pub placement: Placement,
+
+    /// The coordinates passed to the middleware.
+    pub input: Coords,
+
+    /// The coordinates after applying the returned coordinates.
+    pub output: Coords,
+
+    /// The data returned by the middleware.
+    pub data: Option<MiddlewareReturnData>,
+
+    /// The reset returned by the middleware.
+    pub reset: Option<Reset>,
 }
 
 #[derive(Clone, Debug, PartialEq)]
@@ -268,13 +902,14 @@ pub enum Reset {
 pub struct MiddlewareReturn {
     pub x: Option<f64>,
     pub y: Option<f64>,
-    pub data: Option<serde_json::Value>,
+    pub data: Option<MiddlewareReturnData>,
     pub reset: Option<Reset>,
 }
 
 /// Middleware used by [`compute_position`][`crate::compute_position::compute_position`].
-#[dyn_trait]
-pub trait Middleware<Element: Clone + 'static, Window: Clone + 'static>: Clone + PartialEq {
+pub trait Middleware<Element: Clone + 'static, Window: Clone + 'static>:
+    MiddlewareClone<Element, Window> + DynPartialEq
+{
     /// The name of this middleware.
     fn name(&self) -> &'static str;
 
@@ -282,6 +917,63 @@ pub trait Middleware<Element: Clone + 'static, Window: Clone + 'static>: Clone +
     fn compute(&self, state: MiddlewareState<Element, Window>) -> MiddlewareReturn;
 }
 
+/// Object-safe [`Clone`] for [`Middleware`].
+///
+/// Implemented for all middleware which implement [`Clone`].
+pub trait MiddlewareClone<Element: Clone + 'static, Window: Clone + 'static> {
+    fn clone_box(&self) -> Box<dyn Middleware<Element, Window>>;
+}
+
+impl<Element: Clone + 'static, Window: Clone + 'static, T> MiddlewareClone<Element, Window> for T
+where
+    T: Middleware<Element, Window> + Clone + 'static,
+{
+    fn clone_box(&self) -> Box<dyn Middleware<Element, Window>> {
+        Box::new(self.clone())
+    }
+}
+
+impl<Element: Clone + 'static, Window: Clone + 'static> Clone
+    for Box<dyn Middleware<Element, Window>>
+{
+    fn clone(&self) -> Self {
+        (**self).clone_box()
+    }
+}
+
+impl<Element: Clone + 'static, Window: Clone + 'static> PartialEq for dyn Middleware<Element, Window> {
+    fn eq(&self, other: &Self) -> bool {
+        self.dyn_eq(other.as_dyn_any())
+    }
+}
+
+/// Middleware used by [`compute_position_async`][`crate::compute_position::compute_position_async`].
+///
+/// Implemented for every [`Middleware`], which is run against the measurements of the [`AsyncPlatform`].
+///
+/// # Re-execution
+///
+/// A synchronous [`Middleware`] can not await measurements, so it is executed more than once:
+///
+/// - Each run records every platform method call which has not been resolved yet and answers it with a placeholder,
+///   e.g. an empty rect, `None` or the element itself as document element.
+/// - The recorded calls are then awaited on the [`AsyncPlatform`] and the middleware is executed again.
+/// - This repeats until a run only used resolved measurements. Only the return value of that run is used.
+///
+/// The number of runs is one more than the longest chain of measurements depending on each other,
+/// usually two or three. The middleware should therefore only perform side effects while [`Platform::has_placeholders`] is `false`,
+/// as [`Size`][`crate::middleware::Size`] does for its `apply` option, and must not panic on placeholder geometry. Implement this trait directly to await each measurement exactly once.
+pub trait AsyncMiddleware<Element: Clone + 'static, Window: Clone + 'static> {
+    /// The name of this middleware.
+    fn name(&self) -> &'static str;
+
+    /// Executes this middleware.
+    fn compute<'a>(
+        &'a self,
+        state: AsyncMiddlewareState<'a, Element, Window>,
+    ) -> LocalBoxFuture<'a, MiddlewareReturn>;
+}
+
 /// Middleware with options.
 pub trait MiddlewareWithOptions<Element: Clone, Window: Clone, O: Clone> {
     /// The options passed to this middleware.
@@ -343,14 +1035,44 @@ impl<Element: Clone, Window: Clone> Clone for MiddlewareState<'_, Element, Windo
     }
 }
 
-#[derive(Clone, Debug, PartialEq)]
+/// State passed to [`AsyncMiddleware::compute`].
+pub struct AsyncMiddlewareState<'a, Element: Clone + 'static, Window: Clone> {
+    pub x: f64,
+    pub y: f64,
+    pub initial_placement: Placement,
+    pub placement: Placement,
+    pub strategy: Strategy,
+    pub middleware_data: &'a MiddlewareData,
+    pub elements: Elements<'a, Element>,
+    pub rects: &'a ElementRects,
+    pub platform: &'a dyn AsyncPlatform<Element, Window>,
+}
+
+impl<Element: Clone, Window: Clone> Clone for AsyncMiddlewareState<'_, Element, Window> {
+    fn clone(&self) -> Self {
+        Self {
+            x: self.x,
+            y: self.y,
+            initial_placement: self.initial_placement,
+            placement: self.placement,
+            strategy: self.strategy,
+            middleware_data: self.middleware_data,
+            elements: self.elements.clone(),
+            rects: self.rects,
+            platform: self.platform,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub enum Boundary<Element> {
     ClippingAncestors,
     Element(Element),
     Elements(Vec<Element>),
 }
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
 pub enum RootBoundary {
     Viewport,
     LayoutViewport,
@@ -358,7 +1080,8 @@ pub enum RootBoundary {
     Rect(Rect),
 }
 
-#[derive(Copy, Clone, Debug, PartialEq)]
+#[derive(Copy, Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
 pub enum ElementContext {
     Reference,
     Floating,
@@ -0,0 +1,341 @@
+//! Property-based invariants of the core middleware, checked against randomized layouts.
+
+use std::cell::Cell;
+
+use floating_ui_core::{
+    ComputePositionConfig, DetectOverflowOptions, GetClippingRectArgs, GetElementRectsArgs,
+    Middleware, MiddlewareState, Platform, compute_position, detect_overflow,
+    middleware::{
+        Arrow, ArrowData, ArrowOptions, Flip, FlipOptions, Shift, ShiftOptions, Size, SizeOptions,
+    },
+};
+use floating_ui_utils::{
+    ALL_PLACEMENTS, Alignment, Axis, Dimensions, ElementRects, Padding, Placement, Rect,
+    SideObject, WritingMode, get_alignment_axis,
+};
+use proptest::{prelude::*, sample::subsequence};
+
+const EPSILON: f64 = 1e-6;
+
+#[derive(Clone, Debug, PartialEq)]
+enum Element {
+    Reference,
+    Floating,
+    Arrow,
+}
+
+#[derive(Clone, Debug)]
+struct PropertyPlatform {
+    reference: Rect,
+    floating: Dimensions,
+    arrow: Dimensions,
+    boundary: Rect,
+    rtl: bool,
+    writing_mode: WritingMode,
+}
+
+impl Platform<Element, ()> for PropertyPlatform {
+    fn get_element_rects(&self, _args: GetElementRectsArgs<Element>) -> ElementRects {
+        ElementRects {
+            reference: self.reference.clone(),
+            floating: Rect {
+                x: 0.0,
+                y: 0.0,
+                width: self.floating.width,
+                height: self.floating.height,
+            },
+        }
+    }
+
+    fn get_clipping_rect(&self, _args: GetClippingRectArgs<Element>) -> Rect {
+        self.boundary.clone()
+    }
+
+    fn get_dimensions(&self, element: &Element) -> Dimensions {
+        match element {
+            Element::Reference => Dimensions {
+                width: self.reference.width,
+                height: self.reference.height,
+            },
+            Element::Floating => self.floating.clone(),
+            Element::Arrow => self.arrow.clone(),
+        }
+    }
+
+    fn is_rtl(&self, _element: &Element) -> Option<bool> {
+        Some(self.rtl)
+    }
+
+    fn get_writing_mode(&self, _element: &Element) -> Option<WritingMode> {
+        Some(self.writing_mode)
+    }
+
+    fn detect_overflow(
+        &self,
+        state: MiddlewareState<Element, ()>,
+        options: DetectOverflowOptions<Element>,
+    ) -> SideObject {
+        detect_overflow(state, options)
+    }
+}
+
+prop_compose! {
+    fn platform()(
+        boundary_x in -200.0..200.0,
+        boundary_y in -200.0..200.0,
+        boundary_width in 100.0..1000.0,
+        boundary_height in 100.0..1000.0,
+        reference_x in -300.0..1300.0,
+        reference_y in -300.0..1300.0,
+        reference_width in 0.0..300.0,
+        reference_height in 0.0..300.0,
+        floating_width in 20.0..400.0,
+        floating_height in 20.0..400.0,
+        arrow_width in 1.0..16.0,
+        arrow_height in 1.0..16.0,
+        rtl in any::<bool>(),
+        writing_mode in prop::sample::select(vec![
+            WritingMode::HorizontalTb,
+            WritingMode::VerticalRl,
+            WritingMode::VerticalLr,
+            WritingMode::SidewaysLr,
+        ]),
+    ) -> PropertyPlatform {
+        PropertyPlatform {
+            reference: Rect {
+                x: reference_x,
+                y: reference_y,
+                width: reference_width,
+                height: reference_height,
+            },
+            floating: Dimensions {
+                width: floating_width,
+                height: floating_height,
+            },
+            arrow: Dimensions {
+                width: arrow_width,
+                height: arrow_height,
+            },
+            boundary: Rect {
+                x: boundary_x,
+                y: boundary_y,
+                width: boundary_width,
+                height: boundary_height,
+            },
+            rtl,
+            writing_mode,
+        }
+    }
+}
+
+prop_compose! {
+    /// Layout with padding where the reference element lies inside the padded clipping boundary.
+    fn contained_platform()(
+        platform in platform(),
+        padding in 0.0..20.0,
+        reference_x in 0.0..=1.0,
+        reference_y in 0.0..=1.0,
+    ) -> (PropertyPlatform, f64) {
+        let padded_width = platform.boundary.width - 2.0 * padding;
+        let padded_height = platform.boundary.height - 2.0 * padding;
+        let width = platform.reference.width.min(padded_width);
+        let height = platform.reference.height.min(padded_height);
+
+        (
+            PropertyPlatform {
+                reference: Rect {
+                    x: platform.boundary.x + padding + reference_x * (padded_width - width),
+                    y: platform.boundary.y + padding + reference_y * (padded_height - height),
+                    width,
+                    height,
+                },
+                ..platform
+            },
+            padding,
+        )
+    }
+}
+
+fn placement() -> impl Strategy<Value = Placement> {
+    prop::sample::select(ALL_PLACEMENTS.to_vec())
+}
+
+fn run(
+    platform: &PropertyPlatform,
+    placement: Placement,
+    middleware: Vec<Box<dyn Middleware<Element, ()>>>,
+) -> floating_ui_core::ComputePositionReturn {
+    compute_position(
+        (&Element::Reference).into(),
+        &Element::Floating,
+        ComputePositionConfig::new(platform)
+            .placement(placement)
+            .middleware(middleware),
+    )
+}
+
+thread_local! {
+    static AVAILABLE_DIMENSIONS: Cell<Option<(f64, f64)>> = const { Cell::new(None) };
+}
+
+fn record_available_dimensions(state: floating_ui_core::middleware::ApplyState<Element, ()>) {
+    AVAILABLE_DIMENSIONS.set(Some((state.available_width, state.available_height)));
+}
+
+proptest! {
+    #[test]
+    fn shift_keeps_floating_inside_boundary_when_it_fits(
+        platform in platform(),
+        placement in placement(),
+        padding in 0.0..20.0,
+    ) {
+        let position = run(
+            &platform,
+            placement,
+            vec![Box::new(Shift::new(
+                ShiftOptions::default()
+                    .cross_axis(true)
+                    .detect_overflow(DetectOverflowOptions::default().padding(Padding::All(padding))),
+            ))],
+        );
+
+        let boundary = &platform.boundary;
+        if platform.floating.width <= boundary.width - 2.0 * padding {
+            prop_assert!(position.x >= boundary.x + padding - EPSILON);
+            prop_assert!(
+                position.x + platform.floating.width
+                    <= boundary.x + boundary.width - padding + EPSILON
+            );
+        }
+        if platform.floating.height <= boundary.height - 2.0 * padding {
+            prop_assert!(position.y >= boundary.y + padding - EPSILON);
+            prop_assert!(
+                position.y + platform.floating.height
+                    <= boundary.y + boundary.height - padding + EPSILON
+            );
+        }
+    }
+
+    #[test]
+    fn flip_stays_within_fallback_placements(
+        platform in platform(),
+        placement in placement(),
+        fallback_placements in subsequence(ALL_PLACEMENTS.to_vec(), 0..=ALL_PLACEMENTS.len()),
+        fallback_axis_side_direction in prop::option::of(prop_oneof![
+            Just(Alignment::Start),
+            Just(Alignment::End),
+        ]),
+    ) {
+        let mut options = FlipOptions::default().fallback_placements(fallback_placements.clone());
+        if let Some(fallback_axis_side_direction) = fallback_axis_side_direction {
+            options = options.fallback_axis_side_direction(fallback_axis_side_direction);
+        }
+
+        let position = run(&platform, placement, vec![Box::new(Flip::new(options))]);
+
+        prop_assert!(
+            position.placement == placement
+                || fallback_placements.contains(&position.placement),
+            "{:?} is not {:?} or one of {:?}",
+            position.placement,
+            placement,
+            fallback_placements
+        );
+    }
+
+    #[test]
+    fn arrow_stays_within_floating_minus_padding(
+        platform in platform(),
+        placement in placement(),
+        padding in 0.0..20.0,
+    ) {
+        let position = run(
+            &platform,
+            placement,
+            vec![
+                Box::new(Shift::new(ShiftOptions::default())),
+                Box::new(Arrow::new(
+                    ArrowOptions::new(Element::Arrow).padding(Padding::All(padding)),
+                )),
+            ],
+        );
+
+        let data = position
+            .middleware_data
+            .get_typed::<ArrowData>()
+            .expect("Arrow data should exist.");
+        let (offset, floating_length, arrow_length) = match get_alignment_axis(placement) {
+            Axis::X => (data.x, platform.floating.width, platform.arrow.width),
+            Axis::Y => (data.y, platform.floating.height, platform.arrow.height),
+        };
+        let offset = offset.expect("Arrow offset should exist on the alignment axis.");
+
+        // Padding is reduced when it would prevent centering the arrow.
+        let padding = padding.min(floating_length / 2.0 - arrow_length / 2.0 - 1.0);
+        prop_assert!(offset >= padding - EPSILON);
+        prop_assert!(offset + arrow_length <= floating_length - padding + EPSILON);
+    }
+
+    // Upstream does not clamp the available dimensions, so they are negative when the reference element
+    // lies outside of the padded clipping boundary. They are only never negative for references inside of it.
+    #[test]
+    fn size_available_dimensions_are_never_negative(
+        (platform, padding) in contained_platform(),
+        placement in placement(),
+        shift in any::<bool>(),
+    ) {
+        let mut middleware: Vec<Box<dyn Middleware<Element, ()>>> = vec![];
+        if shift {
+            middleware.push(Box::new(Shift::new(ShiftOptions::default())));
+        }
+        middleware.push(Box::new(Size::new(
+            SizeOptions::new()
+                .detect_overflow(DetectOverflowOptions::default().padding(Padding::All(padding)))
+                .apply(&record_available_dimensions),
+        )));
+
+        AVAILABLE_DIMENSIONS.set(None);
+        run(&platform, placement, middleware);
+
+        let (available_width, available_height) = AVAILABLE_DIMENSIONS
+            .get()
+            .expect("Apply function should be called.");
+        prop_assert!(available_width >= -EPSILON, "available width {available_width}");
+        prop_assert!(available_height >= -EPSILON, "available height {available_height}");
+    }
+
+    // The available dimensions can be negative when the floating element is further outside
+    // the clipping boundary than its own size, but never exceed the padded boundary.
+    #[test]
+    fn size_available_dimensions_fit_inside_boundary(
+        platform in platform(),
+        placement in placement(),
+        shift in any::<bool>(),
+        padding in 0.0..20.0,
+    ) {
+        let mut middleware: Vec<Box<dyn Middleware<Element, ()>>> = vec![];
+        if shift {
+            middleware.push(Box::new(Shift::new(ShiftOptions::default())));
+        }
+        middleware.push(Box::new(Size::new(
+            SizeOptions::new()
+                .detect_overflow(DetectOverflowOptions::default().padding(Padding::All(padding)))
+                .apply(&record_available_dimensions),
+        )));
+
+        AVAILABLE_DIMENSIONS.set(None);
+        run(&platform, placement, middleware);
+
+        let (available_width, available_height) = AVAILABLE_DIMENSIONS
+            .get()
+            .expect("Apply function should be called.");
+        prop_assert!(
+            available_width <= platform.boundary.width - 2.0 * padding + EPSILON,
+            "available width {available_width}"
+        );
+        prop_assert!(
+            available_height <= platform.boundary.height - 2.0 * padding + EPSILON,
+            "available height {available_height}"
+        );
+    }
+}
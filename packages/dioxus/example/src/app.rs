@@ -2,9 +2,9 @@ use std::rc::Rc;
 
 use dioxus::prelude::*;
 use floating_ui_dioxus::{
-    ARROW_NAME, Arrow, ArrowData, ArrowOptions, DetectOverflowOptions, Flip, FlipOptions,
-    MiddlewareVec, Offset, OffsetOptions, Padding, Placement, Shift, ShiftOptions, Side,
-    UseFloatingOptions, UseFloatingReturn, use_auto_update, use_floating,
+    Arrow, ArrowData, ArrowOptions, DetectOverflowOptions, Flip, FlipOptions, MiddlewareVec,
+    Offset, OffsetOptions, Padding, Placement, Shift, ShiftOptions, Side, UseFloatingOptions,
+    UseFloatingReturn, use_auto_update, use_floating,
 };
 
 #[component]
@@ -42,8 +42,7 @@ pub fn App() -> Element {
     );
 
     let static_side = use_memo(move || placement().side().opposite());
-    let arrow_data =
-        use_memo(move || -> Option<ArrowData> { middleware_data().get_as(ARROW_NAME) });
+    let arrow_data = use_memo(move || middleware_data().get_typed::<ArrowData>().cloned());
     let arrow_x = use_memo(move || {
         arrow_data().and_then(|arrow_data| arrow_data.x.map(|x| format!("{x}px")))
     });
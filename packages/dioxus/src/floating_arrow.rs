@@ -0,0 +1,99 @@
+use std::rc::Rc;
+
+use dioxus::{prelude::*, web::WebEventExt};
+use floating_ui_dom::{
+    FloatingArrowLayout, FloatingArrowOptions, MiddlewareData, Placement, dom::get_computed_style,
+    floating_arrow_clip_path_id,
+};
+
+/// SVG arrow which points from the floating element to the reference element.
+///
+/// Pass the same `arrow_ref` to [`Arrow`][crate::Arrow] and render the arrow inside the floating element.
+/// The arrow is positioned from the `placement` and `middleware_data` returned by [`use_floating`][crate::use_floating].
+///
+/// See [the Rust Floating UI book](https://floating-ui.rustforweb.org/frameworks/dioxus.html) for more documentation.
+#[component]
+pub fn FloatingArrow(
+    /// The arrow element, also passed to [`Arrow`][crate::Arrow].
+    arrow_ref: Signal<Option<Rc<MountedData>>>,
+    /// The final placement of the floating element.
+    placement: ReadSignal<Placement>,
+    /// The middleware data of the floating element.
+    middleware_data: ReadSignal<MiddlewareData>,
+    /// The width of the arrow. Defaults to `14`.
+    width: Option<f64>,
+    /// The height of the arrow. Defaults to `7`.
+    height: Option<f64>,
+    /// The radius of the rounded tip of the arrow. Defaults to `0`.
+    tip_radius: Option<f64>,
+    /// The width of the stroke, matching the border width of the floating element. Defaults to `0`.
+    stroke_width: Option<f64>,
+    /// A static CSS offset of the arrow from the aligned edge of the floating element.
+    static_offset: Option<String>,
+    /// A custom SVG path of the arrow, drawn pointing down.
+    d: Option<String>,
+    /// The fill color of the arrow, matching the background of the floating element.
+    fill: Option<String>,
+    /// The stroke color of the arrow, matching the border color of the floating element.
+    stroke: Option<String>,
+) -> Element {
+    let clip_path_id = use_hook(floating_arrow_clip_path_id);
+    let mut rtl = use_signal(|| false);
+
+    let layout = FloatingArrowLayout::new(
+        &FloatingArrowOptions {
+            width,
+            height,
+            tip_radius,
+            stroke_width,
+            static_offset,
+            d,
+        },
+        placement(),
+        &middleware_data.read(),
+        rtl(),
+    );
+
+    let fill_stroke = if layout.stroke_fill {
+        fill.clone().unwrap_or_else(|| "none".to_owned())
+    } else {
+        "none".to_owned()
+    };
+
+    rsx! {
+        svg {
+            "aria-hidden": "true",
+            width: layout.width,
+            height: layout.height,
+            view_box: layout.view_box,
+            style: layout.style,
+            onmounted: move |event| {
+                let element = event.as_web_event();
+                rtl.set(
+                    get_computed_style(&element)
+                        .get_property_value("direction")
+                        .is_ok_and(|direction| direction == "rtl"),
+                );
+                arrow_ref.set(Some(event.data()));
+            },
+            if layout.stroke_width > 0.0 {
+                path {
+                    clip_path: "url(#{clip_path_id})",
+                    fill: "none",
+                    stroke,
+                    stroke_width: layout.stroke_width,
+                    d: layout.d.clone(),
+                }
+            }
+            path { fill, stroke: fill_stroke, d: layout.d }
+            clipPath { id: clip_path_id,
+                rect {
+                    x: layout.clip_rect.x,
+                    y: layout.clip_rect.y,
+                    width: layout.clip_rect.width,
+                    height: layout.clip_rect.height,
+                }
+            }
+        }
+    }
+}
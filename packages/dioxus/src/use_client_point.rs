@@ -0,0 +1,85 @@
+use std::{cell::RefCell, rc::Rc};
+
+use dioxus::{core::use_drop, prelude::*, web::WebEventExt};
+use floating_ui_dom::{Coords, track_client_point, virtual_element_from_client_point};
+
+use crate::types::{UseClientPointOptions, UseClientPointReturn};
+
+/// Positions the floating element at the cursor while the pointer moves over the reference element.
+///
+/// Pass the returned position reference to [`UseFloatingOptions::position_reference`][crate::types::UseFloatingOptions::position_reference].
+pub fn use_client_point(
+    reference: Signal<Option<Rc<MountedData>>>,
+    options: UseClientPointOptions,
+) -> UseClientPointReturn {
+    let enabled_option = use_memo(move || options.enabled.unwrap_or(true));
+    let axis_option = use_memo(move || options.axis.unwrap_or_default());
+    let initial_only_option = use_memo(move || options.initial_only.unwrap_or(false));
+    let open = options.open.unwrap_or(true);
+
+    let mut point = use_signal(|| None::<Coords>);
+
+    use_effect(use_reactive!(|open| {
+        // Capture a new initial point on the next open.
+        if !open {
+            point.set(None);
+        }
+    }));
+
+    let tracking_cleanup =
+        use_hook::<Rc<RefCell<Option<Box<dyn Fn()>>>>>(|| Rc::new(RefCell::new(None)));
+
+    let cleanup = use_callback({
+        let tracking_cleanup = tracking_cleanup.clone();
+
+        move |_| {
+            if let Some(tracking_cleanup) = tracking_cleanup.take() {
+                tracking_cleanup();
+            }
+        }
+    });
+
+    use_effect(move || {
+        cleanup.call(());
+
+        if enabled_option()
+            && let Some(reference_element) = reference().map(|reference| reference.as_web_event())
+        {
+            tracking_cleanup.replace(Some(track_client_point(
+                &reference_element,
+                Rc::new(move |coords| {
+                    if *initial_only_option.peek() && point.peek().is_some() {
+                        return;
+                    }
+
+                    let mut point = point;
+                    point.set(Some(coords));
+                }),
+            )));
+        }
+    });
+
+    use_drop(move || {
+        cleanup.call(());
+    });
+
+    let position_reference = use_memo(move || {
+        if !enabled_option() {
+            return None;
+        }
+
+        match (
+            reference().map(|reference| reference.as_web_event()),
+            point(),
+        ) {
+            (Some(reference_element), Some(point)) => Some(virtual_element_from_client_point(
+                &reference_element,
+                point,
+                axis_option(),
+            )),
+            _ => None,
+        }
+    });
+
+    UseClientPointReturn { position_reference }
+}
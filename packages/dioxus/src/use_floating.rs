@@ -2,7 +2,9 @@ use std::{cell::RefCell, rc::Rc};
 
 use dioxus::{core::use_drop, prelude::*, web::WebEventExt};
 use floating_ui_dom::{
-    ComputePositionConfig, MiddlewareData, Placement, Strategy, compute_position,
+    ComputePositionConfig, MiddlewareData, OwnedElementOrVirtual, Placement, Strategy,
+    TransformOriginData, hide_popover, remove_popover, show_popover, try_compute_position,
+    while_popover_open,
 };
 
 use crate::{
@@ -18,10 +20,29 @@ pub fn use_floating(
 ) -> UseFloatingReturn {
     let open_option = use_memo(move || options.open.unwrap_or(true));
     let placement_option = use_memo(move || options.placement.unwrap_or(Placement::Bottom));
-    let strategy_option = use_memo(move || options.strategy.unwrap_or(Strategy::Absolute));
+    let logical_placement_option = use_memo(move || options.logical_placement);
+    let popover_option = use_memo(move || options.popover.unwrap_or(false));
+    let strategy_option = use_memo(move || {
+        if popover_option() {
+            // The containing block of elements in the top layer is the viewport.
+            Strategy::Fixed
+        } else {
+            options.strategy.unwrap_or(Strategy::Absolute)
+        }
+    });
     let middleware_option = use_memo(move || options.middleware.clone().unwrap_or_default());
     let transform_option = use_memo(move || options.transform.unwrap_or(true));
     let while_elements_mounted_option = options.while_elements_mounted;
+    let position_reference_option = options.position_reference;
+
+    let reference_or_virtual = move || -> Option<OwnedElementOrVirtual> {
+        match position_reference_option.and_then(|position_reference| position_reference()) {
+            Some(virtual_element) => Some(OwnedElementOrVirtual::VirtualElement(Box::new(
+                virtual_element,
+            ))),
+            None => reference().map(|reference| reference.as_web_event().into()),
+        }
+    };
 
     let mut x = use_signal(|| 0.0);
     let mut y = use_signal(|| 0.0);
@@ -34,10 +55,16 @@ pub fn use_floating(
     let floating_styles = use_memo(move || {
         let initial_styles = FloatingStyles {
             position: strategy(),
+            margin: popover_option().then(|| "0".to_owned()),
+            inset: popover_option().then(|| "auto".to_owned()),
             top: "0".to_owned(),
             left: "0".to_owned(),
             transform: None,
             will_change: None,
+            transform_origin: middleware_data
+                .read()
+                .get_typed::<TransformOriginData>()
+                .map(TransformOriginData::transform_origin),
         };
 
         match floating().map(|floating| floating.as_web_event()) {
@@ -65,18 +92,26 @@ pub fn use_floating(
     });
 
     let update = use_callback(move |_| {
-        if let Some(reference_element) = reference().map(|reference| reference.as_web_event())
+        if let Some(reference_element) = reference_or_virtual()
             && let Some(floating_element) = floating().map(|floating| floating.as_web_event())
         {
             let config = ComputePositionConfig {
                 placement: Some(placement_option()),
+                logical_placement: logical_placement_option(),
                 strategy: Some(strategy_option()),
                 middleware: Some(middleware_option()),
+                trace: None,
+                cache: None,
             };
 
             let open = open_option();
 
-            let position = compute_position((&reference_element).into(), &floating_element, config);
+            let Ok(position) =
+                try_compute_position((&reference_element).into(), &floating_element, config)
+            else {
+                // The elements can not be measured, e.g. because they were removed during teardown.
+                return;
+            };
             x.set(position.x);
             y.set(position.y);
             strategy.set(position.strategy);
@@ -90,6 +125,36 @@ pub fn use_floating(
         }
     });
 
+    // The floating element shown as popover, which is removed from the top layer when it changes or the `popover` option is disabled.
+    let popover_element =
+        use_hook::<Rc<RefCell<Option<web_sys::Element>>>>(|| Rc::new(RefCell::new(None)));
+
+    let sync_popover = use_callback({
+        let popover_element = popover_element.clone();
+
+        move |_| {
+            if popover_option()
+                && let Some(floating_element) = floating().map(|floating| floating.as_web_event())
+            {
+                if popover_element.borrow().as_ref() != Some(&floating_element)
+                    && let Some(previous_element) = popover_element.take()
+                {
+                    remove_popover(&previous_element);
+                }
+
+                if open_option() {
+                    show_popover(&floating_element);
+                } else {
+                    hide_popover(&floating_element);
+                }
+
+                popover_element.replace(Some(floating_element));
+            } else if let Some(previous_element) = popover_element.take() {
+                remove_popover(&previous_element);
+            }
+        }
+    });
+
     let while_elements_mounted_cleanup = use_hook::<
         Rc<RefCell<Option<Rc<WhileElementsMountedCleanupFn>>>>,
     >(|| Rc::new(RefCell::new(None)));
@@ -106,18 +171,40 @@ pub fn use_floating(
 
     let attach = use_callback(move |_| {
         cleanup.call(());
+        sync_popover.call(());
 
         if let Some(while_elements_mounted) = &while_elements_mounted_option {
-            if let Some(reference_element) = reference().map(|reference| reference.as_web_event())
+            if let Some(reference_element) = reference_or_virtual()
                 && let Some(floating_element) = floating().map(|floating| floating.as_web_event())
             {
-                while_elements_mounted_cleanup.replace(Some(Rc::new((*while_elements_mounted)(
-                    (&reference_element).into(),
-                    &floating_element,
-                    Rc::new(move || {
-                        update.call(());
-                    }),
-                ))));
+                let update: Rc<dyn Fn()> = Rc::new(move || {
+                    update.call(());
+                });
+
+                let while_elements_mounted_cleanup_fn = if popover_option() {
+                    let open_floating_element = floating_element.clone();
+                    let while_elements_mounted = while_elements_mounted.clone();
+
+                    while_popover_open(
+                        &floating_element,
+                        Rc::new(move || {
+                            (*while_elements_mounted)(
+                                (&reference_element).into(),
+                                &open_floating_element,
+                                update.clone(),
+                            )
+                        }),
+                    )
+                } else {
+                    (*while_elements_mounted)(
+                        (&reference_element).into(),
+                        &floating_element,
+                        update,
+                    )
+                };
+
+                while_elements_mounted_cleanup
+                    .replace(Some(Rc::new(while_elements_mounted_cleanup_fn)));
             }
         } else {
             update.call(());
@@ -133,15 +220,19 @@ pub fn use_floating(
     use_effect(move || {
         _ = open_option();
         _ = placement_option();
+        _ = logical_placement_option();
         _ = strategy_option();
         _ = middleware_option();
 
+        sync_popover.call(());
         update.call(());
     });
 
     use_effect(move || {
         _ = reference();
         _ = floating();
+        _ = position_reference_option.map(|position_reference| position_reference());
+        _ = popover_option();
 
         attach(());
     });
@@ -154,6 +245,10 @@ pub fn use_floating(
 
     use_drop(move || {
         cleanup.call(());
+
+        if let Some(popover_element) = popover_element.take() {
+            remove_popover(&popover_element);
+        }
     });
 
     UseFloatingReturn {
@@ -0,0 +1,146 @@
+use std::{cell::RefCell, rc::Rc};
+
+use dioxus::{core::use_drop, prelude::*};
+use floating_ui_dom::{
+    Placement, TransitionStatus, get_initial_transition_styles, get_transition_styles,
+    schedule::{request_animation_frame, set_timeout},
+};
+
+use crate::types::{
+    UseTransitionStatusOptions, UseTransitionStatusReturn, UseTransitionStylesOptions,
+    UseTransitionStylesReturn,
+};
+
+type CancelFn = Box<dyn Fn()>;
+
+fn use_cancel() -> (Rc<RefCell<Option<CancelFn>>>, Callback<()>) {
+    let cancel_fn = use_hook::<Rc<RefCell<Option<CancelFn>>>>(|| Rc::new(RefCell::new(None)));
+
+    let cancel = use_callback({
+        let cancel_fn = cancel_fn.clone();
+
+        move |_| {
+            if let Some(cancel_fn) = cancel_fn.take() {
+                cancel_fn();
+            }
+        }
+    });
+
+    use_drop(move || {
+        cancel.call(());
+    });
+
+    (cancel_fn, cancel)
+}
+
+/// Provides the status of the transition to apply CSS transitions to a floating element, correctly handling placement-aware transitions.
+///
+/// The floating element should only be rendered while `is_mounted` is `true`, so it stays mounted while the close transition plays.
+pub fn use_transition_status(
+    floating: Signal<Option<Rc<MountedData>>>,
+    options: UseTransitionStatusOptions,
+) -> UseTransitionStatusReturn {
+    let open = options.open.unwrap_or(true);
+    let close_duration = options.duration.unwrap_or_default().close();
+
+    let open_option = use_memo(use_reactive!(|open| open));
+    let close_duration_option = use_memo(use_reactive!(|close_duration| close_duration));
+
+    let mut delayed_is_mounted = use_signal(|| open);
+    let mut status = use_signal(|| TransitionStatus::Unmounted);
+
+    let (timeout_cancel_fn, timeout_cancel) = use_cancel();
+    let (frame_cancel_fn, frame_cancel) = use_cancel();
+
+    // Delay unmounting until the close transition has finished.
+    use_effect(move || {
+        timeout_cancel.call(());
+
+        if open_option() {
+            delayed_is_mounted.set(true);
+        } else {
+            timeout_cancel_fn.replace(Some(set_timeout(
+                move || delayed_is_mounted.set(false),
+                close_duration_option(),
+            )));
+        }
+    });
+
+    let is_mounted = use_memo(move || open_option() || delayed_is_mounted());
+
+    use_effect(move || {
+        if !is_mounted() && *status.peek() == TransitionStatus::Close {
+            status.set(TransitionStatus::Unmounted);
+        }
+    });
+
+    use_effect(move || {
+        let open = open_option();
+        if floating().is_none() {
+            return;
+        }
+
+        frame_cancel.call(());
+
+        if open {
+            status.set(TransitionStatus::Initial);
+
+            frame_cancel_fn.replace(Some(request_animation_frame(move || {
+                let mut status = status;
+                status.set(TransitionStatus::Open);
+            })));
+        } else {
+            status.set(TransitionStatus::Close);
+        }
+    });
+
+    #[expect(clippy::redundant_closure)]
+    let status = use_memo(move || status());
+
+    UseTransitionStatusReturn { is_mounted, status }
+}
+
+/// Provides styles to apply CSS transitions to a floating element, correctly handling placement-aware transitions.
+///
+/// The floating element should only be rendered while `is_mounted` is `true`, so it stays mounted while the close transition plays.
+pub fn use_transition_styles(
+    floating: Signal<Option<Rc<MountedData>>>,
+    options: UseTransitionStylesOptions,
+) -> UseTransitionStylesReturn {
+    let placement = options.placement.unwrap_or(Placement::Bottom);
+    let styles_options = options.styles.unwrap_or_default();
+
+    let UseTransitionStatusReturn { is_mounted, status } = use_transition_status(
+        floating,
+        UseTransitionStatusOptions {
+            open: options.open,
+            duration: styles_options.duration,
+        },
+    );
+
+    let placement_option = use_memo(use_reactive!(|placement| placement));
+    let styles_option = use_memo(use_reactive!(|styles_options| styles_options));
+
+    let mut styles = use_signal(|| {
+        get_initial_transition_styles(*placement_option.peek(), &styles_option.peek())
+    });
+
+    use_effect(move || {
+        let next_styles = get_transition_styles(
+            status(),
+            placement_option(),
+            &styles_option.read(),
+            &styles.peek(),
+        );
+        styles.set(next_styles);
+    });
+
+    #[expect(clippy::redundant_closure)]
+    let styles = use_memo(move || styles());
+
+    UseTransitionStylesReturn {
+        is_mounted,
+        status,
+        styles,
+    }
+}
@@ -1,7 +1,7 @@
 use std::rc::Rc;
 
 use floating_ui_dom::{
-    ARROW_NAME, Arrow, ArrowData, ArrowOptions, ComputePositionConfig, ComputePositionReturn,
+    Arrow, ArrowData, ArrowOptions, ComputePositionConfig, ComputePositionReturn,
     DetectOverflowOptions, Flip, FlipOptions, Offset, OffsetOptions, Padding, Placement, Shift,
     ShiftOptions, Side, compute_position,
 };
@@ -63,7 +63,7 @@ fn run() -> Result<(), JsValue> {
                 ]),
         );
 
-        let arrow_data: Option<ArrowData> = middleware_data.get_as(ARROW_NAME);
+        let arrow_data = middleware_data.get_typed::<ArrowData>().cloned();
         if let Some(arrow_data) = arrow_data {
             let static_side = placement.side().opposite();
 
@@ -0,0 +1,590 @@
+use std::{
+    cell::Cell,
+    error::Error,
+    fmt::{self, Display},
+    rc::Rc,
+};
+
+use floating_ui_utils::{
+    Alignment, LogicalPlacement, LogicalSide, Placement, Strategy, WritingMode,
+    get_expanded_placements, get_opposite_axis_placements_with_writing_mode,
+    get_opposite_placement,
+};
+use web_sys::{Element, HtmlElement, Window, wasm_bindgen::JsCast};
+
+use crate::{
+    AutoUpdateOptions, ComputePositionConfig, auto_update,
+    cache::PlatformCache,
+    compute_position,
+    middleware::{
+        AUTO_PLACEMENT_NAME, CrossAxis, FLIP_NAME, FlipOptions, HIDE_NAME, INLINE_NAME,
+        MiddlewareSpec, OFFSET_NAME, OffsetOptions, SHIFT_NAME, ShiftOptions,
+    },
+    platform::{expect_platform, get_writing_mode::get_writing_mode, is_rtl::is_rtl},
+    types::ComputePositionSpec,
+};
+
+/// Error returned when a [`ComputePositionSpec`] has no CSS anchor positioning equivalent.
+#[derive(Clone, Debug, PartialEq)]
+pub enum AnchorPositioningError {
+    /// The middleware can not be expressed in CSS.
+    UnsupportedMiddleware { name: &'static str },
+    /// The option of the middleware can not be expressed in CSS.
+    UnsupportedOption {
+        name: &'static str,
+        option: &'static str,
+    },
+    /// The fallback placement can not be reached from the initial placement with `position-try-fallbacks` tactics.
+    UnsupportedFallbackPlacement { placement: Placement },
+}
+
+impl Display for AnchorPositioningError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AnchorPositioningError::UnsupportedMiddleware { name } => {
+                write!(
+                    f,
+                    "Middleware `{name}` has no CSS anchor positioning equivalent."
+                )
+            }
+            AnchorPositioningError::UnsupportedOption { name, option } => write!(
+                f,
+                "Option `{option}` of middleware `{name}` has no CSS anchor positioning equivalent."
+            ),
+            AnchorPositioningError::UnsupportedFallbackPlacement { placement } => write!(
+                f,
+                "Fallback placement `{placement}` can not be reached with `position-try-fallbacks`."
+            ),
+        }
+    }
+}
+
+impl Error for AnchorPositioningError {}
+
+/// Flip tactic of a `position-try-fallbacks` entry, e.g. `flip-block`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum TryTactic {
+    Block,
+    Inline,
+    Start,
+}
+
+impl TryTactic {
+    fn as_str(&self) -> &'static str {
+        match self {
+            TryTactic::Block => "flip-block",
+            TryTactic::Inline => "flip-inline",
+            TryTactic::Start => "flip-start",
+        }
+    }
+
+    fn apply(&self, placement: LogicalPlacement) -> LogicalPlacement {
+        let side = placement.side();
+        let alignment = placement.alignment();
+        let opposite_alignment = alignment.map(|alignment| match alignment {
+            Alignment::Start => Alignment::End,
+            Alignment::End => Alignment::Start,
+        });
+
+        match (self, side) {
+            (TryTactic::Block, LogicalSide::BlockStart | LogicalSide::BlockEnd)
+            | (TryTactic::Inline, LogicalSide::InlineStart | LogicalSide::InlineEnd) => {
+                (side.opposite(), alignment).into()
+            }
+            (TryTactic::Block, _) | (TryTactic::Inline, _) => (side, opposite_alignment).into(),
+            (TryTactic::Start, _) => {
+                let side = match side {
+                    LogicalSide::BlockStart => LogicalSide::InlineStart,
+                    LogicalSide::BlockEnd => LogicalSide::InlineEnd,
+                    LogicalSide::InlineStart => LogicalSide::BlockStart,
+                    LogicalSide::InlineEnd => LogicalSide::BlockEnd,
+                };
+                (side, alignment).into()
+            }
+        }
+    }
+}
+
+/// Combinations of tactics, which together reach every placement with the same kind of alignment.
+const TRY_TACTICS: [&[TryTactic]; 8] = [
+    &[],
+    &[TryTactic::Block],
+    &[TryTactic::Inline],
+    &[TryTactic::Block, TryTactic::Inline],
+    &[TryTactic::Start],
+    &[TryTactic::Block, TryTactic::Start],
+    &[TryTactic::Inline, TryTactic::Start],
+    &[TryTactic::Block, TryTactic::Inline, TryTactic::Start],
+];
+
+fn position_area(placement: LogicalPlacement) -> String {
+    let side = placement.side();
+    let span = match (side, placement.alignment()) {
+        (_, None) => return side.as_str().to_owned(),
+        (LogicalSide::BlockStart | LogicalSide::BlockEnd, Some(Alignment::Start)) => {
+            "span-inline-end"
+        }
+        (LogicalSide::BlockStart | LogicalSide::BlockEnd, Some(Alignment::End)) => {
+            "span-inline-start"
+        }
+        (LogicalSide::InlineStart | LogicalSide::InlineEnd, Some(Alignment::Start)) => {
+            "span-block-end"
+        }
+        (LogicalSide::InlineStart | LogicalSide::InlineEnd, Some(Alignment::End)) => {
+            "span-block-start"
+        }
+    };
+
+    format!("{} {span}", side.as_str())
+}
+
+fn offset_margin(side: LogicalSide) -> &'static str {
+    match side {
+        LogicalSide::BlockStart => "margin-block-end",
+        LogicalSide::BlockEnd => "margin-block-start",
+        LogicalSide::InlineStart => "margin-inline-end",
+        LogicalSide::InlineEnd => "margin-inline-start",
+    }
+}
+
+/// CSS anchor positioning equivalent of a [`ComputePositionSpec`].
+///
+/// Logical keywords are used, so the styles only apply to a floating element with the writing mode and direction passed to [`AnchorPositioning::new`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct AnchorPositioning {
+    /// Value of the `position` property, e.g. `absolute`.
+    pub position: Strategy,
+
+    /// Value of the `position-area` property, e.g. `block-end span-inline-end`.
+    pub position_area: String,
+
+    /// Value of the `position-try-fallbacks` property, e.g. `flip-block`.
+    pub position_try_fallbacks: Option<String>,
+
+    /// Margin property and value in pixels between the reference and floating element, e.g. `margin-block-start` for [`Offset`][`crate::Offset`].
+    pub margin: Option<(&'static str, f64)>,
+}
+
+impl AnchorPositioning {
+    /// Translates the spec for a floating element with the given writing mode and direction.
+    pub fn new(
+        spec: &ComputePositionSpec,
+        writing_mode: WritingMode,
+        rtl: bool,
+    ) -> Result<Self, AnchorPositioningError> {
+        let placement = match spec.logical_placement {
+            Some(logical_placement) => logical_placement.resolve(writing_mode, rtl),
+            None => spec.placement.unwrap_or(Placement::Bottom),
+        };
+        let logical_placement = LogicalPlacement::from_placement(placement, writing_mode, rtl);
+
+        let mut offset = 0.0;
+        let mut fallback_placements = vec![];
+
+        for middleware in spec.middleware.iter().flatten() {
+            match middleware {
+                MiddlewareSpec::Offset(options) => offset += offset_main_axis(options)?,
+                MiddlewareSpec::Flip(options) => fallback_placements.append(
+                    &mut flip_fallback_placements(options, placement, writing_mode, rtl)?,
+                ),
+                MiddlewareSpec::Shift(options) => check_shift(options)?,
+                MiddlewareSpec::AutoPlacement(_) => {
+                    return Err(AnchorPositioningError::UnsupportedMiddleware {
+                        name: AUTO_PLACEMENT_NAME,
+                    });
+                }
+                MiddlewareSpec::Hide(_) => {
+                    return Err(AnchorPositioningError::UnsupportedMiddleware { name: HIDE_NAME });
+                }
+                MiddlewareSpec::Inline(_) => {
+                    return Err(AnchorPositioningError::UnsupportedMiddleware {
+                        name: INLINE_NAME,
+                    });
+                }
+            }
+        }
+
+        let mut position_try_fallbacks: Vec<String> = vec![];
+        for fallback_placement in fallback_placements {
+            let target = LogicalPlacement::from_placement(fallback_placement, writing_mode, rtl);
+            let tactics = TRY_TACTICS
+                .into_iter()
+                .find(|tactics| {
+                    tactics.iter().fold(logical_placement, |placement, tactic| {
+                        tactic.apply(placement)
+                    }) == target
+                })
+                .ok_or(AnchorPositioningError::UnsupportedFallbackPlacement {
+                    placement: fallback_placement,
+                })?;
+
+            if tactics.is_empty() {
+                continue;
+            }
+
+            let fallback = tactics
+                .iter()
+                .map(TryTactic::as_str)
+                .collect::<Vec<_>>()
+                .join(" ");
+            if !position_try_fallbacks.contains(&fallback) {
+                position_try_fallbacks.push(fallback);
+            }
+        }
+
+        Ok(AnchorPositioning {
+            position: spec.strategy.unwrap_or(Strategy::Absolute),
+            position_area: position_area(logical_placement),
+            position_try_fallbacks: (!position_try_fallbacks.is_empty())
+                .then(|| position_try_fallbacks.join(", ")),
+            margin: (offset != 0.0).then_some((offset_margin(logical_placement.side()), offset)),
+        })
+    }
+
+    /// Applies the styles to the reference and floating element, linking them with the anchor name, e.g. `--tooltip`.
+    pub fn apply(&self, reference: &Element, floating: &Element, anchor_name: &str) {
+        set_style(reference, "anchor-name", anchor_name);
+
+        set_style(floating, "position", self.position.as_str());
+        set_style(floating, "position-anchor", anchor_name);
+        set_style(floating, "position-area", &self.position_area);
+        if let Some(position_try_fallbacks) = &self.position_try_fallbacks {
+            set_style(floating, "position-try-fallbacks", position_try_fallbacks);
+        }
+        if let Some((property, value)) = self.margin {
+            set_style(floating, property, &format!("{value}px"));
+        }
+        remove_style(floating, "left");
+        remove_style(floating, "top");
+    }
+
+    /// Removes the styles added by [`AnchorPositioning::apply`].
+    pub fn remove(&self, reference: &Element, floating: &Element) {
+        remove_style(reference, "anchor-name");
+
+        for property in [
+            "position",
+            "position-anchor",
+            "position-area",
+            "position-try-fallbacks",
+        ] {
+            remove_style(floating, property);
+        }
+        if let Some((property, _)) = self.margin {
+            remove_style(floating, property);
+        }
+    }
+}
+
+fn offset_main_axis(options: &OffsetOptions) -> Result<f64, AnchorPositioningError> {
+    match options {
+        OffsetOptions::Value(value) => Ok(*value),
+        OffsetOptions::Values(values) => {
+            if values
+                .cross_axis
+                .is_some_and(|cross_axis| cross_axis != 0.0)
+            {
+                Err(AnchorPositioningError::UnsupportedOption {
+                    name: OFFSET_NAME,
+                    option: "cross_axis",
+                })
+            } else if values.alignment_axis.is_some() {
+                Err(AnchorPositioningError::UnsupportedOption {
+                    name: OFFSET_NAME,
+                    option: "alignment_axis",
+                })
+            } else {
+                Ok(values.main_axis.unwrap_or(0.0))
+            }
+        }
+    }
+}
+
+fn flip_fallback_placements(
+    options: &FlipOptions<Element>,
+    placement: Placement,
+    writing_mode: WritingMode,
+    rtl: bool,
+) -> Result<Vec<Placement>, AnchorPositioningError> {
+    if options.detect_overflow.is_some() {
+        return Err(AnchorPositioningError::UnsupportedOption {
+            name: FLIP_NAME,
+            option: "detect_overflow",
+        });
+    }
+    if options
+        .cross_axis
+        .is_some_and(|cross_axis| cross_axis != CrossAxis::True)
+    {
+        return Err(AnchorPositioningError::UnsupportedOption {
+            name: FLIP_NAME,
+            option: "cross_axis",
+        });
+    }
+
+    if !options.main_axis.unwrap_or(true) {
+        return Ok(vec![]);
+    }
+
+    let flip_alignment = options.flip_alignment.unwrap_or(true);
+
+    Ok(match &options.fallback_placements {
+        Some(fallback_placements) => fallback_placements.clone(),
+        None => {
+            let mut placements = if placement.alignment().is_none() || !flip_alignment {
+                vec![get_opposite_placement(placement)]
+            } else {
+                get_expanded_placements(placement)
+            };
+
+            if options.fallback_axis_side_direction.is_some() {
+                placements.append(&mut get_opposite_axis_placements_with_writing_mode(
+                    placement,
+                    flip_alignment,
+                    options.fallback_axis_side_direction,
+                    Some(rtl),
+                    Some(writing_mode),
+                ));
+            }
+
+            placements
+        }
+    })
+}
+
+fn check_shift(options: &ShiftOptions<Element, Window>) -> Result<(), AnchorPositioningError> {
+    if options.detect_overflow.is_some() {
+        Err(AnchorPositioningError::UnsupportedOption {
+            name: SHIFT_NAME,
+            option: "detect_overflow",
+        })
+    } else if !options.main_axis.unwrap_or(true) {
+        Err(AnchorPositioningError::UnsupportedOption {
+            name: SHIFT_NAME,
+            option: "main_axis",
+        })
+    } else if options.cross_axis.unwrap_or(false) {
+        Err(AnchorPositioningError::UnsupportedOption {
+            name: SHIFT_NAME,
+            option: "cross_axis",
+        })
+    } else {
+        Ok(())
+    }
+}
+
+fn set_style(element: &Element, property: &str, value: &str) {
+    element
+        .unchecked_ref::<HtmlElement>()
+        .style()
+        .set_property(property, value)
+        .expect("Style should be updated.");
+}
+
+fn remove_style(element: &Element, property: &str) {
+    element
+        .unchecked_ref::<HtmlElement>()
+        .style()
+        .remove_property(property)
+        .expect("Style should be updated.");
+}
+
+/// Whether the browser supports [CSS anchor positioning](https://developer.mozilla.org/en-US/docs/Web/CSS/CSS_anchor_positioning).
+pub fn supports_anchor_positioning() -> bool {
+    web_sys::css::supports_with_value("anchor-name", "--floating-ui").unwrap_or(false)
+        && web_sys::css::supports_with_value("position-try-fallbacks", "flip-block")
+            .unwrap_or(false)
+}
+
+thread_local! {
+    static ANCHOR_ID: Cell<usize> = const { Cell::new(0) };
+}
+
+/// Options for [`anchor_position`].
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct AnchorPositionOptions {
+    /// Anchor name linking the reference and floating element, e.g. `--tooltip`.
+    ///
+    /// Defaults to a unique name.
+    pub anchor_name: Option<String>,
+
+    /// Options for [`auto_update`] when the position is computed in JavaScript.
+    ///
+    /// Defaults to [`AutoUpdateOptions::default`].
+    pub auto_update: Option<AutoUpdateOptions>,
+
+    /// Whether to always compute the position in JavaScript, e.g. for comparison.
+    ///
+    /// Defaults to `false`.
+    pub force_fallback: Option<bool>,
+}
+
+impl AnchorPositionOptions {
+    /// Set `anchor_name` option.
+    pub fn anchor_name(mut self, value: String) -> Self {
+        self.anchor_name = Some(value);
+        self
+    }
+
+    /// Set `auto_update` option.
+    pub fn auto_update(mut self, value: AutoUpdateOptions) -> Self {
+        self.auto_update = Some(value);
+        self
+    }
+
+    /// Set `force_fallback` option.
+    pub fn force_fallback(mut self, value: bool) -> Self {
+        self.force_fallback = Some(value);
+        self
+    }
+}
+
+/// Positions the floating element with native CSS anchor positioning if possible.
+///
+/// Falls back to [`compute_position`] and [`auto_update`] if the browser does not support anchor positioning
+/// or the spec has no CSS equivalent, see [`AnchorPositioning::new`].
+///
+/// Returns a cleanup function.
+pub fn anchor_position(
+    reference: &Element,
+    floating: &Element,
+    spec: ComputePositionSpec,
+    options: AnchorPositionOptions,
+) -> Box<dyn Fn()> {
+    let anchor_positioning = (!options.force_fallback.unwrap_or(false)
+        && supports_anchor_positioning())
+    .then(|| {
+        let cache = PlatformCache::new();
+
+        AnchorPositioning::new(
+            &spec,
+            expect_platform(get_writing_mode(&cache, floating)),
+            expect_platform(is_rtl(&cache, floating)),
+        )
+        .ok()
+    })
+    .flatten();
+
+    if let Some(anchor_positioning) = anchor_positioning {
+        let anchor_name = options.anchor_name.unwrap_or_else(|| {
+            let id = ANCHOR_ID.get();
+            ANCHOR_ID.set(id + 1);
+            format!("--floating-ui-anchor-{id}")
+        });
+        anchor_positioning.apply(reference, floating, &anchor_name);
+
+        let reference = reference.clone();
+        let floating = floating.clone();
+        return Box::new(move || anchor_positioning.remove(&reference, &floating));
+    }
+
+    let config = ComputePositionConfig::from(spec);
+    let update_reference = reference.clone();
+    let update_floating = floating.clone();
+    let update = Rc::new(move || {
+        let position =
+            compute_position((&update_reference).into(), &update_floating, config.clone());
+
+        set_style(&update_floating, "position", position.strategy.as_str());
+        set_style(&update_floating, "left", &format!("{}px", position.x));
+        set_style(&update_floating, "top", &format!("{}px", position.y));
+    });
+
+    auto_update(
+        reference.into(),
+        Some(floating),
+        update,
+        options.auto_update.unwrap_or_default(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn translate(spec: ComputePositionSpec) -> Result<AnchorPositioning, AnchorPositioningError> {
+        AnchorPositioning::new(&spec, WritingMode::HorizontalTb, false)
+    }
+
+    #[test]
+    fn test_placement() {
+        let positioning = translate(
+            ComputePositionSpec::default()
+                .placement(Placement::TopStart)
+                .middleware(vec![MiddlewareSpec::Offset(OffsetOptions::Value(8.0))]),
+        )
+        .unwrap();
+
+        assert_eq!(positioning.position_area, "block-start span-inline-end");
+        assert_eq!(positioning.position_try_fallbacks, None);
+        assert_eq!(positioning.margin, Some(("margin-block-end", 8.0)));
+    }
+
+    #[test]
+    fn test_flip() {
+        let positioning = translate(
+            ComputePositionSpec::default()
+                .placement(Placement::BottomStart)
+                .middleware(vec![
+                    MiddlewareSpec::Flip(FlipOptions::default()),
+                    MiddlewareSpec::Shift(ShiftOptions::default()),
+                ]),
+        )
+        .unwrap();
+
+        assert_eq!(
+            positioning.position_try_fallbacks.as_deref(),
+            Some("flip-inline, flip-block, flip-block flip-inline")
+        );
+
+        let positioning = translate(
+            ComputePositionSpec::default()
+                .placement(Placement::Top)
+                .middleware(vec![MiddlewareSpec::Flip(
+                    FlipOptions::default()
+                        .fallback_placements(vec![Placement::Right, Placement::Bottom]),
+                )]),
+        )
+        .unwrap();
+
+        assert_eq!(
+            positioning.position_try_fallbacks.as_deref(),
+            Some("flip-block flip-start, flip-block")
+        );
+    }
+
+    #[test]
+    fn test_vertical_rl() {
+        let positioning = AnchorPositioning::new(
+            &ComputePositionSpec::default().placement(Placement::Left),
+            WritingMode::VerticalRl,
+            false,
+        )
+        .unwrap();
+
+        assert_eq!(positioning.position_area, "block-end");
+    }
+
+    #[test]
+    fn test_unsupported() {
+        assert_eq!(
+            translate(
+                ComputePositionSpec::default()
+                    .middleware(vec![MiddlewareSpec::Hide(Default::default())])
+            ),
+            Err(AnchorPositioningError::UnsupportedMiddleware { name: HIDE_NAME })
+        );
+        assert_eq!(
+            translate(
+                ComputePositionSpec::default()
+                    .placement(Placement::Top)
+                    .middleware(vec![MiddlewareSpec::Flip(
+                        FlipOptions::default().fallback_placements(vec![Placement::BottomStart]),
+                    )])
+            ),
+            Err(AnchorPositioningError::UnsupportedFallbackPlacement {
+                placement: Placement::BottomStart
+            })
+        );
+    }
+}
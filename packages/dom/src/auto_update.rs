@@ -12,6 +12,8 @@ use web_sys::{
 };
 
 use crate::{
+    cache::PlatformCache,
+    platform::expect_platform,
     types::{ElementOrVirtual, OwnedElementOrVirtual},
     utils::{get_bounding_client_rect::get_bounding_client_rect, rects_are_equal::rects_are_equal},
 };
@@ -240,6 +242,12 @@ pub struct AutoUpdateOptions {
     ///
     /// Defaults to `false`.
     pub animation_frame: Option<bool>,
+
+    /// Cache to clear when the layout may have changed, i.e. on any update except ancestor scroll.
+    /// Should be the same cache as [`ComputePositionConfig::cache`][`crate::ComputePositionConfig::cache`].
+    ///
+    /// Defaults to `None`.
+    pub cache: Option<PlatformCache>,
 }
 
 impl AutoUpdateOptions {
@@ -272,6 +280,12 @@ impl AutoUpdateOptions {
         self.animation_frame = Some(value);
         self
     }
+
+    /// Set `cache` option.
+    pub fn cache(mut self, value: PlatformCache) -> Self {
+        self.cache = Some(value);
+        self
+    }
 }
 
 /// Automatically updates the position of the floating element when necessary.
@@ -311,6 +325,27 @@ pub fn auto_update(
         vec![]
     };
 
+    let scroll_closure: Closure<dyn Fn()> = Closure::new({
+        let update = update.clone();
+
+        move || {
+            update();
+        }
+    });
+
+    // Scrolling does not change the layout, so only the other updates clear the cache.
+    let update: Rc<dyn Fn()> = Rc::new({
+        let cache = options.cache.clone();
+
+        move || {
+            if let Some(cache) = cache.as_ref() {
+                cache.clear();
+            }
+
+            update();
+        }
+    });
+
     let update_closure: Closure<dyn Fn()> = Closure::new({
         let update = update.clone();
 
@@ -328,7 +363,7 @@ pub fn auto_update(
 
         if ancestor_scoll {
             event_target
-                .add_event_listener_with_callback("scroll", update_closure.as_ref().unchecked_ref())
+                .add_event_listener_with_callback("scroll", scroll_closure.as_ref().unchecked_ref())
                 .expect("Scroll event listener should be added.");
         }
 
@@ -410,10 +445,18 @@ pub fn auto_update(
         }
     }
 
+    // Computed styles are live, so the frame loop can keep measuring with the same cache.
+    let frame_cache = PlatformCache::new();
     let frame_id: Rc<RefCell<Option<i32>>> = Rc::new(RefCell::new(None));
     let prev_ref_rect: Rc<RefCell<Option<ClientRectObject>>> =
         Rc::new(RefCell::new(animation_frame.then(|| {
-            get_bounding_client_rect(reference, false, false, None)
+            expect_platform(get_bounding_client_rect(
+                &frame_cache,
+                reference,
+                false,
+                false,
+                None,
+            ))
         })));
 
     let frame_loop_frame_id = frame_id.clone();
@@ -426,10 +469,16 @@ pub fn auto_update(
         let update = update.clone();
         let prev_ref_rect = prev_ref_rect.clone();
         let frame_loop_frame_id = frame_loop_frame_id.clone();
+        let frame_cache = frame_cache.clone();
 
         move || {
-            let next_ref_rect =
-                get_bounding_client_rect((&owned_reference).into(), false, false, None);
+            let next_ref_rect = expect_platform(get_bounding_client_rect(
+                &frame_cache,
+                (&owned_reference).into(),
+                false,
+                false,
+                None,
+            ));
 
             if let Some(prev_ref_rect) = prev_ref_rect.borrow().as_ref()
                 && !rects_are_equal(prev_ref_rect, &next_ref_rect)
@@ -450,7 +499,13 @@ pub fn auto_update(
     if animation_frame {
         // Frame loop closure can't be called here, so the code below is copied.
 
-        let next_ref_rect = get_bounding_client_rect((&owned_reference).into(), false, false, None);
+        let next_ref_rect = expect_platform(get_bounding_client_rect(
+            &frame_cache,
+            (&owned_reference).into(),
+            false,
+            false,
+            None,
+        ));
 
         if let Some(prev_ref_rect) = prev_ref_rect.borrow().as_ref()
             && (next_ref_rect.x != prev_ref_rect.x
@@ -484,7 +539,7 @@ pub fn auto_update(
                 event_target
                     .remove_event_listener_with_callback(
                         "scroll",
-                        update_closure.as_ref().unchecked_ref(),
+                        scroll_closure.as_ref().unchecked_ref(),
                     )
                     .expect("Scroll event listener should be removed.");
             }
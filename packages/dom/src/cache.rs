@@ -0,0 +1,352 @@
+use std::{
+    cell::RefCell,
+    collections::HashMap,
+    sync::{
+        Arc,
+        atomic::{AtomicU64, Ordering},
+    },
+};
+
+use floating_ui_core::{Boundary, GetClippingRectArgs, PlatformError, RootBoundary};
+use floating_ui_utils::{OwnedElementOrWindow, Rect, Strategy, dom::try_get_computed_style};
+use web_sys::{CssStyleDeclaration, Element, Window};
+
+#[derive(Debug, Default)]
+struct PlatformCacheEntries {
+    clipping_element_ancestors: Vec<(Element, Vec<Element>)>,
+    computed_styles: Vec<(Element, CssStyleDeclaration)>,
+    #[cfg(test)]
+    computed_style_lookups: usize,
+    offset_parents: Vec<(Element, OwnedElementOrWindow<Element, Window>)>,
+    /// Values which change when scrolling, only stored while a batch is active.
+    batch: Option<PlatformCacheBatchEntries>,
+}
+
+#[derive(Debug, Default)]
+struct PlatformCacheBatchEntries {
+    viewport_rects: Vec<((Element, Strategy), Rect)>,
+    document_rects: Vec<(Element, Rect)>,
+    clipping_rects: Vec<(ClippingRectKey, Rect)>,
+}
+
+#[derive(Debug, PartialEq)]
+struct ClippingRectKey {
+    element: Element,
+    boundary: Boundary<Element>,
+    root_boundary: RootBoundary,
+    strategy: Strategy,
+}
+
+static NEXT_CACHE_ID: AtomicU64 = AtomicU64::new(0);
+
+thread_local! {
+    // DOM values can not be sent between threads, so the entries are stored outside of the `Send` cache handle.
+    static CACHE_ENTRIES: RefCell<HashMap<u64, PlatformCacheEntries>> = RefCell::new(HashMap::new());
+}
+
+#[derive(Debug)]
+struct PlatformCacheId(u64);
+
+impl Drop for PlatformCacheId {
+    fn drop(&mut self) {
+        // The entries may already be destroyed if the thread is exiting.
+        _ = CACHE_ENTRIES.try_with(|entries| entries.borrow_mut().remove(&self.0));
+    }
+}
+
+/// Cache for layout queries of the [`Platform`][`crate::Platform`].
+///
+/// Stores clipping ancestors, computed styles and offset parents of elements. By default, a new cache is used for every [`compute_position`][`crate::compute_position`] call.
+/// During [`compute_positions`][`crate::compute_positions`], the viewport, document and clipping rects are stored as well.
+/// To share a cache between calls, pass it to both [`ComputePositionConfig::cache`][`crate::ComputePositionConfig::cache`] and [`AutoUpdateOptions::cache`][`crate::AutoUpdateOptions::cache`], which clears it when the layout may have changed.
+///
+/// Cloning the cache returns a handle to the same cache. The cached values are only available on the thread which stored them.
+#[derive(Clone, Debug)]
+pub struct PlatformCache {
+    id: Arc<PlatformCacheId>,
+}
+
+impl PlatformCache {
+    /// Create a new cache.
+    pub fn new() -> Self {
+        PlatformCache {
+            id: Arc::new(PlatformCacheId(
+                NEXT_CACHE_ID.fetch_add(1, Ordering::Relaxed),
+            )),
+        }
+    }
+
+    /// Removes all cached values.
+    pub fn clear(&self) {
+        CACHE_ENTRIES.with(|entries| entries.borrow_mut().remove(&self.id.0));
+    }
+
+    fn with_entries<T>(&self, f: impl FnOnce(&mut PlatformCacheEntries) -> T) -> T {
+        CACHE_ENTRIES.with(|entries| f(entries.borrow_mut().entry(self.id.0).or_default()))
+    }
+
+    /// Stores values which change when scrolling until the returned batch is dropped.
+    ///
+    /// The layout must not change while the batch is active.
+    pub(crate) fn start_batch(&self) -> PlatformCacheBatch {
+        self.with_entries(|entries| {
+            entries.batch.get_or_insert_default();
+        });
+
+        PlatformCacheBatch {
+            cache: self.clone(),
+        }
+    }
+
+    fn get_batch_value<K: PartialEq, T: Clone>(
+        &self,
+        select: impl Fn(&mut PlatformCacheBatchEntries) -> &mut Vec<(K, T)>,
+        key: K,
+        f: impl FnOnce() -> Result<T, PlatformError>,
+    ) -> Result<T, PlatformError> {
+        let cached = self.with_entries(|entries| {
+            entries
+                .batch
+                .as_mut()
+                .map(|batch| find(select(batch), &key))
+        });
+
+        match cached {
+            Some(Some(value)) => Ok(value),
+            Some(None) => {
+                let value = f()?;
+                self.with_entries(|entries| {
+                    if let Some(batch) = entries.batch.as_mut() {
+                        select(batch).push((key, value.clone()));
+                    }
+                });
+                Ok(value)
+            }
+            None => f(),
+        }
+    }
+
+    pub(crate) fn get_viewport_rect(
+        &self,
+        document_element: &Element,
+        strategy: Strategy,
+        f: impl FnOnce() -> Result<Rect, PlatformError>,
+    ) -> Result<Rect, PlatformError> {
+        self.get_batch_value(
+            |batch| &mut batch.viewport_rects,
+            (document_element.clone(), strategy),
+            f,
+        )
+    }
+
+    pub(crate) fn get_document_rect(
+        &self,
+        document_element: &Element,
+        f: impl FnOnce() -> Result<Rect, PlatformError>,
+    ) -> Result<Rect, PlatformError> {
+        self.get_batch_value(
+            |batch| &mut batch.document_rects,
+            document_element.clone(),
+            f,
+        )
+    }
+
+    pub(crate) fn get_clipping_rect(
+        &self,
+        args: &GetClippingRectArgs<Element>,
+        f: impl FnOnce() -> Result<Rect, PlatformError>,
+    ) -> Result<Rect, PlatformError> {
+        self.get_batch_value(
+            |batch| &mut batch.clipping_rects,
+            ClippingRectKey {
+                element: args.element.clone(),
+                boundary: args.boundary.clone(),
+                root_boundary: args.root_boundary.clone(),
+                strategy: args.strategy,
+            },
+            f,
+        )
+    }
+
+    pub(crate) fn get_clipping_element_ancestors(
+        &self,
+        element: &Element,
+        f: impl FnOnce() -> Result<Vec<Element>, PlatformError>,
+    ) -> Result<Vec<Element>, PlatformError> {
+        if let Some(ancestors) =
+            self.with_entries(|entries| find(&entries.clipping_element_ancestors, element))
+        {
+            return Ok(ancestors);
+        }
+
+        let ancestors = f()?;
+        self.with_entries(|entries| {
+            entries
+                .clipping_element_ancestors
+                .push((element.clone(), ancestors.clone()))
+        });
+        Ok(ancestors)
+    }
+
+    pub(crate) fn get_computed_style(
+        &self,
+        element: &Element,
+    ) -> Result<CssStyleDeclaration, PlatformError> {
+        if let Some(computed_style) =
+            self.with_entries(|entries| find(&entries.computed_styles, element))
+        {
+            return Ok(computed_style);
+        }
+
+        let computed_style = try_get_computed_style(element).ok_or(PlatformError::MissingWindow)?;
+        self.with_entries(|entries| {
+            #[cfg(test)]
+            {
+                entries.computed_style_lookups += 1;
+            }
+
+            entries
+                .computed_styles
+                .push((element.clone(), computed_style.clone()))
+        });
+        Ok(computed_style)
+    }
+
+    pub(crate) fn get_offset_parent(
+        &self,
+        element: &Element,
+        f: impl FnOnce() -> Result<OwnedElementOrWindow<Element, Window>, PlatformError>,
+    ) -> Result<OwnedElementOrWindow<Element, Window>, PlatformError> {
+        if let Some(offset_parent) =
+            self.with_entries(|entries| find(&entries.offset_parents, element))
+        {
+            return Ok(offset_parent);
+        }
+
+        let offset_parent = f()?;
+        self.with_entries(|entries| {
+            entries
+                .offset_parents
+                .push((element.clone(), offset_parent.clone()))
+        });
+        Ok(offset_parent)
+    }
+}
+
+impl Default for PlatformCache {
+    fn default() -> Self {
+        PlatformCache::new()
+    }
+}
+
+impl PartialEq for PlatformCache {
+    fn eq(&self, other: &Self) -> bool {
+        self.id.0 == other.id.0
+    }
+}
+
+/// Batch of [`PlatformCache::start_batch`], which removes the values stored for the batch when dropped.
+pub(crate) struct PlatformCacheBatch {
+    cache: PlatformCache,
+}
+
+impl PlatformCacheBatch {
+    pub(crate) fn cache(&self) -> &PlatformCache {
+        &self.cache
+    }
+}
+
+impl Drop for PlatformCacheBatch {
+    fn drop(&mut self) {
+        // The entries may already be destroyed if the thread is exiting.
+        _ = CACHE_ENTRIES.try_with(|entries| {
+            if let Some(entries) = entries.borrow_mut().get_mut(&self.cache.id.0) {
+                entries.batch = None;
+            }
+        });
+    }
+}
+
+fn find<K: PartialEq, T: Clone>(entries: &[(K, T)], key: &K) -> Option<T> {
+    entries
+        .iter()
+        .find(|(cached_key, _)| cached_key == key)
+        .map(|(_, value)| value.clone())
+}
+
+#[cfg(target_arch = "wasm32")]
+#[cfg(test)]
+mod tests {
+    use wasm_bindgen_test::*;
+    use web_sys::window;
+
+    use crate::{
+        ComputePositionConfig, compute_position,
+        middleware::{
+            Flip, FlipOptions, Hide, HideOptions, Shift, ShiftOptions, Size, SizeOptions,
+        },
+    };
+
+    use super::*;
+
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    fn create_element(document: &web_sys::Document, style: &str) -> Element {
+        let element = document
+            .create_element("div")
+            .expect("Element should be created.");
+        element
+            .set_attribute("style", style)
+            .expect("Style should be set.");
+        element
+    }
+
+    #[wasm_bindgen_test]
+    fn looks_up_computed_styles_once_per_element() {
+        let document = window()
+            .and_then(|window| window.document())
+            .expect("Document should exist.");
+        let body = document.body().expect("Body should exist.");
+
+        let container = create_element(
+            &document,
+            "position: relative; overflow: auto; width: 200px; height: 200px;",
+        );
+        let reference = create_element(&document, "width: 50px; height: 20px;");
+        let floating = create_element(
+            &document,
+            "position: absolute; top: 0; left: 0; width: 100px; height: 40px;",
+        );
+        container
+            .append_with_node_2(&reference, &floating)
+            .expect("Elements should be appended.");
+        body.append_with_node_1(&container)
+            .expect("Container should be appended.");
+
+        let cache = PlatformCache::new();
+        compute_position(
+            (&reference).into(),
+            &floating,
+            ComputePositionConfig::default()
+                .cache(cache.clone())
+                .middleware(vec![
+                    Box::new(Flip::new(FlipOptions::default())),
+                    Box::new(Shift::new(ShiftOptions::default())),
+                    Box::new(Size::new(SizeOptions::default())),
+                    Box::new(Hide::new(HideOptions::default())),
+                ]),
+        );
+
+        let (lookups, elements) = cache.with_entries(|entries| {
+            (
+                entries.computed_style_lookups,
+                entries.computed_styles.len(),
+            )
+        });
+        container.remove();
+
+        // Each middleware measures the floating element, but every computed style is only looked up once.
+        assert!(elements > 0);
+        assert_eq!(lookups, elements);
+    }
+}
@@ -0,0 +1,137 @@
+use std::{
+    cell::{Cell, RefCell},
+    rc::Rc,
+};
+
+use floating_ui_utils::{ClientRectObject, Coords, Rect};
+use web_sys::{
+    Element, MouseEvent,
+    wasm_bindgen::{JsCast, closure::Closure},
+    window,
+};
+
+use crate::types::DefaultVirtualElement;
+
+/// Axis on which a floating element follows the client point.
+#[derive(Copy, Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub enum ClientPointAxis {
+    /// Follow the horizontal position, the vertical position and height of the reference element are kept.
+    X,
+    /// Follow the vertical position, the horizontal position and width of the reference element are kept.
+    Y,
+    #[default]
+    Both,
+}
+
+/// Creates a virtual element at a client point on the reference element, e.g. the cursor position.
+///
+/// The point is stored relative to the reference element, so the virtual element moves along when an ancestor is scrolled.
+/// On a locked axis, the virtual element spans the reference element instead of the point,
+/// so placements on that axis keep their distance to the reference element.
+pub fn virtual_element_from_client_point(
+    reference: &Element,
+    point: Coords,
+    axis: ClientPointAxis,
+) -> DefaultVirtualElement {
+    let initial_rect = reference.get_bounding_client_rect();
+    let offset_x = point.x - initial_rect.x();
+    let offset_y = point.y - initial_rect.y();
+
+    DefaultVirtualElement::new(Rc::new({
+        let reference = reference.clone();
+
+        move || {
+            let rect = reference.get_bounding_client_rect();
+
+            ClientRectObject::from(match axis {
+                ClientPointAxis::X => Rect {
+                    x: rect.x() + offset_x,
+                    y: rect.y(),
+                    width: 0.0,
+                    height: rect.height(),
+                },
+                ClientPointAxis::Y => Rect {
+                    x: rect.x(),
+                    y: rect.y() + offset_y,
+                    width: rect.width(),
+                    height: 0.0,
+                },
+                ClientPointAxis::Both => Rect {
+                    x: rect.x() + offset_x,
+                    y: rect.y() + offset_y,
+                    width: 0.0,
+                    height: 0.0,
+                },
+            })
+        }
+    }))
+    .context_element(reference.clone())
+}
+
+/// Calls `on_point` with the cursor position while the pointer moves over the reference element.
+///
+/// Updates are throttled to animation frames, only the latest position of a frame is reported.
+/// Returns a cleanup function that removes the event listeners and cancels a pending frame.
+pub fn track_client_point(reference: &Element, on_point: Rc<dyn Fn(Coords)>) -> Box<dyn Fn()> {
+    let latest_point: Rc<RefCell<Option<Coords>>> = Rc::new(RefCell::new(None));
+    let frame_id: Rc<Cell<Option<i32>>> = Rc::new(Cell::new(None));
+
+    let frame_closure: Rc<Closure<dyn Fn()>> = Rc::new(Closure::new({
+        let latest_point = latest_point.clone();
+        let frame_id = frame_id.clone();
+
+        move || {
+            frame_id.set(None);
+
+            if let Some(point) = latest_point.take() {
+                on_point(point);
+            }
+        }
+    }));
+
+    let handle_pointer: Closure<dyn Fn(MouseEvent)> = Closure::new({
+        let frame_id = frame_id.clone();
+        let frame_closure = frame_closure.clone();
+
+        move |event: MouseEvent| {
+            latest_point.replace(Some(Coords {
+                x: event.client_x() as f64,
+                y: event.client_y() as f64,
+            }));
+
+            if frame_id.get().is_none() {
+                frame_id.set(Some(
+                    window()
+                        .expect("Window should exist.")
+                        .request_animation_frame((*frame_closure).as_ref().unchecked_ref())
+                        .expect("Request animation frame should be successful."),
+                ));
+            }
+        }
+    });
+
+    for event_type in ["pointerenter", "pointermove"] {
+        reference
+            .add_event_listener_with_callback(event_type, handle_pointer.as_ref().unchecked_ref())
+            .expect("Pointer event listener should be added.");
+    }
+
+    let reference = reference.clone();
+    Box::new(move || {
+        for event_type in ["pointerenter", "pointermove"] {
+            reference
+                .remove_event_listener_with_callback(
+                    event_type,
+                    handle_pointer.as_ref().unchecked_ref(),
+                )
+                .expect("Pointer event listener should be removed.");
+        }
+
+        if let Some(frame_id) = frame_id.take() {
+            window()
+                .expect("Window should exist.")
+                .cancel_animation_frame(frame_id)
+                .expect("Cancel animation frame should be successful.");
+        }
+    })
+}
@@ -0,0 +1,56 @@
+use floating_ui_utils::{Alignment, ParseError, Placement, Side};
+use web_sys::Element;
+
+/// Sets the `data-placement`, `data-side` and `data-align` attributes of the element to the string forms of the placement, e.g. `bottom-start`, `bottom` and `start`.
+///
+/// The `data-align` attribute is removed for placements without alignment.
+pub fn set_placement_data_attributes(element: &Element, placement: Placement) {
+    element
+        .set_attribute("data-placement", placement.as_str())
+        .expect("Attribute should be set.");
+    element
+        .set_attribute("data-side", placement.side().as_str())
+        .expect("Attribute should be set.");
+
+    match placement.alignment() {
+        Some(alignment) => element
+            .set_attribute("data-align", alignment.as_str())
+            .expect("Attribute should be set."),
+        None => element
+            .remove_attribute("data-align")
+            .expect("Attribute should be removed."),
+    }
+}
+
+/// Removes the `data-placement`, `data-side` and `data-align` attributes of the element.
+pub fn remove_placement_data_attributes(element: &Element) {
+    for name in ["data-placement", "data-side", "data-align"] {
+        element
+            .remove_attribute(name)
+            .expect("Attribute should be removed.");
+    }
+}
+
+/// Reads the `data-placement` attribute of the element, e.g. `bottom-start`.
+pub fn get_data_placement(element: &Element) -> Result<Option<Placement>, ParseError> {
+    element
+        .get_attribute("data-placement")
+        .map(|value| value.parse())
+        .transpose()
+}
+
+/// Reads the `data-side` attribute of the element, e.g. `bottom`.
+pub fn get_data_side(element: &Element) -> Result<Option<Side>, ParseError> {
+    element
+        .get_attribute("data-side")
+        .map(|value| value.parse())
+        .transpose()
+}
+
+/// Reads the `data-align` attribute of the element, e.g. `start`.
+pub fn get_data_align(element: &Element) -> Result<Option<Alignment>, ParseError> {
+    element
+        .get_attribute("data-align")
+        .map(|value| value.parse())
+        .transpose()
+}
@@ -0,0 +1,367 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use floating_ui_core::{
+    MiddlewareData,
+    middleware::{ArrowData, ShiftData},
+};
+use floating_ui_utils::{Alignment, Placement, Rect, Side};
+
+/// Options for a floating arrow, see [`FloatingArrowLayout`].
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct FloatingArrowOptions {
+    /// The width of the arrow.
+    ///
+    /// Defaults to `14`.
+    pub width: Option<f64>,
+
+    /// The height of the arrow.
+    ///
+    /// Defaults to `7`.
+    pub height: Option<f64>,
+
+    /// The radius of the rounded tip of the arrow.
+    ///
+    /// Defaults to `0` (sharp tip).
+    pub tip_radius: Option<f64>,
+
+    /// The width of the stroke, matching the border width of the floating element.
+    ///
+    /// Defaults to `0`.
+    pub stroke_width: Option<f64>,
+
+    /// A static CSS offset of the arrow from the aligned edge of the floating element, e.g. `15px` or `10%`,
+    /// instead of pointing at the center of the reference element.
+    /// Ignored when the floating element is shifted, so the arrow keeps pointing at the reference element.
+    ///
+    /// Defaults to [`Option::None`].
+    pub static_offset: Option<String>,
+
+    /// A custom SVG path of the arrow, drawn pointing down in a box of `width` by `height`.
+    ///
+    /// Defaults to [`Option::None`].
+    pub d: Option<String>,
+}
+
+impl FloatingArrowOptions {
+    /// Set `width` option.
+    pub fn width(mut self, value: f64) -> Self {
+        self.width = Some(value);
+        self
+    }
+
+    /// Set `height` option.
+    pub fn height(mut self, value: f64) -> Self {
+        self.height = Some(value);
+        self
+    }
+
+    /// Set `tip_radius` option.
+    pub fn tip_radius(mut self, value: f64) -> Self {
+        self.tip_radius = Some(value);
+        self
+    }
+
+    /// Set `stroke_width` option.
+    pub fn stroke_width(mut self, value: f64) -> Self {
+        self.stroke_width = Some(value);
+        self
+    }
+
+    /// Set `static_offset` option.
+    pub fn static_offset(mut self, value: String) -> Self {
+        self.static_offset = Some(value);
+        self
+    }
+
+    /// Set `d` option.
+    pub fn d(mut self, value: String) -> Self {
+        self.d = Some(value);
+        self
+    }
+}
+
+/// Attributes and styles of an SVG arrow element, shared by the `FloatingArrow` components of the framework crates.
+///
+/// The arrow consists of an `<svg>` with an optional stroke `<path>` clipped by a `<clipPath>` containing a `<rect>`,
+/// followed by the fill `<path>`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct FloatingArrowLayout {
+    /// The `width` attribute of the `<svg>` element.
+    pub width: f64,
+
+    /// The `height` attribute of the `<svg>` element.
+    pub height: f64,
+
+    /// The `viewBox` attribute of the `<svg>` element.
+    pub view_box: String,
+
+    /// The `style` attribute of the `<svg>` element, positioning it on the edge of the floating element.
+    pub style: String,
+
+    /// The `d` attribute of both paths.
+    pub d: String,
+
+    /// The `stroke-width` attribute of the stroke path, `0` if there is no stroke and the path should not be rendered.
+    pub stroke_width: f64,
+
+    /// Whether the fill path should be stroked with the fill color, to cover the inner half of the stroke path.
+    pub stroke_fill: bool,
+
+    /// The `x`, `y`, `width` and `height` attributes of the `<rect>` in the `<clipPath>`.
+    pub clip_rect: Rect,
+}
+
+impl FloatingArrowLayout {
+    /// Computes the layout of the arrow for the final placement and middleware data of the floating element.
+    ///
+    /// The arrow is hidden if the [`Arrow`][`crate::Arrow`] middleware could not center it to the reference element,
+    /// because it would point past the reference element.
+    pub fn new(
+        options: &FloatingArrowOptions,
+        placement: Placement,
+        middleware_data: &MiddlewareData,
+        rtl: bool,
+    ) -> Self {
+        let width = options.width.unwrap_or(14.0);
+        let height = options.height.unwrap_or(7.0);
+        let tip_radius = options.tip_radius.unwrap_or(0.0);
+
+        let side = placement.side();
+        let alignment = placement.alignment();
+        let is_vertical_side = matches!(side, Side::Top | Side::Bottom);
+
+        let arrow_data = middleware_data.get_typed::<ArrowData>();
+        let shift_data = middleware_data.get_typed::<ShiftData>();
+
+        let is_shifted = shift_data.is_some_and(|shift_data| {
+            if is_vertical_side {
+                shift_data.x != 0.0
+            } else {
+                shift_data.y != 0.0
+            }
+        });
+        let static_offset = options
+            .static_offset
+            .as_ref()
+            .filter(|_| !is_shifted)
+            .cloned();
+
+        // Strokes must be double the border width, as half of the stroke is clipped.
+        let stroke_width = options.stroke_width.unwrap_or(0.0) * 2.0;
+        let half_stroke_width = stroke_width / 2.0;
+
+        let svg_x = (width / 2.0) * (tip_radius / -8.0 + 1.0);
+        let svg_y = ((height / 2.0) * tip_radius) / 4.0;
+
+        let is_custom_shape = options.d.is_some();
+
+        let is_end = alignment == Some(Alignment::End);
+        let y_offset_property = if static_offset.is_some() && is_end {
+            "bottom"
+        } else {
+            "top"
+        };
+        let x_offset_property = match (static_offset.is_some(), rtl) {
+            (true, false) if is_end => "right",
+            (true, false) => "left",
+            (true, true) if is_end => "left",
+            (true, true) => "right",
+            (false, _) => "left",
+        };
+
+        let arrow_offset = |coord: Option<f64>| {
+            coord.map(|coord| {
+                static_offset
+                    .clone()
+                    .unwrap_or_else(|| format!("{coord}px"))
+            })
+        };
+        let arrow_x = arrow_offset(arrow_data.and_then(|arrow_data| arrow_data.x));
+        let arrow_y = arrow_offset(arrow_data.and_then(|arrow_data| arrow_data.y));
+
+        let d = options.d.clone().unwrap_or_else(|| {
+            format!(
+                "M0,0 H{width} L{},{} Q{},{height} {svg_x},{} Z",
+                width - svg_x,
+                height - svg_y,
+                width / 2.0,
+                height - svg_y,
+            )
+        });
+
+        let rotation = match (side, is_custom_shape) {
+            (Side::Top, true) => Some("rotate(180deg)"),
+            (Side::Top, false) => None,
+            (Side::Left, true) => Some("rotate(90deg)"),
+            (Side::Left, false) => Some("rotate(-90deg)"),
+            (Side::Bottom, true) => None,
+            (Side::Bottom, false) => Some("rotate(180deg)"),
+            (Side::Right, true) => Some("rotate(-90deg)"),
+            (Side::Right, false) => Some("rotate(90deg)"),
+        };
+
+        let mut style = "position: absolute; pointer-events: none;".to_owned();
+        if let Some(arrow_x) = arrow_x {
+            style.push_str(&format!(" {x_offset_property}: {arrow_x};"));
+        }
+        if let Some(arrow_y) = arrow_y {
+            style.push_str(&format!(" {y_offset_property}: {arrow_y};"));
+        }
+        style.push_str(&format!(
+            " {}: {};",
+            side,
+            if is_vertical_side || is_custom_shape {
+                "100%".to_owned()
+            } else {
+                format!("calc(100% - {half_stroke_width}px)")
+            }
+        ));
+        if let Some(rotation) = rotation {
+            style.push_str(&format!(" transform: {rotation};"));
+        }
+        if arrow_data.is_some_and(|arrow_data| arrow_data.center_offset != 0.0) {
+            style.push_str(" visibility: hidden;");
+        }
+
+        FloatingArrowLayout {
+            width: if is_custom_shape {
+                width
+            } else {
+                width + stroke_width
+            },
+            height: width,
+            view_box: format!("0 0 {width} {}", height.max(width)),
+            style,
+            d,
+            stroke_width: if stroke_width > 0.0 {
+                stroke_width + if is_custom_shape { 0.0 } else { 1.0 }
+            } else {
+                0.0
+            },
+            stroke_fill: stroke_width > 0.0 && !is_custom_shape,
+            clip_rect: Rect {
+                x: -half_stroke_width,
+                y: half_stroke_width * if is_custom_shape { -1.0 } else { 1.0 },
+                width: width + stroke_width,
+                height: width,
+            },
+        }
+    }
+}
+
+static FLOATING_ARROW_ID: AtomicUsize = AtomicUsize::new(0);
+
+/// Generates a unique ID for the `<clipPath>` of a floating arrow.
+pub fn floating_arrow_clip_path_id() -> String {
+    format!(
+        "floating-ui-arrow-{}",
+        FLOATING_ARROW_ID.fetch_add(1, Ordering::Relaxed)
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn middleware_data(arrow_data: ArrowData, shift_data: Option<ShiftData>) -> MiddlewareData {
+        let mut middleware_data = MiddlewareData::default();
+        middleware_data.set_typed(arrow_data);
+        if let Some(shift_data) = shift_data {
+            middleware_data.set_typed(shift_data);
+        }
+        middleware_data
+    }
+
+    #[test]
+    fn test_layout() {
+        let layout = FloatingArrowLayout::new(
+            &FloatingArrowOptions::default(),
+            Placement::Top,
+            &middleware_data(
+                ArrowData {
+                    x: Some(20.0),
+                    y: None,
+                    center_offset: 0.0,
+                    alignment_offset: None,
+                },
+                None,
+            ),
+            false,
+        );
+
+        assert_eq!(layout.width, 14.0);
+        assert_eq!(layout.view_box, "0 0 14 14");
+        assert_eq!(layout.d, "M0,0 H14 L7,7 Q7,7 7,7 Z");
+        assert_eq!(
+            layout.style,
+            "position: absolute; pointer-events: none; left: 20px; top: 100%;"
+        );
+        assert_eq!(layout.stroke_width, 0.0);
+    }
+
+    #[test]
+    fn test_static_offset() {
+        let options = FloatingArrowOptions::default()
+            .static_offset("15%".to_owned())
+            .stroke_width(1.0);
+        let arrow_data = ArrowData {
+            x: None,
+            y: Some(20.0),
+            center_offset: 0.0,
+            alignment_offset: None,
+        };
+
+        assert_eq!(
+            FloatingArrowLayout::new(
+                &options,
+                Placement::RightEnd,
+                &middleware_data(arrow_data.clone(), None),
+                false
+            )
+            .style,
+            "position: absolute; pointer-events: none; bottom: 15%; right: calc(100% - 1px); transform: rotate(90deg);"
+        );
+
+        // The static offset is ignored when shifted, so the arrow keeps pointing at the reference element.
+        assert_eq!(
+            FloatingArrowLayout::new(
+                &options,
+                Placement::RightEnd,
+                &middleware_data(
+                    arrow_data,
+                    Some(ShiftData {
+                        x: 0.0,
+                        y: 5.0,
+                        enabled: Default::default(),
+                    })
+                ),
+                false
+            )
+            .style,
+            "position: absolute; pointer-events: none; top: 20px; right: calc(100% - 1px); transform: rotate(90deg);"
+        );
+    }
+
+    #[test]
+    fn test_static_offset_rtl() {
+        let layout = FloatingArrowLayout::new(
+            &FloatingArrowOptions::default().static_offset("8px".to_owned()),
+            Placement::BottomStart,
+            &middleware_data(
+                ArrowData {
+                    x: Some(20.0),
+                    y: None,
+                    center_offset: 0.0,
+                    alignment_offset: None,
+                },
+                None,
+            ),
+            true,
+        );
+
+        assert_eq!(
+            layout.style,
+            "position: absolute; pointer-events: none; right: 8px; bottom: 100%; transform: rotate(180deg);"
+        );
+    }
+}
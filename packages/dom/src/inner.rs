@@ -0,0 +1,36 @@
+use floating_ui_core::middleware::{InnerData, InnerListState};
+use web_sys::{Element, HtmlElement, wasm_bindgen::JsCast};
+
+/// Reads the layout of a list and its selected item for the [`Inner`][`crate::Inner`] middleware.
+///
+/// The items are the children of the list. Returns [`Option::None`] if there is no item at `index`.
+pub fn get_inner_list_state(list: &Element, index: usize) -> Option<InnerListState> {
+    let mut item = list.first_element_child();
+    for _ in 0..index {
+        item = item?.next_element_sibling();
+    }
+    let item = item?;
+
+    let list_rect = list.get_bounding_client_rect();
+    let item_rect = item.get_bounding_client_rect();
+    let client_top = list.client_top() as f64;
+
+    Some(InnerListState {
+        item_offset_top: item_rect.top() - list_rect.top() - client_top + list.scroll_top() as f64,
+        item_height: item_rect.height(),
+        item_count: list.child_element_count() as usize,
+        scroll_height: list.scroll_height() as f64,
+        client_top,
+    })
+}
+
+/// Applies the [`InnerData`] of the [`Inner`][`crate::Inner`] middleware to the list.
+///
+/// Sets the `max-height` style and the scroll offset of the list.
+pub fn apply_inner_data(list: &Element, data: &InnerData) {
+    list.unchecked_ref::<HtmlElement>()
+        .style()
+        .set_property("max-height", &format!("{}px", data.max_height))
+        .expect("Style should be updated.");
+    list.set_scroll_top(data.scroll_top as i32);
+}
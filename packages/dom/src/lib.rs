@@ -6,34 +6,57 @@
 //!
 //! See [@floating-ui/dom](https://www.npmjs.com/package/@floating-ui/dom) for the original package.
 
+mod anchor_positioning;
 mod auto_update;
+mod cache;
+mod client_point;
+mod data_attributes;
+mod floating_arrow;
+mod inner;
 mod middleware;
 mod platform;
+mod popover;
+mod safe_polygon;
+pub mod schedule;
+mod transition;
 mod types;
 mod utils;
+mod virtual_element;
 
 pub use self::platform::Platform;
+pub use crate::anchor_positioning::*;
 pub use crate::auto_update::*;
+pub use crate::cache::*;
+pub use crate::client_point::*;
+pub use crate::data_attributes::*;
+pub use crate::floating_arrow::*;
+pub use crate::inner::*;
 pub use crate::middleware::*;
+pub use crate::popover::*;
+pub use crate::safe_polygon::*;
+pub use crate::transition::*;
 pub use crate::types::*;
+pub use crate::virtual_element::*;
 pub use floating_ui_core::{
-    Boundary, ComputePositionReturn, Derivable, DerivableFn, DetectOverflowOptions, ElementContext,
-    Middleware, MiddlewareData, MiddlewareReturn, MiddlewareState, MiddlewareWithOptions,
-    RootBoundary,
+    Boundary, ComputePositionError, ComputePositionReturn, Derivable, DerivableFn,
+    DetectOverflowOptions, ElementContext, Middleware, MiddlewareData, MiddlewareDataError,
+    MiddlewareDataValue, MiddlewareReturn, MiddlewareReturnData, MiddlewareState,
+    MiddlewareTraceStep, MiddlewareWithOptions, PlatformError, RootBoundary,
+    replay::PlatformFixture,
 };
 #[doc(no_inline)]
 pub use floating_ui_utils::{
     AlignedPlacement, Alignment, Axis, ClientRectObject, Coords, Dimensions, ElementRects, Length,
-    Padding, PartialSideObject, Placement, Rect, Side, SideObject, Strategy, VirtualElement, dom,
+    LogicalPlacement, LogicalSide, Padding, ParseError, PartialSideObject, Placement, Rect, Side,
+    SideObject, Strategy, VirtualElement, WritingMode, dom,
 };
 
 use floating_ui_core::{
     ComputePositionConfig as CoreComputePositionConfig, compute_position as compute_position_core,
+    replay::RecordingPlatform, try_compute_position as try_compute_position_core,
 };
 use web_sys::Element;
 
-const PLATFORM: Platform = Platform {};
-
 /// Options for [`compute_position`].
 #[derive(Clone, Default)]
 pub struct ComputePositionConfig {
@@ -42,6 +65,13 @@ pub struct ComputePositionConfig {
     /// Defaults to [`Placement::Bottom`].
     pub placement: Option<Placement>,
 
+    /// Writing-mode relative placement, which takes precedence over `placement`.
+    ///
+    /// Resolved with the computed `writing-mode` and `direction` of the floating element.
+    ///
+    /// Defaults to `None`.
+    pub logical_placement: Option<LogicalPlacement>,
+
     /// The strategy to use when positioning the floating element.
     ///
     /// Defaults to [`Strategy::Absolute`].
@@ -51,6 +81,16 @@ pub struct ComputePositionConfig {
     ///
     /// Defaults to an empty vector.
     pub middleware: Option<MiddlewareVec>,
+
+    /// Whether to record every middleware invocation in [`ComputePositionReturn::trace`].
+    ///
+    /// Defaults to `false`.
+    pub trace: Option<bool>,
+
+    /// Cache for layout queries, shared between calls.
+    ///
+    /// Defaults to a new cache for every call.
+    pub cache: Option<PlatformCache>,
 }
 
 impl ComputePositionConfig {
@@ -60,6 +100,12 @@ impl ComputePositionConfig {
         self
     }
 
+    /// Set `logical_placement` option.
+    pub fn logical_placement(mut self, value: LogicalPlacement) -> Self {
+        self.logical_placement = Some(value);
+        self
+    }
+
     /// Set `strategy` option.
     pub fn strategy(mut self, value: Strategy) -> Self {
         self.strategy = Some(value);
@@ -71,6 +117,36 @@ impl ComputePositionConfig {
         self.middleware = Some(value);
         self
     }
+
+    /// Set `trace` option.
+    pub fn trace(mut self, value: bool) -> Self {
+        self.trace = Some(value);
+        self
+    }
+
+    /// Set `cache` option.
+    pub fn cache(mut self, value: PlatformCache) -> Self {
+        self.cache = Some(value);
+        self
+    }
+}
+
+impl From<ComputePositionSpec> for ComputePositionConfig {
+    fn from(value: ComputePositionSpec) -> Self {
+        ComputePositionConfig {
+            placement: value.placement,
+            logical_placement: value.logical_placement,
+            strategy: value.strategy,
+            middleware: value.middleware.map(|middleware| {
+                middleware
+                    .into_iter()
+                    .map(MiddlewareSpec::into_middleware)
+                    .collect()
+            }),
+            trace: None,
+            cache: None,
+        }
+    }
 }
 
 /// Computes the `x` and `y` coordinates that will place the floating element next to a given reference element.
@@ -79,16 +155,114 @@ pub fn compute_position(
     floating: &Element,
     config: ComputePositionConfig,
 ) -> ComputePositionReturn {
-    // TODO: cache
+    let platform = Platform::with_cache(config.cache.unwrap_or_default());
 
     compute_position_core(
         reference,
         floating,
         CoreComputePositionConfig {
-            platform: &PLATFORM,
+            platform: &platform,
+            placement: config.placement,
+            logical_placement: config.logical_placement,
+            strategy: config.strategy,
+            middleware: config.middleware,
+            trace: config.trace,
+        },
+    )
+}
+
+/// Computes the `x` and `y` coordinates that will place the floating element next to a given reference element, recording every answer of the platform.
+///
+/// The [`PlatformFixture`] can be serialized with [`PlatformFixture::to_json`], e.g. to attach it to a bug report,
+/// and replayed natively with a [`ReplayPlatform`][`floating_ui_core::replay::ReplayPlatform`].
+pub fn record_compute_position(
+    reference: ElementOrVirtual,
+    floating: &Element,
+    config: ComputePositionConfig,
+) -> (ComputePositionReturn, PlatformFixture) {
+    let platform = Platform::with_cache(config.cache.unwrap_or_default());
+    let recording = RecordingPlatform::new(&platform, reference.clone(), floating);
+
+    let position = compute_position_core(
+        reference,
+        floating,
+        CoreComputePositionConfig {
+            platform: &recording,
+            placement: config.placement,
+            logical_placement: config.logical_placement,
+            strategy: config.strategy,
+            middleware: config.middleware,
+            trace: config.trace,
+        },
+    );
+
+    (position, recording.into_fixture())
+}
+
+/// Computes the `x` and `y` coordinates that will place the floating element next to a given reference element, returning an error instead of panicking.
+///
+/// Fails if the reference or floating element is not connected to a document or its window does not exist, e.g. during teardown.
+pub fn try_compute_position(
+    reference: ElementOrVirtual,
+    floating: &Element,
+    config: ComputePositionConfig,
+) -> Result<ComputePositionReturn, ComputePositionError> {
+    let platform = Platform::with_cache(config.cache.unwrap_or_default());
+
+    try_compute_position_core(
+        reference,
+        floating,
+        CoreComputePositionConfig {
+            platform: &platform,
             placement: config.placement,
+            logical_placement: config.logical_placement,
             strategy: config.strategy,
             middleware: config.middleware,
+            trace: config.trace,
         },
     )
 }
+
+/// Computes the positions of many floating elements in one pass, sharing layout queries between them.
+///
+/// Entries without a [`ComputePositionConfig::cache`] share a new cache.
+/// The viewport, document and clipping rects are measured once per batch, so the layout must not change while computing.
+pub fn compute_positions<'a>(
+    entries: impl IntoIterator<Item = (ElementOrVirtual<'a>, &'a Element, ComputePositionConfig)>,
+) -> Vec<ComputePositionReturn> {
+    compute_batch(entries, compute_position)
+}
+
+/// Computes the positions of many floating elements in one pass, sharing layout queries between them, returning an error for each entry which could not be computed.
+///
+/// Entries without a [`ComputePositionConfig::cache`] share a new cache.
+/// The viewport, document and clipping rects are measured once per batch, so the layout must not change while computing.
+pub fn try_compute_positions<'a>(
+    entries: impl IntoIterator<Item = (ElementOrVirtual<'a>, &'a Element, ComputePositionConfig)>,
+) -> Vec<Result<ComputePositionReturn, ComputePositionError>> {
+    compute_batch(entries, try_compute_position)
+}
+
+fn compute_batch<'a, T>(
+    entries: impl IntoIterator<Item = (ElementOrVirtual<'a>, &'a Element, ComputePositionConfig)>,
+    compute: impl Fn(ElementOrVirtual<'a>, &'a Element, ComputePositionConfig) -> T,
+) -> Vec<T> {
+    let mut batches = vec![PlatformCache::new().start_batch()];
+
+    entries
+        .into_iter()
+        .map(|(reference, floating, config)| {
+            let cache = match config.cache.clone() {
+                Some(cache) => {
+                    if !batches.iter().any(|batch| *batch.cache() == cache) {
+                        batches.push(cache.start_batch());
+                    }
+                    cache
+                }
+                None => batches[0].cache().clone(),
+            };
+
+            compute(reference, floating, config.cache(cache))
+        })
+        .collect()
+}
@@ -1,6 +1,8 @@
 use floating_ui_core::middleware::{
     Arrow as CoreArrow, AutoPlacement as CoreAutoPlacement, Flip as CoreFlip, Hide as CoreHide,
-    Inline as CoreInline, Offset as CoreOffset, Shift as CoreShift, Size as CoreSize,
+    Inline as CoreInline, Inner as CoreInner, MiddlewareSpec as CoreMiddlewareSpec,
+    Offset as CoreOffset, Shift as CoreShift, Size as CoreSize,
+    TransformOrigin as CoreTransformOrigin,
 };
 use web_sys::{Element, Window};
 
@@ -8,9 +10,10 @@ pub use floating_ui_core::middleware::{
     ARROW_NAME, AUTO_PLACEMENT_NAME, ApplyState, ArrowData, ArrowOptions, AutoPlacementData,
     AutoPlacementDataOverflow, AutoPlacementOptions, CrossAxis, DefaultLimiter, FLIP_NAME,
     FallbackStrategy, FlipData, FlipDataOverflow, FlipOptions, HIDE_NAME, HideData, HideOptions,
-    HideStrategy, INLINE_NAME, InlineOptions, LimitShift, LimitShiftOffset, LimitShiftOffsetValues,
-    LimitShiftOptions, OFFSET_NAME, OffsetData, OffsetOptions, OffsetOptionsValues, SHIFT_NAME,
-    SIZE_NAME, ShiftData, ShiftOptions, SizeOptions,
+    HideStrategy, INLINE_NAME, INNER_NAME, InlineOptions, InnerData, InnerListState, InnerOptions,
+    LimitShift, LimitShiftOffset, LimitShiftOffsetValues, LimitShiftOptions, OFFSET_NAME,
+    OffsetData, OffsetOptions, OffsetOptionsValues, SHIFT_NAME, SIZE_NAME, ShiftData, ShiftOptions,
+    SizeOptions, TRANSFORM_ORIGIN_NAME, TransformOriginData, TransformOriginOptions,
 };
 
 /// Arrow middleware.
@@ -53,6 +56,14 @@ pub type Hide<'a> = CoreHide<'a, Element, Window>;
 /// See [the Rust Floating UI book](https://floating-ui.rustforweb.org/middleware/inline.html) for more documentation.
 pub type Inline<'a> = CoreInline<'a, Element, Window>;
 
+/// Inner middleware.
+///
+/// Positions the floating element so that the selected item of a list inside it appears over the reference element,
+/// such as in macOS-style select menus.
+///
+/// See [the Rust Floating UI book](https://floating-ui.rustforweb.org/middleware/inner.html) for more documentation.
+pub type Inner<'a> = CoreInner<'a, Element, Window>;
+
 /// Offset middleware.
 ///
 /// Modifies the placement by translating the floating element along the specified axes.
@@ -74,3 +85,16 @@ pub type Shift<'a> = CoreShift<'a, Element, Window>;
 ///
 /// See [the Rust Floating UI book](https://floating-ui.rustforweb.org/middleware/size.html) for more documentation.
 pub type Size<'a> = CoreSize<'a, Element, Window>;
+
+/// Transform origin middleware.
+///
+/// Provides data to set the `transform-origin` of the floating element to the side facing the reference element,
+/// or to the tip of the arrow element if it is centered.
+///
+/// See [the Rust Floating UI book](https://floating-ui.rustforweb.org/middleware/transform-origin.html) for more documentation.
+pub type TransformOrigin<'a> = CoreTransformOrigin<'a, Element, Window>;
+
+/// Declarative description of a middleware, e.g. stored in a configuration file.
+///
+/// See [`ComputePositionSpec`][`crate::ComputePositionSpec`].
+pub type MiddlewareSpec = CoreMiddlewareSpec<Element, Window>;
@@ -6,20 +6,22 @@ pub mod get_dimensions;
 pub mod get_element_rects;
 pub mod get_offset_parent;
 pub mod get_scale;
+pub mod get_writing_mode;
 pub mod is_rtl;
 
 use floating_ui_core::{
     ConvertOffsetParentRelativeRectToViewportRelativeRectArgs, DetectOverflowOptions,
     GetClippingRectArgs, GetElementRectsArgs, MiddlewareState, Platform as CorePlatform,
-    detect_overflow,
+    PlatformError, detect_overflow,
 };
-use floating_ui_utils::dom::get_document_element;
+use floating_ui_utils::dom::{get_document_element, try_get_document_element, try_get_window};
 use floating_ui_utils::{
-    ClientRectObject, Coords, Dimensions, ElementRects, Length, OwnedElementOrWindow, Rect,
-    SideObject,
+    ClientRectObject, Coords, Dimensions, ElementOrWindow, ElementRects, Length,
+    OwnedElementOrWindow, Rect, SideObject, WritingMode,
 };
 use web_sys::{Element, Window};
 
+use crate::cache::PlatformCache;
 use crate::types::ElementOrVirtual;
 
 use self::convert_offset_parent_relative_rect_to_viewport_relative_rect::convert_offset_parent_relative_rect_to_viewport_relative_rect;
@@ -30,36 +32,65 @@ use self::get_dimensions::get_dimensions;
 use self::get_element_rects::get_element_rects;
 use self::get_offset_parent::get_offset_parent;
 use self::get_scale::get_scale;
+use self::get_writing_mode::get_writing_mode;
 use self::is_rtl::is_rtl;
 
-#[derive(Debug)]
-pub struct Platform {}
+/// Platform interface methods to work with the DOM.
+#[derive(Clone, Debug, Default)]
+pub struct Platform {
+    cache: PlatformCache,
+}
+
+impl Platform {
+    /// Create a platform with a new cache.
+    pub fn new() -> Self {
+        Platform::default()
+    }
+
+    /// Create a platform with the given cache.
+    pub fn with_cache(cache: PlatformCache) -> Self {
+        Platform { cache }
+    }
+
+    /// Get the cache of the platform.
+    pub fn cache(&self) -> &PlatformCache {
+        &self.cache
+    }
+}
 
 impl CorePlatform<Element, Window> for Platform {
     fn get_element_rects(&self, args: GetElementRectsArgs<Element>) -> ElementRects {
-        get_element_rects(self, args)
+        expect_platform(get_element_rects(self, args))
     }
 
     fn get_clipping_rect(&self, args: GetClippingRectArgs<Element>) -> Rect {
-        get_clipping_rect(self, args)
+        expect_platform(
+            self.cache
+                .get_clipping_rect(&args, || get_clipping_rect(self, &args)),
+        )
     }
 
     fn get_dimensions(&self, element: &Element) -> Dimensions {
-        get_dimensions(element)
+        expect_platform(get_dimensions(&self.cache, element))
     }
 
     fn convert_offset_parent_relative_rect_to_viewport_relative_rect(
         &self,
         args: ConvertOffsetParentRelativeRectToViewportRelativeRectArgs<Element, Window>,
     ) -> Option<Rect> {
-        Some(convert_offset_parent_relative_rect_to_viewport_relative_rect(args))
+        Some(expect_platform(
+            convert_offset_parent_relative_rect_to_viewport_relative_rect(&self.cache, args),
+        ))
     }
 
     fn get_offset_parent(
         &self,
         element: &Element,
     ) -> Option<OwnedElementOrWindow<Element, Window>> {
-        Some(get_offset_parent(element, None))
+        Some(expect_platform(
+            self.cache
+                .get_offset_parent(element, || get_offset_parent(&self.cache, element, None)),
+        ))
     }
 
     fn get_document_element(&self, element: &Element) -> Option<Element> {
@@ -71,17 +102,127 @@ impl CorePlatform<Element, Window> for Platform {
     }
 
     fn is_rtl(&self, element: &Element) -> Option<bool> {
-        Some(is_rtl(element))
+        Some(expect_platform(is_rtl(&self.cache, element)))
+    }
+
+    fn get_writing_mode(&self, element: &Element) -> Option<WritingMode> {
+        Some(expect_platform(get_writing_mode(&self.cache, element)))
     }
 
     fn get_scale(&self, element: &Element) -> Option<Coords> {
-        Some(get_scale(element.into()))
+        Some(expect_platform(get_scale(&self.cache, element.into())))
     }
 
     fn get_client_length(&self, element: &Element, length: Length) -> Option<f64> {
         Some(get_client_length(element, length))
     }
 
+    fn try_get_element_rects(
+        &self,
+        args: GetElementRectsArgs<Element>,
+    ) -> Result<ElementRects, PlatformError> {
+        if let ElementOrVirtual::Element(reference) = args.reference {
+            check_element(reference)?;
+        }
+        check_element(args.floating)?;
+
+        get_element_rects(self, args)
+    }
+
+    fn try_get_clipping_rect(
+        &self,
+        args: GetClippingRectArgs<Element>,
+    ) -> Result<Rect, PlatformError> {
+        check_element(args.element)?;
+
+        self.cache
+            .get_clipping_rect(&args, || get_clipping_rect(self, &args))
+    }
+
+    fn try_get_dimensions(&self, element: &Element) -> Result<Dimensions, PlatformError> {
+        check_element(element)?;
+
+        get_dimensions(&self.cache, element)
+    }
+
+    fn try_convert_offset_parent_relative_rect_to_viewport_relative_rect(
+        &self,
+        args: ConvertOffsetParentRelativeRectToViewportRelativeRectArgs<Element, Window>,
+    ) -> Result<Option<Rect>, PlatformError> {
+        if let Some(elements) = &args.elements {
+            check_element(elements.floating)?;
+        }
+        if let Some(ElementOrWindow::Element(offset_parent)) = &args.offset_parent {
+            check_element(offset_parent)?;
+        }
+
+        convert_offset_parent_relative_rect_to_viewport_relative_rect(&self.cache, args).map(Some)
+    }
+
+    fn try_get_offset_parent(
+        &self,
+        element: &Element,
+    ) -> Result<Option<OwnedElementOrWindow<Element, Window>>, PlatformError> {
+        check_element(element)?;
+
+        self.cache
+            .get_offset_parent(element, || get_offset_parent(&self.cache, element, None))
+            .map(Some)
+    }
+
+    fn try_get_document_element(
+        &self,
+        element: &Element,
+    ) -> Result<Option<Element>, PlatformError> {
+        check_element(element)?;
+
+        try_get_document_element(Some(element.into()))
+            .ok_or(PlatformError::DetachedElement)
+            .map(Some)
+    }
+
+    fn try_get_client_rects(
+        &self,
+        element: ElementOrVirtual,
+    ) -> Result<Option<Vec<ClientRectObject>>, PlatformError> {
+        if let ElementOrVirtual::Element(element) = element {
+            check_element(element)?;
+        }
+
+        Ok(self.get_client_rects(element))
+    }
+
+    fn try_is_rtl(&self, element: &Element) -> Result<Option<bool>, PlatformError> {
+        check_element(element)?;
+
+        is_rtl(&self.cache, element).map(Some)
+    }
+
+    fn try_get_writing_mode(
+        &self,
+        element: &Element,
+    ) -> Result<Option<WritingMode>, PlatformError> {
+        check_element(element)?;
+
+        get_writing_mode(&self.cache, element).map(Some)
+    }
+
+    fn try_get_scale(&self, element: &Element) -> Result<Option<Coords>, PlatformError> {
+        check_element(element)?;
+
+        get_scale(&self.cache, element.into()).map(Some)
+    }
+
+    fn try_get_client_length(
+        &self,
+        element: &Element,
+        length: Length,
+    ) -> Result<Option<f64>, PlatformError> {
+        check_element(element)?;
+
+        Ok(self.get_client_length(element, length))
+    }
+
     fn detect_overflow(
         &self,
         state: MiddlewareState<Element, Window>,
@@ -90,3 +231,21 @@ impl CorePlatform<Element, Window> for Platform {
         detect_overflow(state, options)
     }
 }
+
+/// Unwraps the result of a fallible DOM measurement for the infallible platform methods.
+pub(crate) fn expect_platform<T>(result: Result<T, PlatformError>) -> T {
+    result.unwrap_or_else(|error| panic!("{error}"))
+}
+
+/// Checks whether the element can be measured.
+fn check_element(element: &Element) -> Result<(), PlatformError> {
+    if !element.is_connected() {
+        return Err(PlatformError::DetachedElement);
+    }
+
+    if try_get_window(Some(element)).is_none() {
+        return Err(PlatformError::MissingWindow);
+    }
+
+    Ok(())
+}
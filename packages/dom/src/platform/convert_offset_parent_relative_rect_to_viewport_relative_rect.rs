@@ -1,32 +1,35 @@
-use floating_ui_core::ConvertOffsetParentRelativeRectToViewportRelativeRectArgs;
+use floating_ui_core::{ConvertOffsetParentRelativeRectToViewportRelativeRectArgs, PlatformError};
 use floating_ui_utils::{
     Coords, ElementOrWindow, Rect, Strategy,
     dom::{
-        NodeScroll, get_document_element, get_node_name, get_node_scroll, is_overflow_element,
-        is_top_layer,
+        NodeScroll, get_node_name, is_top_layer, try_get_document_element, try_get_node_scroll,
+        try_is_overflow_element,
     },
 };
 use web_sys::{Element, Window};
 
 use crate::{
+    cache::PlatformCache,
     platform::get_scale::get_scale,
     utils::{get_bounding_client_rect::get_bounding_client_rect, get_html_offset::get_html_offset},
 };
 
 pub fn convert_offset_parent_relative_rect_to_viewport_relative_rect(
+    cache: &PlatformCache,
     ConvertOffsetParentRelativeRectToViewportRelativeRectArgs {
         elements,
         rect,
         offset_parent,
         strategy,
     }: ConvertOffsetParentRelativeRectToViewportRelativeRectArgs<Element, Window>,
-) -> Rect {
+) -> Result<Rect, PlatformError> {
     let is_fixed = strategy == Strategy::Fixed;
-    let document_element = get_document_element(
+    let document_element = try_get_document_element(
         offset_parent
             .as_ref()
             .map(|offset_parent| offset_parent.into()),
-    );
+    )
+    .ok_or(PlatformError::DetachedElement)?;
     let top_layer = elements.is_some_and(|elements| is_top_layer(elements.floating));
 
     if offset_parent
@@ -37,7 +40,7 @@ pub fn convert_offset_parent_relative_rect_to_viewport_relative_rect(
         })
         || (top_layer && is_fixed)
     {
-        return rect;
+        return Ok(rect);
     }
 
     let mut scroll = NodeScroll::new(0.0);
@@ -55,29 +58,32 @@ pub fn convert_offset_parent_relative_rect_to_viewport_relative_rect(
     if is_offset_parent_an_element || !is_fixed {
         if let Some(offset_parent) = offset_parent.as_ref()
             && (get_node_name(offset_parent.into()) != "body"
-                || is_overflow_element(&document_element))
+                || try_is_overflow_element(&document_element)
+                    .ok_or(PlatformError::MissingWindow)?)
         {
-            scroll = get_node_scroll(offset_parent.into());
+            scroll =
+                try_get_node_scroll(offset_parent.into()).ok_or(PlatformError::MissingWindow)?;
         }
 
         if let Some(ElementOrWindow::Element(offset_parent)) = offset_parent {
-            let offset_rect = get_bounding_client_rect(offset_parent.into(), false, false, None);
-            scale = get_scale(offset_parent.into());
+            let offset_rect =
+                get_bounding_client_rect(cache, offset_parent.into(), false, false, None)?;
+            scale = get_scale(cache, offset_parent.into())?;
             offsets.x = offset_rect.x + offset_parent.client_left() as f64;
             offsets.y = offset_rect.y + offset_parent.client_top() as f64;
         }
     }
 
     let html_offset = if !is_offset_parent_an_element && !is_fixed {
-        get_html_offset(&document_element, &scroll)
+        get_html_offset(cache, &document_element, &scroll)?
     } else {
         Coords::new(0.0)
     };
 
-    Rect {
+    Ok(Rect {
         x: rect.x * scale.x - scroll.scroll_left * scale.x + offsets.x + html_offset.x,
         y: rect.y * scale.y - scroll.scroll_top * scale.y + offsets.y + html_offset.y,
         width: rect.width * scale.x,
         height: rect.height * scale.y,
-    }
+    })
 }
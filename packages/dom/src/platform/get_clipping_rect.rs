@@ -1,16 +1,16 @@
-use floating_ui_core::{GetClippingRectArgs, RootBoundary};
+use floating_ui_core::{GetClippingRectArgs, PlatformError, RootBoundary};
 use floating_ui_utils::{
     ClientRectObject, Rect, Strategy,
     dom::{
-        OverflowAncestor, get_computed_style, get_document_element, get_node_name,
-        get_overflow_ancestors, get_parent_node, is_containing_block, is_last_traversable_node,
-        is_top_layer,
+        OverflowAncestor, get_node_name, is_containing_block, is_last_traversable_node,
+        is_top_layer, try_get_document_element, try_get_overflow_ancestors, try_get_parent_node,
     },
     rect_to_client_rect,
 };
 use web_sys::{CssStyleDeclaration, Element, Node, wasm_bindgen::JsCast};
 
 use crate::{
+    cache::PlatformCache,
     platform::{Platform, get_scale::get_scale},
     types::Boundary,
     utils::{
@@ -27,41 +27,66 @@ enum ElementOrRootBoundary {
     RootBoundary(RootBoundary),
 }
 
-fn get_inner_bounding_client_rect(element: &Element, strategy: Strategy) -> Rect {
-    let client_rect =
-        get_bounding_client_rect(element.into(), true, strategy == Strategy::Fixed, None);
+fn get_inner_bounding_client_rect(
+    cache: &PlatformCache,
+    element: &Element,
+    strategy: Strategy,
+) -> Result<Rect, PlatformError> {
+    let client_rect = get_bounding_client_rect(
+        cache,
+        element.into(),
+        true,
+        strategy == Strategy::Fixed,
+        None,
+    )?;
     let top = client_rect.top + element.client_top() as f64;
     let left = client_rect.left + element.client_left() as f64;
-    let scale = get_scale(element.into());
+    let scale = get_scale(cache, element.into())?;
 
-    Rect {
+    Ok(Rect {
         x: left * scale.x,
         y: top * scale.y,
         width: element.client_width() as f64 * scale.x,
         height: element.client_height() as f64 * scale.y,
-    }
+    })
 }
 
 fn get_client_rect_from_clipping_ancestor(
+    platform: &Platform,
     element: &Element,
     clipping_ancestor: ElementOrRootBoundary,
     strategy: Strategy,
-) -> ClientRectObject {
+) -> Result<ClientRectObject, PlatformError> {
     let rect = match clipping_ancestor {
         ElementOrRootBoundary::Element(element) => {
-            get_inner_bounding_client_rect(&element, strategy)
+            get_inner_bounding_client_rect(platform.cache(), &element, strategy)?
         }
         ElementOrRootBoundary::RootBoundary(RootBoundary::Viewport)
-        | ElementOrRootBoundary::RootBoundary(RootBoundary::LayoutViewport) => get_viewport_rect(
-            &get_document_element(Some(element.into())),
-            strategy,
-            ViewportRootBoundary::Viewport,
-        ),
+        | ElementOrRootBoundary::RootBoundary(RootBoundary::LayoutViewport) => {
+            let document_element = try_get_document_element(Some(element.into()))
+                .ok_or(PlatformError::DetachedElement)?;
+
+            platform
+                .cache()
+                .get_viewport_rect(&document_element, strategy, || {
+                    get_viewport_rect(
+                        platform.cache(),
+                        &document_element,
+                        strategy,
+                        ViewportRootBoundary::Viewport,
+                    )
+                })?
+        }
         ElementOrRootBoundary::RootBoundary(RootBoundary::Document) => {
-            get_document_rect(&get_document_element(Some(element.into())))
+            let document_element = try_get_document_element(Some(element.into()))
+                .ok_or(PlatformError::DetachedElement)?;
+
+            platform.cache().get_document_rect(&document_element, || {
+                get_document_rect(platform.cache(), &document_element)
+            })?
         }
         ElementOrRootBoundary::RootBoundary(RootBoundary::Rect(rect)) => {
-            let visual_offsets = get_visual_offsets(Some(element));
+            let visual_offsets = get_visual_offsets(Some(element))?;
             Rect {
                 x: rect.x - visual_offsets.x,
                 y: rect.y - visual_offsets.y,
@@ -71,13 +96,15 @@ fn get_client_rect_from_clipping_ancestor(
         }
     };
 
-    rect_to_client_rect(rect)
+    Ok(rect_to_client_rect(rect))
 }
 
-fn get_clipping_element_ancestors(element: &Element) -> Vec<Element> {
-    // TODO: cache
-
-    let mut result: Vec<Element> = get_overflow_ancestors(element, vec![], false)
+fn get_clipping_element_ancestors(
+    platform: &Platform,
+    element: &Element,
+) -> Result<Vec<Element>, PlatformError> {
+    let mut result: Vec<Element> = try_get_overflow_ancestors(element, vec![], false)
+        .ok_or(PlatformError::MissingWindow)?
         .into_iter()
         .filter_map(|ancestor| match ancestor {
             OverflowAncestor::Element(element) => {
@@ -88,12 +115,14 @@ fn get_clipping_element_ancestors(element: &Element) -> Vec<Element> {
         })
         .collect();
     let mut last_kept_computed_style: Option<CssStyleDeclaration> = None;
-    let element_is_fixed = get_computed_style(element)
+    let element_is_fixed = platform
+        .cache()
+        .get_computed_style(element)?
         .get_property_value("position")
         .expect("Computed style should have position.")
         == "fixed";
     let mut current_node: Node = if element_is_fixed {
-        get_parent_node(element)
+        try_get_parent_node(element).ok_or(PlatformError::DetachedElement)?
     } else {
         element.clone().into()
     };
@@ -101,8 +130,8 @@ fn get_clipping_element_ancestors(element: &Element) -> Vec<Element> {
     // https://developer.mozilla.org/en-US/docs/Web/CSS/Containing_block#identifying_the_containing_block
     while current_node.is_instance_of::<Element>() && !is_last_traversable_node(&current_node) {
         let current_element = current_node.unchecked_ref::<Element>();
-        let computed_style = get_computed_style(current_element);
-        let current_node_is_containing = is_containing_block(current_element.into());
+        let computed_style = platform.cache().get_computed_style(current_element)?;
+        let current_node_is_containing = is_containing_block(computed_style.clone().into());
 
         // Position of the containing block chain below the current node. A fixed
         // element whose containing block hasn't been found yet is a fixed chain.
@@ -134,31 +163,31 @@ fn get_clipping_element_ancestors(element: &Element) -> Vec<Element> {
             last_kept_computed_style = Some(computed_style);
         }
 
-        current_node = get_parent_node(&current_node);
+        current_node = try_get_parent_node(&current_node).ok_or(PlatformError::DetachedElement)?;
     }
 
-    // TODO: cache
-
-    result
+    Ok(result)
 }
 
 pub fn get_clipping_rect(
-    _platform: &Platform,
+    platform: &Platform,
     GetClippingRectArgs {
         element,
         boundary,
         root_boundary,
         strategy,
-    }: GetClippingRectArgs<Element>,
-) -> Rect {
-    // TODO: cache
-
+    }: &GetClippingRectArgs<Element>,
+) -> Result<Rect, PlatformError> {
     let clipping_element_ancestors = match boundary {
         Boundary::ClippingAncestors => {
             if is_top_layer(element) {
                 vec![]
             } else {
-                get_clipping_element_ancestors(element)
+                platform
+                    .cache()
+                    .get_clipping_element_ancestors(element, || {
+                        get_clipping_element_ancestors(platform, element)
+                    })?
             }
         }
         _ => vec![],
@@ -167,8 +196,8 @@ pub fn get_clipping_rect(
     let element_clipping_ancestors: Vec<Element> = clipping_element_ancestors
         .into_iter()
         .chain(match boundary {
-            Boundary::Element(element) => vec![element],
-            Boundary::Elements(elements) => elements,
+            Boundary::Element(element) => vec![element.clone()],
+            Boundary::Elements(elements) => elements.clone(),
             _ => vec![],
         })
         .collect();
@@ -176,28 +205,39 @@ pub fn get_clipping_rect(
     let clipping_ancestors: Vec<ElementOrRootBoundary> = element_clipping_ancestors
         .into_iter()
         .map(ElementOrRootBoundary::Element)
-        .chain(vec![ElementOrRootBoundary::RootBoundary(root_boundary)])
+        .chain(vec![ElementOrRootBoundary::RootBoundary(
+            root_boundary.clone(),
+        )])
         .collect();
 
-    let first_rect =
-        get_client_rect_from_clipping_ancestor(element, clipping_ancestors[0].clone(), strategy);
+    let first_rect = get_client_rect_from_clipping_ancestor(
+        platform,
+        element,
+        clipping_ancestors[0].clone(),
+        *strategy,
+    )?;
     let mut top = first_rect.top;
     let mut right = first_rect.right;
     let mut bottom = first_rect.bottom;
     let mut left = first_rect.left;
 
     for clipping_ancestor in clipping_ancestors.into_iter().skip(1) {
-        let rect = get_client_rect_from_clipping_ancestor(element, clipping_ancestor, strategy);
+        let rect = get_client_rect_from_clipping_ancestor(
+            platform,
+            element,
+            clipping_ancestor,
+            *strategy,
+        )?;
         top = top.max(rect.top);
         right = right.min(rect.right);
         bottom = bottom.min(rect.bottom);
         left = left.max(rect.left);
     }
 
-    Rect {
+    Ok(Rect {
         x: left,
         y: top,
         width: right - left,
         height: bottom - top,
-    }
+    })
 }
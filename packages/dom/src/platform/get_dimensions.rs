@@ -1,9 +1,16 @@
+use floating_ui_core::PlatformError;
 use floating_ui_utils::Dimensions;
 use web_sys::Element;
 
-use crate::utils::get_css_dimensions::{CssDimensions, get_css_dimensions};
+use crate::{
+    cache::PlatformCache,
+    utils::get_css_dimensions::{CssDimensions, get_css_dimensions},
+};
 
-pub fn get_dimensions(element: &Element) -> Dimensions {
-    let CssDimensions { dimensions, .. } = get_css_dimensions(element);
-    dimensions
+pub fn get_dimensions(
+    cache: &PlatformCache,
+    element: &Element,
+) -> Result<Dimensions, PlatformError> {
+    let CssDimensions { dimensions, .. } = get_css_dimensions(cache, element)?;
+    Ok(dimensions)
 }
@@ -1,31 +1,35 @@
-use floating_ui_core::{GetElementRectsArgs, Platform as CorePlatform};
+use floating_ui_core::{GetElementRectsArgs, PlatformError};
 use floating_ui_utils::{ElementOrWindow, ElementRects, Rect};
 use web_sys::{Element, Window};
 
 use crate::{
-    platform::Platform,
+    platform::{Platform, get_dimensions::get_dimensions, get_offset_parent::get_offset_parent},
     utils::get_rect_relative_to_offset_parent::get_rect_relative_to_offset_parent,
 };
 
-pub fn get_element_rects(platform: &Platform, args: GetElementRectsArgs<Element>) -> ElementRects {
-    let offset_parent = platform
-        .get_offset_parent(args.floating)
-        .expect("Platform implements get_offset_parent.");
-    let dimensions = platform.get_dimensions(args.floating);
+pub fn get_element_rects(
+    platform: &Platform,
+    args: GetElementRectsArgs<Element>,
+) -> Result<ElementRects, PlatformError> {
+    let offset_parent = platform.cache().get_offset_parent(args.floating, || {
+        get_offset_parent(platform.cache(), args.floating, None)
+    })?;
+    let dimensions = get_dimensions(platform.cache(), args.floating)?;
 
     let offset_parent_ref: ElementOrWindow<Element, Window> = (&offset_parent).into();
 
-    ElementRects {
+    Ok(ElementRects {
         reference: get_rect_relative_to_offset_parent(
+            platform.cache(),
             args.reference,
             offset_parent_ref.into(),
             args.strategy,
-        ),
+        )?,
         floating: Rect {
             x: 0.0,
             y: 0.0,
             width: dimensions.width,
             height: dimensions.height,
         },
-    }
+    })
 }
@@ -1,66 +1,73 @@
+use floating_ui_core::PlatformError;
 use floating_ui_utils::OwnedElementOrWindow;
 use floating_ui_utils::dom::{
-    DomNodeOrWindow, get_computed_style, get_containing_block, get_document_element,
-    get_parent_node, get_window, is_containing_block, is_element, is_html_element,
-    is_last_traversable_node, is_table_element, is_top_layer,
+    DomNodeOrWindow, is_element, is_html_element, is_last_traversable_node, is_table_element,
+    is_top_layer, try_get_containing_block, try_get_document_element, try_get_parent_node,
+    try_get_window, try_is_containing_block,
 };
 use web_sys::Window;
 use web_sys::{Element, HtmlElement, wasm_bindgen::JsCast};
 
-use crate::utils::is_static_positioned::is_static_positioned;
+use crate::{cache::PlatformCache, utils::is_static_positioned::is_static_positioned};
 
 pub type Polyfill = Box<dyn Fn(&HtmlElement) -> Option<Element>>;
 
-pub fn get_true_offset_parent(element: &Element, polyfill: &Option<Polyfill>) -> Option<Element> {
+pub fn get_true_offset_parent(
+    cache: &PlatformCache,
+    element: &Element,
+    polyfill: &Option<Polyfill>,
+) -> Result<Option<Element>, PlatformError> {
     if !is_html_element(element)
-        || get_computed_style(element)
+        || cache
+            .get_computed_style(element)?
             .get_property_value("position")
             .expect("Computed style should have position.")
             == "fixed"
     {
-        None
+        Ok(None)
     } else {
         let element = element.unchecked_ref::<HtmlElement>();
 
         if let Some(polyfill) = polyfill {
-            polyfill(element)
+            Ok(polyfill(element))
         } else {
             let raw_offset_parent = element.offset_parent();
 
             // Firefox returns the <html> element as the offsetParent if it's non-static, while Chrome and Safari return the <body> element.
             // The <body> element must be used to perform the correct calculations even if the <html> element is non-static.
             if let Some(raw_offset_parent) = raw_offset_parent.as_ref()
-                && get_document_element(Some(DomNodeOrWindow::Node(raw_offset_parent)))
-                    == *raw_offset_parent
+                && try_get_document_element(Some(DomNodeOrWindow::Node(raw_offset_parent))).as_ref()
+                    == Some(raw_offset_parent)
             {
-                return Some(
+                return Ok(Some(
                     raw_offset_parent
                         .owner_document()
-                        .expect("Element should have owner document.")
-                        .body()
-                        .expect("Document should have body.")
+                        .and_then(|document| document.body())
+                        .ok_or(PlatformError::DetachedElement)?
                         .unchecked_into::<Element>(),
-                );
+                ));
             }
 
-            raw_offset_parent
+            Ok(raw_offset_parent)
         }
     }
 }
 
 /// Gets the closest ancestor positioned element. Handles some edge cases, such as table ancestors and cross browser bugs.
 pub fn get_offset_parent(
+    cache: &PlatformCache,
     element: &Element,
     polyfill: Option<Polyfill>,
-) -> OwnedElementOrWindow<Element, Window> {
-    let window = get_window(Some(element));
+) -> Result<OwnedElementOrWindow<Element, Window>, PlatformError> {
+    let window = try_get_window(Some(element)).ok_or(PlatformError::MissingWindow)?;
 
     if is_top_layer(element) {
-        return OwnedElementOrWindow::Window(window);
+        return Ok(OwnedElementOrWindow::Window(window));
     }
 
     if !is_html_element(element) {
-        let mut svg_offset_parent = Some(get_parent_node(element));
+        let mut svg_offset_parent =
+            Some(try_get_parent_node(element).ok_or(PlatformError::DetachedElement)?);
         while let Some(parent) = svg_offset_parent.as_ref() {
             if is_last_traversable_node(parent) {
                 break;
@@ -68,20 +75,21 @@ pub fn get_offset_parent(
 
             if is_element(parent) {
                 let element = parent.unchecked_ref::<Element>();
-                if !is_static_positioned(element) {
-                    return OwnedElementOrWindow::Element(element.clone());
+                if !is_static_positioned(cache, element)? {
+                    return Ok(OwnedElementOrWindow::Element(element.clone()));
                 }
             }
-            svg_offset_parent = Some(get_parent_node(parent))
+            svg_offset_parent =
+                Some(try_get_parent_node(parent).ok_or(PlatformError::DetachedElement)?)
         }
-        return OwnedElementOrWindow::Window(window);
+        return Ok(OwnedElementOrWindow::Window(window));
     }
 
-    let mut offset_parent = get_true_offset_parent(element, &polyfill);
+    let mut offset_parent = get_true_offset_parent(cache, element, &polyfill)?;
 
     while let Some(parent) = offset_parent.as_ref() {
-        if is_table_element(parent) && is_static_positioned(parent) {
-            offset_parent = get_true_offset_parent(parent, &polyfill);
+        if is_table_element(parent) && is_static_positioned(cache, parent)? {
+            offset_parent = get_true_offset_parent(cache, parent, &polyfill)?;
         } else {
             break;
         }
@@ -89,15 +97,17 @@ pub fn get_offset_parent(
 
     if let Some(parent) = offset_parent.as_ref()
         && is_last_traversable_node(parent)
-        && is_static_positioned(parent)
-        && !is_containing_block(parent.into())
+        && is_static_positioned(cache, parent)?
+        && !try_is_containing_block(parent.into()).ok_or(PlatformError::MissingWindow)?
     {
-        return OwnedElementOrWindow::Window(window);
+        return Ok(OwnedElementOrWindow::Window(window));
     }
 
-    offset_parent
-        .map(OwnedElementOrWindow::Element)
-        .or(get_containing_block(element)
-            .map(|element| OwnedElementOrWindow::Element(element.into())))
-        .unwrap_or(OwnedElementOrWindow::Window(window))
+    Ok(match offset_parent {
+        Some(offset_parent) => OwnedElementOrWindow::Element(offset_parent),
+        None => try_get_containing_block(element)
+            .ok_or(PlatformError::MissingWindow)?
+            .map(|element| OwnedElementOrWindow::Element(element.into()))
+            .unwrap_or(OwnedElementOrWindow::Window(window)),
+    })
 }
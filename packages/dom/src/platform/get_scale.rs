@@ -1,11 +1,16 @@
+use floating_ui_core::PlatformError;
 use floating_ui_utils::Coords;
 
 use crate::{
+    cache::PlatformCache,
     types::ElementOrVirtual,
     utils::get_css_dimensions::{CssDimensions, get_css_dimensions},
 };
 
-pub fn get_scale(element_or_virtual: ElementOrVirtual) -> Coords {
+pub fn get_scale(
+    cache: &PlatformCache,
+    element_or_virtual: ElementOrVirtual,
+) -> Result<Coords, PlatformError> {
     let dom_element = element_or_virtual.resolve();
 
     if let Some(dom_element) = dom_element {
@@ -13,7 +18,7 @@ pub fn get_scale(element_or_virtual: ElementOrVirtual) -> Coords {
         let CssDimensions {
             dimensions,
             should_fallback,
-        } = get_css_dimensions(&dom_element);
+        } = get_css_dimensions(cache, &dom_element)?;
         let mut x = if should_fallback {
             rect.width().round()
         } else {
@@ -33,8 +38,8 @@ pub fn get_scale(element_or_virtual: ElementOrVirtual) -> Coords {
             y = 1.0;
         }
 
-        Coords { x, y }
+        Ok(Coords { x, y })
     } else {
-        Coords::new(1.0)
+        Ok(Coords::new(1.0))
     }
 }
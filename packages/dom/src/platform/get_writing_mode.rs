@@ -0,0 +1,24 @@
+use floating_ui_core::PlatformError;
+use floating_ui_utils::WritingMode;
+use web_sys::Element;
+
+use crate::cache::PlatformCache;
+
+pub fn get_writing_mode(
+    cache: &PlatformCache,
+    element: &Element,
+) -> Result<WritingMode, PlatformError> {
+    Ok(
+        match cache
+            .get_computed_style(element)?
+            .get_property_value("writing-mode")
+            .unwrap_or_default()
+            .as_str()
+        {
+            "vertical-rl" | "sideways-rl" => WritingMode::VerticalRl,
+            "vertical-lr" => WritingMode::VerticalLr,
+            "sideways-lr" => WritingMode::SidewaysLr,
+            _ => WritingMode::HorizontalTb,
+        },
+    )
+}
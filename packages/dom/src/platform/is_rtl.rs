@@ -1,9 +1,12 @@
-use floating_ui_utils::dom::get_computed_style;
+use floating_ui_core::PlatformError;
 use web_sys::Element;
 
-pub fn is_rtl(element: &Element) -> bool {
-    get_computed_style(element)
+use crate::cache::PlatformCache;
+
+pub fn is_rtl(cache: &PlatformCache, element: &Element) -> Result<bool, PlatformError> {
+    Ok(cache
+        .get_computed_style(element)?
         .get_property_value("direction")
         .unwrap_or_default()
-        == "rtl"
+        == "rtl")
 }
@@ -0,0 +1,189 @@
+use std::{cell::RefCell, rc::Rc};
+
+use floating_ui_utils::Strategy;
+use web_sys::{
+    Element, HtmlElement, ToggleEvent,
+    wasm_bindgen::{JsCast, closure::Closure},
+};
+
+use crate::{
+    ComputePositionConfig,
+    auto_update::{AutoUpdateOptions, auto_update},
+    compute_position,
+    types::{ElementOrVirtual, OwnedElementOrVirtual},
+};
+
+/// Inline styles which clear the user agent styles of popovers, which center the popover in the viewport.
+pub const POPOVER_STYLES: [(&str, &str); 2] = [("margin", "0"), ("inset", "auto")];
+
+/// Marks a `popover` attribute which was set by [`show_popover`], so [`remove_popover`] only removes that one.
+const ADDED_POPOVER_ATTRIBUTE: &str = "data-floating-ui-popover";
+
+fn set_style(element: &Element, property: &str, value: &str) {
+    element
+        .unchecked_ref::<HtmlElement>()
+        .style()
+        .set_property(property, value)
+        .expect("Style should be updated.");
+}
+
+/// Whether the popover is currently shown in the top layer.
+pub fn is_popover_open(floating: &Element) -> bool {
+    floating.matches(":popover-open").unwrap_or(false)
+}
+
+/// Shows the floating element in the top layer using the [`popover`](https://developer.mozilla.org/en-US/docs/Web/HTML/Global_attributes/popover) attribute.
+///
+/// Sets `popover="manual"` if the element has no `popover` attribute yet, so it is not light dismissed.
+pub fn show_popover(floating: &Element) {
+    if !floating.has_attribute("popover") {
+        floating
+            .set_attribute("popover", "manual")
+            .expect("Attribute should be set.");
+        floating
+            .set_attribute(ADDED_POPOVER_ATTRIBUTE, "")
+            .expect("Attribute should be set.");
+    }
+
+    if !is_popover_open(floating) {
+        floating
+            .unchecked_ref::<HtmlElement>()
+            .show_popover()
+            .expect("Popover should be shown.");
+    }
+}
+
+/// Removes the floating element from the top layer.
+pub fn hide_popover(floating: &Element) {
+    if is_popover_open(floating) {
+        floating
+            .unchecked_ref::<HtmlElement>()
+            .hide_popover()
+            .expect("Popover should be hidden.");
+    }
+}
+
+/// Hides the popover and removes the `popover` attribute if it was set by [`show_popover`].
+pub fn remove_popover(floating: &Element) {
+    hide_popover(floating);
+
+    if floating.has_attribute(ADDED_POPOVER_ATTRIBUTE) {
+        for name in ["popover", ADDED_POPOVER_ATTRIBUTE] {
+            floating
+                .remove_attribute(name)
+                .expect("Attribute should be removed.");
+        }
+    }
+}
+
+/// Calls `while_open` whenever the popover is shown and its cleanup function when the popover is hidden, based on the `toggle` event.
+///
+/// Returns a cleanup function that removes the event listener and calls the pending cleanup function.
+pub fn while_popover_open(
+    floating: &Element,
+    while_open: Rc<dyn Fn() -> Box<dyn Fn()>>,
+) -> Box<dyn Fn()> {
+    type StopFn = Box<dyn Fn()>;
+    let stop: Rc<RefCell<Option<StopFn>>> = Rc::new(RefCell::new(None));
+
+    if is_popover_open(floating) {
+        stop.replace(Some(while_open()));
+    }
+
+    let handle_toggle: Closure<dyn Fn(ToggleEvent)> = Closure::new({
+        let stop = stop.clone();
+
+        move |event: ToggleEvent| {
+            if let Some(stop) = stop.take() {
+                stop();
+            }
+
+            if event.new_state() == "open" {
+                stop.replace(Some(while_open()));
+            }
+        }
+    });
+
+    floating
+        .add_event_listener_with_callback("toggle", handle_toggle.as_ref().unchecked_ref())
+        .expect("Toggle event listener should be added.");
+
+    let floating = floating.clone();
+    Box::new(move || {
+        floating
+            .remove_event_listener_with_callback("toggle", handle_toggle.as_ref().unchecked_ref())
+            .expect("Toggle event listener should be removed.");
+
+        if let Some(stop) = stop.take() {
+            stop();
+        }
+    })
+}
+
+/// Runs [`auto_update`] only while the floating element is shown as popover.
+///
+/// Has the same signature as [`auto_update`], so it can be used for `while_elements_mounted`.
+pub fn auto_update_popover(
+    reference: ElementOrVirtual,
+    floating: &Element,
+    update: Rc<dyn Fn()>,
+    options: AutoUpdateOptions,
+) -> Box<dyn Fn()> {
+    let reference: OwnedElementOrVirtual = reference.into();
+    let owned_floating = floating.clone();
+
+    while_popover_open(
+        floating,
+        Rc::new(move || {
+            auto_update(
+                (&reference).into(),
+                Some(&owned_floating),
+                update.clone(),
+                options.clone(),
+            )
+        }),
+    )
+}
+
+/// Shows the floating element in the top layer and keeps it positioned next to the reference element.
+///
+/// The strategy is forced to [`Strategy::Fixed`], since the containing block of top layer elements is the viewport,
+/// and [`POPOVER_STYLES`] are applied. Returns a cleanup function that removes the popover with [`remove_popover`] and stops updating.
+pub fn position_popover(
+    reference: ElementOrVirtual,
+    floating: &Element,
+    config: ComputePositionConfig,
+    options: AutoUpdateOptions,
+) -> Box<dyn Fn()> {
+    let config = config.strategy(Strategy::Fixed);
+    let reference: Rc<OwnedElementOrVirtual> = Rc::new(reference.into());
+
+    for (property, value) in POPOVER_STYLES {
+        set_style(floating, property, value);
+    }
+    set_style(floating, "position", Strategy::Fixed.as_str());
+
+    let update = Rc::new({
+        let reference = reference.clone();
+        let floating = floating.clone();
+
+        move || {
+            let position = compute_position((&*reference).into(), &floating, config.clone());
+
+            set_style(&floating, "left", &format!("{}px", position.x));
+            set_style(&floating, "top", &format!("{}px", position.y));
+        }
+    });
+
+    let stop = auto_update_popover((&*reference).into(), floating, update.clone(), options);
+    show_popover(floating);
+    // The `toggle` event which starts `auto_update` is dispatched asynchronously,
+    // so position the popover before it is painted.
+    update();
+
+    let floating = floating.clone();
+    Box::new(move || {
+        stop();
+        remove_popover(&floating);
+    })
+}
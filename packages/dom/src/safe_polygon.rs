@@ -0,0 +1,298 @@
+use floating_ui_utils::{Coords, Rect, Side};
+
+/// Options for a safe polygon, which keeps a floating element open while the cursor moves from the reference element towards it.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SafePolygonOptions {
+    /// The extra space around the triangle, in pixels.
+    ///
+    /// Defaults to `0.5`.
+    pub buffer: Option<f64>,
+
+    /// Whether the cursor must move towards the floating element with intent.
+    /// The floating element closes when the cursor moves slowly or stops inside the triangle before reaching it.
+    ///
+    /// Defaults to `true`.
+    pub require_intent: Option<bool>,
+}
+
+impl SafePolygonOptions {
+    /// Set `buffer` option.
+    pub fn buffer(mut self, value: f64) -> Self {
+        self.buffer = Some(value);
+        self
+    }
+
+    /// Set `require_intent` option.
+    pub fn require_intent(mut self, value: bool) -> Self {
+        self.require_intent = Some(value);
+        self
+    }
+}
+
+/// Whether a point is inside a polygon, using the even-odd rule.
+pub fn is_point_in_polygon(point: Coords, polygon: &[Coords]) -> bool {
+    let mut is_inside = false;
+
+    for (i, current) in polygon.iter().enumerate() {
+        let previous = &polygon[(i + polygon.len() - 1) % polygon.len()];
+
+        let intersect = (current.y >= point.y) != (previous.y >= point.y)
+            && point.x
+                <= (previous.x - current.x) * (point.y - current.y) / (previous.y - current.y)
+                    + current.x;
+        if intersect {
+            is_inside = !is_inside;
+        }
+    }
+
+    is_inside
+}
+
+/// Whether the cursor left the reference element on the side facing away from the floating element.
+///
+/// A constant of `1` handles floating point rounding errors.
+pub fn is_leaving_opposite_side(side: Side, leave_point: Coords, reference: &Rect) -> bool {
+    match side {
+        Side::Top => leave_point.y >= reference.y + reference.height - 1.0,
+        Side::Bottom => leave_point.y <= reference.y + 1.0,
+        Side::Left => leave_point.x >= reference.x + reference.width - 1.0,
+        Side::Right => leave_point.x <= reference.x + 1.0,
+    }
+}
+
+/// The rectangular trough between the reference and floating element, spanning the narrower of the two.
+///
+/// The cursor can start beyond the edge of the reference element, so moving through the trough can leave the triangle of [`get_safe_polygon`].
+pub fn get_safe_polygon_trough(side: Side, reference: &Rect, floating: &Rect) -> [Coords; 4] {
+    let narrow_x = if floating.width > reference.width {
+        reference
+    } else {
+        floating
+    };
+    let narrow_y = if floating.height > reference.height {
+        reference
+    } else {
+        floating
+    };
+    let left = narrow_x.x;
+    let right = narrow_x.x + narrow_x.width;
+    let top = narrow_y.y;
+    let bottom = narrow_y.y + narrow_y.height;
+
+    let point = |x: f64, y: f64| Coords { x, y };
+
+    match side {
+        Side::Top => [
+            point(left, reference.y + 1.0),
+            point(left, floating.y + floating.height - 1.0),
+            point(right, floating.y + floating.height - 1.0),
+            point(right, reference.y + 1.0),
+        ],
+        Side::Bottom => [
+            point(left, floating.y + 1.0),
+            point(left, reference.y + reference.height - 1.0),
+            point(right, reference.y + reference.height - 1.0),
+            point(right, floating.y + 1.0),
+        ],
+        Side::Left => [
+            point(floating.x + floating.width - 1.0, bottom),
+            point(floating.x + floating.width - 1.0, top),
+            point(reference.x + 1.0, top),
+            point(reference.x + 1.0, bottom),
+        ],
+        Side::Right => [
+            point(reference.x + reference.width - 1.0, bottom),
+            point(reference.x + reference.width - 1.0, top),
+            point(floating.x + 1.0, top),
+            point(floating.x + 1.0, bottom),
+        ],
+    }
+}
+
+/// The triangle from the point where the cursor left the reference element to the near edge of the floating element.
+///
+/// `side` is the side of the final placement of the floating element.
+pub fn get_safe_polygon(
+    side: Side,
+    leave_point: Coords,
+    floating: &Rect,
+    reference: &Rect,
+    buffer: f64,
+) -> [Coords; 4] {
+    let Coords { x, y } = leave_point;
+    let left = floating.x;
+    let right = floating.x + floating.width;
+    let top = floating.y;
+    let bottom = floating.y + floating.height;
+
+    let is_floating_wider = floating.width > reference.width;
+    let is_floating_taller = floating.height > reference.height;
+    let cursor_leave_from_right = x > right - floating.width / 2.0;
+    let cursor_leave_from_bottom = y > bottom - floating.height / 2.0;
+
+    let point = |x: f64, y: f64| Coords { x, y };
+
+    // The cursor points are spread along the edge the cursor left from.
+    let cursor_x = |offset: f64| {
+        if is_floating_wider {
+            x + offset * buffer / 2.0
+        } else if cursor_leave_from_right {
+            x + buffer * 4.0
+        } else {
+            x - buffer * 4.0
+        }
+    };
+    let cursor_y = |offset: f64| {
+        if is_floating_taller {
+            y + offset * buffer / 2.0
+        } else if cursor_leave_from_bottom {
+            y + buffer * 4.0
+        } else {
+            y - buffer * 4.0
+        }
+    };
+
+    match side {
+        Side::Top => [
+            point(cursor_x(1.0), y + buffer + 1.0),
+            point(cursor_x(-1.0), y + buffer + 1.0),
+            point(
+                left,
+                if cursor_leave_from_right || is_floating_wider {
+                    bottom - buffer
+                } else {
+                    top
+                },
+            ),
+            point(
+                right,
+                match (cursor_leave_from_right, is_floating_wider) {
+                    (true, true) | (false, _) => bottom - buffer,
+                    (true, false) => top,
+                },
+            ),
+        ],
+        Side::Bottom => [
+            point(cursor_x(1.0), y - buffer),
+            point(cursor_x(-1.0), y - buffer),
+            point(
+                left,
+                if cursor_leave_from_right || is_floating_wider {
+                    top + buffer
+                } else {
+                    bottom
+                },
+            ),
+            point(
+                right,
+                match (cursor_leave_from_right, is_floating_wider) {
+                    (true, true) | (false, _) => top + buffer,
+                    (true, false) => bottom,
+                },
+            ),
+        ],
+        Side::Left => [
+            point(
+                if cursor_leave_from_bottom || is_floating_taller {
+                    right - buffer
+                } else {
+                    left
+                },
+                top,
+            ),
+            point(
+                match (cursor_leave_from_bottom, is_floating_taller) {
+                    (true, true) | (false, _) => right - buffer,
+                    (true, false) => left,
+                },
+                bottom,
+            ),
+            point(x + buffer + 1.0, cursor_y(1.0)),
+            point(x + buffer + 1.0, cursor_y(-1.0)),
+        ],
+        Side::Right => [
+            point(x - buffer, cursor_y(1.0)),
+            point(x - buffer, cursor_y(-1.0)),
+            point(
+                if cursor_leave_from_bottom || is_floating_taller {
+                    left + buffer
+                } else {
+                    right
+                },
+                top,
+            ),
+            point(
+                match (cursor_leave_from_bottom, is_floating_taller) {
+                    (true, true) | (false, _) => left + buffer,
+                    (true, false) => right,
+                },
+                bottom,
+            ),
+        ],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const REFERENCE: Rect = Rect {
+        x: 100.0,
+        y: 100.0,
+        width: 50.0,
+        height: 20.0,
+    };
+
+    const FLOATING: Rect = Rect {
+        x: 25.0,
+        y: 130.0,
+        width: 200.0,
+        height: 100.0,
+    };
+
+    #[test]
+    fn test_point_in_polygon() {
+        let square = [
+            Coords { x: 0.0, y: 0.0 },
+            Coords { x: 10.0, y: 0.0 },
+            Coords { x: 10.0, y: 10.0 },
+            Coords { x: 0.0, y: 10.0 },
+        ];
+
+        assert!(is_point_in_polygon(Coords { x: 5.0, y: 5.0 }, &square));
+        assert!(!is_point_in_polygon(Coords { x: 15.0, y: 5.0 }, &square));
+    }
+
+    #[test]
+    fn test_safe_polygon() {
+        let leave_point = Coords { x: 120.0, y: 120.0 };
+        let polygon = get_safe_polygon(Side::Bottom, leave_point, &FLOATING, &REFERENCE, 0.5);
+
+        // Towards the far corner of the floating element.
+        assert!(is_point_in_polygon(Coords { x: 60.0, y: 128.0 }, &polygon));
+        // Away from the floating element.
+        assert!(!is_point_in_polygon(Coords { x: 20.0, y: 122.0 }, &polygon));
+    }
+
+    #[test]
+    fn test_trough() {
+        let trough = get_safe_polygon_trough(Side::Bottom, &REFERENCE, &FLOATING);
+
+        assert!(is_point_in_polygon(Coords { x: 110.0, y: 125.0 }, &trough));
+        assert!(!is_point_in_polygon(Coords { x: 90.0, y: 125.0 }, &trough));
+    }
+
+    #[test]
+    fn test_leaving_opposite_side() {
+        assert!(is_leaving_opposite_side(
+            Side::Bottom,
+            Coords { x: 120.0, y: 100.0 },
+            &REFERENCE
+        ));
+        assert!(!is_leaving_opposite_side(
+            Side::Bottom,
+            Coords { x: 120.0, y: 120.0 },
+            &REFERENCE
+        ));
+    }
+}
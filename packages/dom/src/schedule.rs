@@ -0,0 +1,90 @@
+//! Browser timers which call a callback once.
+
+use std::{cell::RefCell, rc::Rc};
+
+use web_sys::{
+    js_sys::Function,
+    wasm_bindgen::{JsCast, closure::Closure},
+    window,
+};
+
+type ScheduledClosure = Rc<RefCell<Option<Closure<dyn FnMut()>>>>;
+
+/// Calls `callback` after `timeout` milliseconds.
+///
+/// Returns a function which cancels the timeout. The closure is dropped after the callback was called or the timeout was cancelled.
+pub fn set_timeout<F: FnOnce() + 'static>(callback: F, timeout: u32) -> Box<dyn Fn()> {
+    let window = window().expect("Window should exist.");
+
+    schedule(
+        callback,
+        {
+            let window = window.clone();
+            move |function| {
+                window
+                    .set_timeout_with_callback_and_timeout_and_arguments_0(function, timeout as i32)
+                    .expect("Set timeout should be successful.")
+            }
+        },
+        move |handle| {
+            window.clear_timeout_with_handle(handle);
+        },
+    )
+}
+
+/// Calls `callback` before the next repaint.
+///
+/// Returns a function which cancels the request. The closure is dropped after the callback was called or the request was cancelled.
+pub fn request_animation_frame<F: FnOnce() + 'static>(callback: F) -> Box<dyn Fn()> {
+    let window = window().expect("Window should exist.");
+
+    schedule(
+        callback,
+        {
+            let window = window.clone();
+            move |function| {
+                window
+                    .request_animation_frame(function)
+                    .expect("Request animation frame should be successful.")
+            }
+        },
+        move |handle| {
+            window
+                .cancel_animation_frame(handle)
+                .expect("Cancel animation frame should be successful.");
+        },
+    )
+}
+
+fn schedule<F: FnOnce() + 'static>(
+    callback: F,
+    start: impl FnOnce(&Function) -> i32,
+    cancel: impl Fn(i32) + 'static,
+) -> Box<dyn Fn()> {
+    // The closure keeps itself alive until it is called, so the cancel function may be dropped before.
+    let closure: ScheduledClosure = Rc::new(RefCell::new(None));
+    closure.replace(Some(Closure::once({
+        let closure = closure.clone();
+
+        move || {
+            // Dropping a closure while it is called is deferred until the call returns.
+            closure.take();
+            callback();
+        }
+    })));
+
+    let handle = start(
+        closure
+            .borrow()
+            .as_ref()
+            .expect("Closure should exist.")
+            .as_ref()
+            .unchecked_ref(),
+    );
+
+    Box::new(move || {
+        if closure.take().is_some() {
+            cancel(handle);
+        }
+    })
+}
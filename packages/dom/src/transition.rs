@@ -0,0 +1,479 @@
+use std::{
+    fmt::{self, Debug, Display},
+    sync::Arc,
+};
+
+use floating_ui_utils::{Placement, Side};
+
+/// Status of the transition of a floating element.
+#[derive(Copy, Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub enum TransitionStatus {
+    /// The floating element is not mounted.
+    #[default]
+    Unmounted,
+    /// The floating element was mounted and has its initial styles, the open transition starts in the next frame.
+    Initial,
+    /// The floating element is open.
+    Open,
+    /// The floating element is closing and stays mounted until the close duration has passed.
+    Close,
+}
+
+impl TransitionStatus {
+    /// The string form of the variant.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TransitionStatus::Unmounted => "unmounted",
+            TransitionStatus::Initial => "initial",
+            TransitionStatus::Open => "open",
+            TransitionStatus::Close => "close",
+        }
+    }
+}
+
+impl Display for TransitionStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Open and close durations for [`TransitionDuration`].
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub struct TransitionDurationValues {
+    /// The duration of the open transition in milliseconds.
+    ///
+    /// Defaults to `0`.
+    pub open: Option<u32>,
+
+    /// The duration of the close transition in milliseconds.
+    ///
+    /// Defaults to `0`.
+    pub close: Option<u32>,
+}
+
+impl TransitionDurationValues {
+    /// Set `open` option.
+    pub fn open(mut self, value: u32) -> Self {
+        self.open = Some(value);
+        self
+    }
+
+    /// Set `close` option.
+    pub fn close(mut self, value: u32) -> Self {
+        self.close = Some(value);
+        self
+    }
+}
+
+/// Duration of a transition in milliseconds.
+///
+/// A number (the same duration for opening and closing) or separate durations ([`TransitionDurationValues`]).
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum TransitionDuration {
+    Value(u32),
+    Values(TransitionDurationValues),
+}
+
+impl TransitionDuration {
+    /// The duration of the open transition in milliseconds.
+    pub fn open(&self) -> u32 {
+        match self {
+            TransitionDuration::Value(value) => *value,
+            TransitionDuration::Values(values) => values.open.unwrap_or(0),
+        }
+    }
+
+    /// The duration of the close transition in milliseconds.
+    pub fn close(&self) -> u32 {
+        match self {
+            TransitionDuration::Value(value) => *value,
+            TransitionDuration::Values(values) => values.close.unwrap_or(0),
+        }
+    }
+}
+
+impl Default for TransitionDuration {
+    fn default() -> Self {
+        TransitionDuration::Value(250)
+    }
+}
+
+impl From<u32> for TransitionDuration {
+    fn from(value: u32) -> Self {
+        TransitionDuration::Value(value)
+    }
+}
+
+/// CSS declarations applied to a floating element during a transition, as kebab-case property names and values.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct TransitionStyles(pub Vec<(String, String)>);
+
+impl TransitionStyles {
+    /// Get the value of a property.
+    pub fn get(&self, property: &str) -> Option<&str> {
+        self.0
+            .iter()
+            .find(|(name, _)| name == property)
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// Set the value of a property, replacing an existing value.
+    pub fn set<P: Into<String>, V: Into<String>>(mut self, property: P, value: V) -> Self {
+        self.insert(property.into(), value.into());
+        self
+    }
+
+    /// The properties, joined as a `transition-property` value.
+    pub fn transition_property(&self) -> String {
+        self.0
+            .iter()
+            .map(|(name, _)| name.as_str())
+            .collect::<Vec<_>>()
+            .join(",")
+    }
+
+    fn insert(&mut self, property: String, value: String) {
+        match self.0.iter_mut().find(|(name, _)| *name == property) {
+            Some((_, existing_value)) => *existing_value = value,
+            None => self.0.push((property, value)),
+        }
+    }
+
+    fn extend(&mut self, styles: TransitionStyles) {
+        for (property, value) in styles.0 {
+            self.insert(property, value);
+        }
+    }
+}
+
+impl<P: Into<String>, V: Into<String>> FromIterator<(P, V)> for TransitionStyles {
+    fn from_iter<T: IntoIterator<Item = (P, V)>>(iter: T) -> Self {
+        iter.into_iter()
+            .fold(TransitionStyles::default(), |styles, (property, value)| {
+                styles.set(property, value)
+            })
+    }
+}
+
+impl Display for TransitionStyles {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Empty values reset the property, so they are left out.
+        let declarations = self
+            .0
+            .iter()
+            .filter(|(_, value)| !value.is_empty())
+            .map(|(property, value)| format!("{property}: {value};"))
+            .collect::<Vec<_>>();
+
+        f.write_str(&declarations.join(" "))
+    }
+}
+
+/// Function computing [`TransitionStyles`] from the side and placement of the floating element.
+pub type TransitionStylesFn = dyn Fn(Side, Placement) -> TransitionStyles + Send + Sync;
+
+/// [`TransitionStyles`] or a function computing them from the side and placement of the floating element.
+#[derive(Clone)]
+pub enum DerivableTransitionStyles {
+    Value(TransitionStyles),
+    Fn(Arc<TransitionStylesFn>),
+}
+
+impl DerivableTransitionStyles {
+    /// Constructs derivable styles from a function.
+    pub fn from_fn<F: Fn(Side, Placement) -> TransitionStyles + Send + Sync + 'static>(
+        value: F,
+    ) -> Self {
+        DerivableTransitionStyles::Fn(Arc::new(value))
+    }
+
+    /// Computes the styles for a placement.
+    pub fn evaluate(&self, placement: Placement) -> TransitionStyles {
+        match self {
+            DerivableTransitionStyles::Value(value) => value.clone(),
+            DerivableTransitionStyles::Fn(value) => value(placement.side(), placement),
+        }
+    }
+}
+
+impl Debug for DerivableTransitionStyles {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DerivableTransitionStyles::Value(value) => f.debug_tuple("Value").field(value).finish(),
+            DerivableTransitionStyles::Fn(_) => f.debug_tuple("Fn").finish(),
+        }
+    }
+}
+
+impl PartialEq for DerivableTransitionStyles {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (DerivableTransitionStyles::Value(a), DerivableTransitionStyles::Value(b)) => a == b,
+            (DerivableTransitionStyles::Fn(a), DerivableTransitionStyles::Fn(b)) => {
+                Arc::ptr_eq(a, b)
+            }
+            _ => false,
+        }
+    }
+}
+
+impl From<TransitionStyles> for DerivableTransitionStyles {
+    fn from(value: TransitionStyles) -> Self {
+        DerivableTransitionStyles::Value(value)
+    }
+}
+
+/// Options for [`get_transition_styles`].
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TransitionStylesOptions {
+    /// The duration of the transitions.
+    ///
+    /// Defaults to `250` milliseconds for opening and closing.
+    pub duration: Option<TransitionDuration>,
+
+    /// The styles of the floating element when it is mounted, before the open transition.
+    ///
+    /// Defaults to `opacity: 0`.
+    pub initial: Option<DerivableTransitionStyles>,
+
+    /// The styles of the floating element when it is open.
+    ///
+    /// Defaults to resetting the initial styles.
+    pub open: Option<DerivableTransitionStyles>,
+
+    /// The styles of the floating element when it is closing.
+    ///
+    /// Defaults to the initial styles.
+    pub close: Option<DerivableTransitionStyles>,
+
+    /// The styles of the floating element during all statuses.
+    ///
+    /// Defaults to no styles.
+    pub common: Option<DerivableTransitionStyles>,
+}
+
+impl TransitionStylesOptions {
+    /// Set `duration` option.
+    pub fn duration<D: Into<TransitionDuration>>(mut self, value: D) -> Self {
+        self.duration = Some(value.into());
+        self
+    }
+
+    /// Set `initial` option.
+    pub fn initial<S: Into<DerivableTransitionStyles>>(mut self, value: S) -> Self {
+        self.initial = Some(value.into());
+        self
+    }
+
+    /// Set `open` option.
+    pub fn open<S: Into<DerivableTransitionStyles>>(mut self, value: S) -> Self {
+        self.open = Some(value.into());
+        self
+    }
+
+    /// Set `close` option.
+    pub fn close<S: Into<DerivableTransitionStyles>>(mut self, value: S) -> Self {
+        self.close = Some(value.into());
+        self
+    }
+
+    /// Set `common` option.
+    pub fn common<S: Into<DerivableTransitionStyles>>(mut self, value: S) -> Self {
+        self.common = Some(value.into());
+        self
+    }
+}
+
+/// Computes the styles of a floating element for a transition status.
+///
+/// The `transition-property` of the previous styles is kept for [`TransitionStatus::Initial`],
+/// so a floating element which is reopened while closing transitions back smoothly.
+/// The previous styles are returned unchanged for [`TransitionStatus::Unmounted`].
+pub fn get_transition_styles(
+    status: TransitionStatus,
+    placement: Placement,
+    options: &TransitionStylesOptions,
+    previous: &TransitionStyles,
+) -> TransitionStyles {
+    let duration = options.duration.unwrap_or_default();
+    let initial_styles = get_initial_styles(placement, options);
+    let common_styles = options
+        .common
+        .as_ref()
+        .map(|common| common.evaluate(placement))
+        .unwrap_or_default();
+
+    let mut styles = TransitionStyles::default();
+    let transition_styles = match status {
+        TransitionStatus::Unmounted => return previous.clone(),
+        TransitionStatus::Initial => {
+            if let Some(transition_property) = previous.get("transition-property") {
+                styles.insert(
+                    "transition-property".to_owned(),
+                    transition_property.to_owned(),
+                );
+            }
+
+            initial_styles
+        }
+        TransitionStatus::Open => {
+            let open_styles = options
+                .open
+                .as_ref()
+                .map(|open| open.evaluate(placement))
+                .unwrap_or_else(|| {
+                    initial_styles
+                        .0
+                        .iter()
+                        .map(|(property, _)| (property.clone(), String::new()))
+                        .collect()
+                });
+
+            styles.insert(
+                "transition-property".to_owned(),
+                open_styles.transition_property(),
+            );
+            styles.insert(
+                "transition-duration".to_owned(),
+                format!("{}ms", duration.open()),
+            );
+
+            open_styles
+        }
+        TransitionStatus::Close => {
+            let close_styles = options
+                .close
+                .as_ref()
+                .map(|close| close.evaluate(placement))
+                .unwrap_or(initial_styles);
+
+            styles.insert(
+                "transition-property".to_owned(),
+                close_styles.transition_property(),
+            );
+            styles.insert(
+                "transition-duration".to_owned(),
+                format!("{}ms", duration.close()),
+            );
+
+            close_styles
+        }
+    };
+
+    styles.extend(common_styles);
+    styles.extend(transition_styles);
+    styles
+}
+
+/// Computes the styles of a floating element before any transition, the common and initial styles.
+pub fn get_initial_transition_styles(
+    placement: Placement,
+    options: &TransitionStylesOptions,
+) -> TransitionStyles {
+    let mut styles = options
+        .common
+        .as_ref()
+        .map(|common| common.evaluate(placement))
+        .unwrap_or_default();
+    styles.extend(get_initial_styles(placement, options));
+    styles
+}
+
+fn get_initial_styles(placement: Placement, options: &TransitionStylesOptions) -> TransitionStyles {
+    options
+        .initial
+        .as_ref()
+        .map(|initial| initial.evaluate(placement))
+        .unwrap_or_else(|| TransitionStyles::default().set("opacity", "0"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn options() -> TransitionStylesOptions {
+        TransitionStylesOptions::default()
+            .duration(TransitionDuration::Values(
+                TransitionDurationValues::default().open(200).close(100),
+            ))
+            .initial(DerivableTransitionStyles::from_fn(|side, _placement| {
+                TransitionStyles::default().set("opacity", "0").set(
+                    "transform",
+                    match side {
+                        Side::Top => "translateY(5px)",
+                        Side::Bottom => "translateY(-5px)",
+                        Side::Left => "translateX(5px)",
+                        Side::Right => "translateX(-5px)",
+                    },
+                )
+            }))
+    }
+
+    #[test]
+    fn test_statuses() {
+        let options = options();
+        let initial = get_initial_transition_styles(Placement::Top, &options);
+        assert_eq!(
+            initial.to_string(),
+            "opacity: 0; transform: translateY(5px);"
+        );
+
+        let open =
+            get_transition_styles(TransitionStatus::Open, Placement::Top, &options, &initial);
+        assert_eq!(
+            open.to_string(),
+            "transition-property: opacity,transform; transition-duration: 200ms;"
+        );
+
+        let close = get_transition_styles(
+            TransitionStatus::Close,
+            Placement::BottomStart,
+            &options,
+            &open,
+        );
+        assert_eq!(
+            close.to_string(),
+            "transition-property: opacity,transform; transition-duration: 100ms; opacity: 0; transform: translateY(-5px);"
+        );
+
+        let initial = get_transition_styles(
+            TransitionStatus::Initial,
+            Placement::BottomStart,
+            &options,
+            &close,
+        );
+        assert_eq!(
+            initial.to_string(),
+            "transition-property: opacity,transform; opacity: 0; transform: translateY(-5px);"
+        );
+
+        assert_eq!(
+            get_transition_styles(
+                TransitionStatus::Unmounted,
+                Placement::BottomStart,
+                &options,
+                &initial
+            ),
+            initial
+        );
+    }
+
+    #[test]
+    fn test_common() {
+        let options = TransitionStylesOptions::default()
+            .duration(150)
+            .common(TransitionStyles::default().set("transform-origin", "bottom"))
+            .open(TransitionStyles::default().set("opacity", "1"));
+
+        assert_eq!(
+            get_transition_styles(
+                TransitionStatus::Open,
+                Placement::Top,
+                &options,
+                &TransitionStyles::default()
+            )
+            .to_string(),
+            "transition-property: opacity; transition-duration: 150ms; transform-origin: bottom; opacity: 1;"
+        );
+    }
+}
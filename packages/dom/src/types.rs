@@ -1,4 +1,6 @@
-use floating_ui_core::{Boundary as CoreBoundary, Middleware};
+use floating_ui_core::{
+    Boundary as CoreBoundary, ComputePositionSpec as CoreComputePositionSpec, Middleware,
+};
 use floating_ui_utils::{
     DefaultVirtualElement as CoreDefaultVirtualElement, ElementOrVirtual as CoreElementOrVirtual,
     OwnedElementOrVirtual as CoreOwnedElementOrVirtual,
@@ -7,6 +9,9 @@ use web_sys::{Element, Window};
 
 pub type Boundary = CoreBoundary<Element>;
 
+/// Declarative options for [`compute_position`][`crate::compute_position`], e.g. stored in a configuration file.
+pub type ComputePositionSpec = CoreComputePositionSpec<Element, Window>;
+
 pub type DefaultVirtualElement = CoreDefaultVirtualElement<Element>;
 pub type ElementOrVirtual<'a> = CoreElementOrVirtual<'a, Element>;
 pub type OwnedElementOrVirtual = CoreOwnedElementOrVirtual<Element>;
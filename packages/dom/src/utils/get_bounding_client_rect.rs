@@ -1,21 +1,24 @@
+use floating_ui_core::PlatformError;
 use floating_ui_utils::{
     ClientRectObject, Coords, Rect,
-    dom::{DomElementOrWindow, get_computed_style, get_frame_element, get_window},
+    dom::{DomElementOrWindow, get_frame_element, try_get_window},
     rect_to_client_rect,
 };
 
 use crate::{
+    cache::PlatformCache,
     platform::get_scale::get_scale,
     types::ElementOrVirtual,
     utils::get_visual_offsets::{get_visual_offsets, should_add_visual_offsets},
 };
 
 pub fn get_bounding_client_rect(
+    cache: &PlatformCache,
     element_or_virtual: ElementOrVirtual,
     include_scale: bool,
     is_fixed_strategy: bool,
     offset_parent: Option<DomElementOrWindow>,
-) -> ClientRectObject {
+) -> Result<ClientRectObject, PlatformError> {
     let client_rect = match &element_or_virtual {
         ElementOrVirtual::Element(element) => element.get_bounding_client_rect().into(),
         ElementOrVirtual::VirtualElement(virtual_element) => {
@@ -27,10 +30,10 @@ pub fn get_bounding_client_rect(
     let scale = if include_scale {
         match &offset_parent {
             Some(offset_parent) => match offset_parent {
-                DomElementOrWindow::Element(element) => get_scale((*element).into()),
+                DomElementOrWindow::Element(element) => get_scale(cache, (*element).into())?,
                 DomElementOrWindow::Window(_) => Coords::new(1.0),
             },
-            None => get_scale(element_or_virtual),
+            None => get_scale(cache, element_or_virtual)?,
         }
     } else {
         Coords::new(1.0)
@@ -40,8 +43,8 @@ pub fn get_bounding_client_rect(
         dom_element.as_ref(),
         is_fixed_strategy,
         offset_parent.clone(),
-    ) {
-        get_visual_offsets(dom_element.as_ref())
+    )? {
+        get_visual_offsets(dom_element.as_ref())?
     } else {
         Coords::new(0.0)
     };
@@ -54,9 +57,11 @@ pub fn get_bounding_client_rect(
     if let Some(dom_element) = dom_element
         && let Some(offset_parent) = offset_parent
     {
-        let window = get_window(Some(&dom_element));
+        let window = try_get_window(Some(&dom_element)).ok_or(PlatformError::MissingWindow)?;
         let offset_window = match offset_parent {
-            DomElementOrWindow::Element(element) => get_window(Some(element)),
+            DomElementOrWindow::Element(element) => {
+                try_get_window(Some(element)).ok_or(PlatformError::MissingWindow)?
+            }
             DomElementOrWindow::Window(window) => window.clone(),
         };
 
@@ -66,9 +71,9 @@ pub fn get_bounding_client_rect(
 
             if let Some(current_iframe) = current_iframe.as_ref() {
                 if offset_window != current_window {
-                    let iframe_scale = get_scale(current_iframe.into());
+                    let iframe_scale = get_scale(cache, current_iframe.into())?;
                     let iframe_rect = current_iframe.get_bounding_client_rect();
-                    let css = get_computed_style(current_iframe);
+                    let css = cache.get_computed_style(current_iframe)?;
                     let padding_left = css
                         .get_property_value("padding-left")
                         .expect("Computed style should have padding left.")
@@ -93,7 +98,8 @@ pub fn get_bounding_client_rect(
                     x += left;
                     y += top;
 
-                    current_window = get_window(Some(current_iframe));
+                    current_window =
+                        try_get_window(Some(current_iframe)).ok_or(PlatformError::MissingWindow)?;
                 } else {
                     break;
                 }
@@ -103,10 +109,10 @@ pub fn get_bounding_client_rect(
         }
     }
 
-    rect_to_client_rect(Rect {
+    Ok(rect_to_client_rect(Rect {
         x,
         y,
         width,
         height,
-    })
+    }))
 }
@@ -1,17 +1,20 @@
-use floating_ui_utils::{
-    Dimensions,
-    dom::{get_computed_style, is_html_element},
-};
+use floating_ui_core::PlatformError;
+use floating_ui_utils::{Dimensions, dom::is_html_element};
 use web_sys::{Element, HtmlElement, wasm_bindgen::JsCast};
 
+use crate::cache::PlatformCache;
+
 #[derive(Clone, Debug)]
 pub struct CssDimensions {
     pub dimensions: Dimensions,
     pub should_fallback: bool,
 }
 
-pub fn get_css_dimensions(element: &Element) -> CssDimensions {
-    let css = get_computed_style(element);
+pub fn get_css_dimensions(
+    cache: &PlatformCache,
+    element: &Element,
+) -> Result<CssDimensions, PlatformError> {
+    let css = cache.get_computed_style(element)?;
 
     let width = css
         .get_property_value("width")
@@ -37,7 +40,7 @@ pub fn get_css_dimensions(element: &Element) -> CssDimensions {
     };
     let should_fallback = width.round() != offset_width || height.round() != offset_height;
 
-    CssDimensions {
+    Ok(CssDimensions {
         dimensions: if should_fallback {
             Dimensions {
                 width: offset_width,
@@ -47,5 +50,5 @@ pub fn get_css_dimensions(element: &Element) -> CssDimensions {
             Dimensions { width, height }
         },
         should_fallback,
-    }
+    })
 }
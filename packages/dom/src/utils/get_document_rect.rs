@@ -1,18 +1,18 @@
-use floating_ui_utils::{Rect, dom::get_node_scroll};
+use floating_ui_core::PlatformError;
+use floating_ui_utils::{Rect, dom::try_get_node_scroll};
 use web_sys::Element;
 
-use crate::platform::is_rtl::is_rtl;
+use crate::{cache::PlatformCache, platform::is_rtl::is_rtl};
 
 use super::get_window_scroll_bar_x::get_window_scroll_bar_x;
 
 /// Gets the entire size of the scrollable document area, even extending outside of the `<html>` and `<body>` rect bounds if horizontally scrollable.
-pub fn get_document_rect(html: &Element) -> Rect {
-    let scroll = get_node_scroll(html.into());
+pub fn get_document_rect(cache: &PlatformCache, html: &Element) -> Result<Rect, PlatformError> {
+    let scroll = try_get_node_scroll(html.into()).ok_or(PlatformError::MissingWindow)?;
     let body = html
         .owner_document()
-        .expect("Element should have owner document.")
-        .body()
-        .expect("Document should have body.");
+        .and_then(|document| document.body())
+        .ok_or(PlatformError::DetachedElement)?;
 
     let width = [
         html.scroll_width(),
@@ -33,17 +33,17 @@ pub fn get_document_rect(html: &Element) -> Rect {
     .max()
     .expect("Iterator is not empty.") as f64;
 
-    let mut x = -scroll.scroll_left + get_window_scroll_bar_x(html, None);
+    let mut x = -scroll.scroll_left + get_window_scroll_bar_x(cache, html, None)?;
     let y = -scroll.scroll_top;
 
-    if is_rtl(&body) {
+    if is_rtl(cache, &body)? {
         x += html.client_width().max(body.client_width()) as f64 - width;
     }
 
-    Rect {
+    Ok(Rect {
         x,
         y,
         width,
         height,
-    }
+    })
 }
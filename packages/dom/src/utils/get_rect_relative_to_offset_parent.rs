@@ -1,12 +1,14 @@
+use floating_ui_core::PlatformError;
 use floating_ui_utils::{
     Coords, Rect, Strategy,
     dom::{
-        DomElementOrWindow, NodeScroll, get_document_element, get_node_name, get_node_scroll,
-        is_overflow_element,
+        DomElementOrWindow, NodeScroll, get_node_name, try_get_document_element,
+        try_get_node_scroll, try_is_overflow_element,
     },
 };
 
 use crate::{
+    cache::PlatformCache,
     types::ElementOrVirtual,
     utils::{
         get_bounding_client_rect::get_bounding_client_rect, get_html_offset::get_html_offset,
@@ -15,19 +17,22 @@ use crate::{
 };
 
 pub fn get_rect_relative_to_offset_parent(
+    cache: &PlatformCache,
     element_or_virtual: ElementOrVirtual,
     offset_parent: DomElementOrWindow,
     strategy: Strategy,
-) -> Rect {
+) -> Result<Rect, PlatformError> {
     let is_offset_parent_an_element = matches!(offset_parent, DomElementOrWindow::Element(_));
-    let document_element = get_document_element(Some((&offset_parent).into()));
+    let document_element = try_get_document_element(Some((&offset_parent).into()))
+        .ok_or(PlatformError::DetachedElement)?;
     let is_fixed = strategy == Strategy::Fixed;
     let rect = get_bounding_client_rect(
+        cache,
         element_or_virtual,
         true,
         is_fixed,
         Some(offset_parent.clone()),
-    );
+    )?;
 
     let mut scroll = NodeScroll::new(0.0);
     let mut offsets = Coords::new(0.0);
@@ -35,19 +40,21 @@ pub fn get_rect_relative_to_offset_parent(
     #[allow(clippy::nonminimal_bool)]
     if is_offset_parent_an_element || !is_fixed {
         if get_node_name((&offset_parent).into()) != "body"
-            || is_overflow_element(&document_element)
+            || try_is_overflow_element(&document_element).ok_or(PlatformError::MissingWindow)?
         {
-            scroll = get_node_scroll(offset_parent.clone());
+            scroll =
+                try_get_node_scroll(offset_parent.clone()).ok_or(PlatformError::MissingWindow)?;
         }
 
         match offset_parent {
             DomElementOrWindow::Element(offset_parent) => {
                 let offset_rect = get_bounding_client_rect(
+                    cache,
                     offset_parent.into(),
                     true,
                     is_fixed,
                     Some(offset_parent.into()),
-                );
+                )?;
                 offsets.x = offset_rect.x + offset_parent.client_left() as f64;
                 offsets.y = offset_rect.y + offset_parent.client_top() as f64;
             }
@@ -58,11 +65,11 @@ pub fn get_rect_relative_to_offset_parent(
     // If the <body> scrollbar appears on the left (e.g. RTL systems).
     // Use Firefox with layout.scrollbar.side = 3 in about:config to test this.
     if !is_offset_parent_an_element {
-        offsets.x = get_window_scroll_bar_x(&document_element, None);
+        offsets.x = get_window_scroll_bar_x(cache, &document_element, None)?;
     }
 
     let html_offset = if !is_offset_parent_an_element && !is_fixed {
-        get_html_offset(&document_element, &scroll)
+        get_html_offset(cache, &document_element, &scroll)?
     } else {
         Coords::new(0.0)
     };
@@ -70,10 +77,10 @@ pub fn get_rect_relative_to_offset_parent(
     let x = rect.left + scroll.scroll_left - offsets.x - html_offset.x;
     let y = rect.top + scroll.scroll_top - offsets.y - html_offset.y;
 
-    Rect {
+    Ok(Rect {
         x,
         y,
         width: rect.width,
         height: rect.height,
-    }
+    })
 }
@@ -1,10 +1,11 @@
+use floating_ui_core::PlatformError;
 use floating_ui_utils::{
     Rect, Strategy,
-    dom::{get_computed_style, get_document_element, get_window, is_web_kit},
+    dom::{is_web_kit, try_get_document_element, try_get_window},
 };
 use web_sys::Element;
 
-use crate::utils::get_window_scroll_bar_x::get_window_scroll_bar_x;
+use crate::{cache::PlatformCache, utils::get_window_scroll_bar_x::get_window_scroll_bar_x};
 
 // Safety check: ensure the scrollbar space is reasonable in case this calculation is affected by unusual styles.
 // Most scrollbars leave 15-18px of space.
@@ -17,13 +18,15 @@ pub enum ViewportRootBoundary {
 }
 
 pub fn get_viewport_rect(
+    cache: &PlatformCache,
     element: &Element,
     strategy: Strategy,
     root_boundary: ViewportRootBoundary,
-) -> Rect {
+) -> Result<Rect, PlatformError> {
     let is_layout_viewport = root_boundary == ViewportRootBoundary::LayoutViewport;
-    let window = get_window(Some(element));
-    let html = get_document_element(Some(element.into()));
+    let window = try_get_window(Some(element)).ok_or(PlatformError::MissingWindow)?;
+    let html =
+        try_get_document_element(Some(element.into())).ok_or(PlatformError::DetachedElement)?;
     let visual_viewport = window.visual_viewport();
 
     let mut x = 0.0;
@@ -52,7 +55,7 @@ pub fn get_viewport_rect(
         }
     }
 
-    let window_scrollbar_x = get_window_scroll_bar_x(&html, None);
+    let window_scrollbar_x = get_window_scroll_bar_x(cache, &html, None)?;
     // `scrollbar-gutter: stable` on the <html> reserves gutter space that shrinks
     // the visual width but isn't reflected in `html.clientWidth`, so subtract it.
     // Only the inline-end (right) gutter can hold the scrollbar; `both-edges` also
@@ -63,9 +66,9 @@ pub fn get_viewport_rect(
     if window_scrollbar_x <= 0.0 {
         let doc = html
             .owner_document()
-            .expect("Element should have owner document.");
-        let body = doc.body().expect("Document should have body.");
-        let body_styles = get_computed_style(&body);
+            .ok_or(PlatformError::DetachedElement)?;
+        let body = doc.body().ok_or(PlatformError::DetachedElement)?;
+        let body_styles = cache.get_computed_style(&body)?;
         let body_margin_inline = if doc.compat_mode() == "CSS1Compat" {
             body_styles
                 .get_property_value("margin-left")
@@ -83,7 +86,8 @@ pub fn get_viewport_rect(
         let reserved_width =
             ((html.client_width() as f64) - (body.client_width() as f64) - body_margin_inline)
                 .abs();
-        let gutter = if get_computed_style(&html)
+        let gutter = if cache
+            .get_computed_style(&html)?
             .get_property_value("scrollbar-gutter")
             .ok()
             .as_deref()
@@ -99,10 +103,10 @@ pub fn get_viewport_rect(
         }
     }
 
-    Rect {
+    Ok(Rect {
         x,
         y,
         width,
         height,
-    }
+    })
 }
@@ -1,22 +1,26 @@
-use floating_ui_utils::dom::{get_document_element, get_node_scroll};
+use floating_ui_core::PlatformError;
+use floating_ui_utils::dom::{try_get_document_element, try_get_node_scroll};
 use web_sys::{DomRect, Element};
 
-use crate::utils::get_bounding_client_rect::get_bounding_client_rect;
+use crate::{cache::PlatformCache, utils::get_bounding_client_rect::get_bounding_client_rect};
 
 // If <html> has a CSS width greater than the viewport, then this will be incorrect for RTL.
-pub fn get_window_scroll_bar_x(element: &Element, rect: Option<&DomRect>) -> f64 {
-    let left_scroll = get_node_scroll(element.into()).scroll_left;
+pub fn get_window_scroll_bar_x(
+    cache: &PlatformCache,
+    element: &Element,
+    rect: Option<&DomRect>,
+) -> Result<f64, PlatformError> {
+    let left_scroll = try_get_node_scroll(element.into())
+        .ok_or(PlatformError::MissingWindow)?
+        .scroll_left;
 
-    if let Some(rect) = rect {
+    Ok(if let Some(rect) = rect {
         rect.left() + left_scroll
     } else {
-        get_bounding_client_rect(
-            (&get_document_element(Some(element.into()))).into(),
-            false,
-            false,
-            None,
-        )
-        .left
+        let document_element =
+            try_get_document_element(Some(element.into())).ok_or(PlatformError::DetachedElement)?;
+
+        get_bounding_client_rect(cache, (&document_element).into(), false, false, None)?.left
             + left_scroll
-    }
+    })
 }
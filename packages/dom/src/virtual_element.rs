@@ -0,0 +1,103 @@
+use std::rc::Rc;
+
+use floating_ui_utils::{ClientRectObject, Coords, Rect};
+use web_sys::{Element, MouseEvent, Node, Range, wasm_bindgen::JsCast, window};
+
+use crate::types::DefaultVirtualElement;
+
+fn node_to_element(node: Node) -> Option<Element> {
+    match node.dyn_into::<Element>() {
+        Ok(element) => Some(element),
+        Err(node) => node.parent_element(),
+    }
+}
+
+/// Creates a virtual element with a zero-size rect at a fixed point in client coordinates.
+///
+/// The `context_element` determines the scroll ancestors watched by [`auto_update`][`crate::auto_update`].
+pub fn virtual_element_from_point(
+    point: Coords,
+    context_element: Option<Element>,
+) -> DefaultVirtualElement {
+    let virtual_element = DefaultVirtualElement::new(Rc::new(move || {
+        ClientRectObject::from(Rect {
+            x: point.x,
+            y: point.y,
+            width: 0.0,
+            height: 0.0,
+        })
+    }));
+
+    match context_element {
+        Some(context_element) => virtual_element.context_element(context_element),
+        None => virtual_element,
+    }
+}
+
+/// Creates a virtual element with a zero-size rect at the cursor position of a mouse event.
+///
+/// Also accepts a `PointerEvent`, which dereferences to [`MouseEvent`].
+/// The event target is used as context element.
+pub fn virtual_element_from_mouse_event(event: &MouseEvent) -> DefaultVirtualElement {
+    virtual_element_from_point(
+        Coords {
+            x: event.client_x() as f64,
+            y: event.client_y() as f64,
+        },
+        event
+            .target()
+            .and_then(|target| target.dyn_into::<Node>().ok())
+            .and_then(node_to_element),
+    )
+}
+
+/// Creates a virtual element which follows a [`Range`], e.g. a text selection.
+///
+/// Provides the client rects of the range for the [`Inline`][`crate::Inline`] middleware.
+/// The element containing the range is used as context element.
+pub fn virtual_element_from_range(range: &Range) -> DefaultVirtualElement {
+    let virtual_element = DefaultVirtualElement::new(Rc::new({
+        let range = range.clone();
+
+        move || range.get_bounding_client_rect().into()
+    }))
+    .get_client_rects(Rc::new({
+        let range = range.clone();
+
+        move || {
+            range
+                .get_client_rects()
+                .map(ClientRectObject::from_dom_rect_list)
+                .unwrap_or_default()
+        }
+    }));
+
+    match range
+        .common_ancestor_container()
+        .ok()
+        .and_then(node_to_element)
+    {
+        Some(context_element) => virtual_element.context_element(context_element),
+        None => virtual_element,
+    }
+}
+
+/// Creates a virtual element which follows the first range of the current selection.
+///
+/// Returns [`Option::None`] if nothing is selected. A collapsed selection results in a zero-width rect at the caret.
+pub fn virtual_element_from_selection() -> Option<DefaultVirtualElement> {
+    let selection = window()
+        .expect("Window should exist.")
+        .get_selection()
+        .ok()
+        .flatten()?;
+
+    if selection.range_count() == 0 {
+        return None;
+    }
+
+    selection
+        .get_range_at(0)
+        .ok()
+        .map(|range| virtual_element_from_range(&range))
+}
@@ -0,0 +1,160 @@
+use egui::{Area, InnerResponse, Order, Pos2, Rect, Response, Ui, Vec2};
+use floating_ui_core::ComputePositionReturn;
+use floating_ui_utils::Placement;
+
+use crate::{ComputePositionConfig, MiddlewareVec, compute_position};
+
+/// Options for [`floating_area`].
+#[derive(Clone, Default)]
+pub struct FloatingAreaOptions {
+    /// Where to place the floating area relative to the reference widget.
+    ///
+    /// Defaults to [`Placement::Bottom`].
+    pub placement: Option<Placement>,
+
+    /// Vector of middleware objects to modify the positioning or provide data for rendering.
+    ///
+    /// Defaults to an empty vector.
+    pub middleware: Option<MiddlewareVec>,
+
+    /// Whether the clip rect of the [`Ui`] is used as clipping ancestor, in addition to the screen.
+    ///
+    /// Defaults to `false`.
+    pub clip: Option<bool>,
+
+    /// The layer order of the floating area.
+    ///
+    /// Defaults to [`Order::Foreground`].
+    pub order: Option<Order>,
+}
+
+impl FloatingAreaOptions {
+    /// Set `placement` option.
+    pub fn placement(mut self, value: Placement) -> Self {
+        self.placement = Some(value);
+        self
+    }
+
+    /// Set `middleware` option.
+    pub fn middleware(mut self, value: MiddlewareVec) -> Self {
+        self.middleware = Some(value);
+        self
+    }
+
+    /// Set `clip` option.
+    pub fn clip(mut self, value: bool) -> Self {
+        self.clip = Some(value);
+        self
+    }
+
+    /// Set `order` option.
+    pub fn order(mut self, value: Order) -> Self {
+        self.order = Some(value);
+        self
+    }
+}
+
+/// Response of [`floating_area`].
+pub struct FloatingAreaResponse<R> {
+    /// The response of the area and the value returned by the contents.
+    pub inner: InnerResponse<R>,
+
+    /// The computed position, including the data of the middleware, e.g. for rendering an arrow.
+    pub position: ComputePositionReturn,
+}
+
+/// Shows an [`Area`] next to the widget of the response.
+///
+/// The size of the area is measured in the previous pass. When the area is shown for the first time, egui measures it without drawing and a repaint is requested.
+pub fn floating_area<R>(
+    ui: &Ui,
+    response: &Response,
+    options: FloatingAreaOptions,
+    add_contents: impl FnOnce(&mut Ui) -> R,
+) -> FloatingAreaResponse<R> {
+    let ctx = ui.ctx();
+    let id = response.id.with("floating_area");
+
+    let size = ctx
+        .memory(|memory| memory.area_rect(id))
+        .map(|rect| rect.size());
+    if size.is_none() {
+        ctx.request_repaint();
+    }
+    let floating = Rect::from_min_size(Pos2::ZERO, size.unwrap_or(Vec2::ZERO));
+
+    let mut config = ComputePositionConfig::new(ctx.content_rect());
+    if options.clip.unwrap_or(false) {
+        config.clip_rect = Some(ui.clip_rect());
+    }
+    config.placement = options.placement;
+    config.middleware = options.middleware;
+
+    let position = compute_position((&response.rect).into(), &floating, config);
+
+    let inner = Area::new(id)
+        .order(options.order.unwrap_or(Order::Foreground))
+        .fixed_pos(Pos2::new(position.x as f32, position.y as f32))
+        .constrain(false)
+        .show(ctx, add_contents);
+
+    FloatingAreaResponse { inner, position }
+}
+
+#[cfg(test)]
+mod tests {
+    use egui::{CentralPanel, Context, RawInput};
+
+    use super::*;
+    use crate::{Flip, FlipOptions};
+
+    fn run(ctx: &Context, placement: Placement) -> (Rect, Rect, Placement) {
+        let mut result = None;
+
+        let _ = ctx.run(
+            RawInput {
+                screen_rect: Some(Rect::from_min_size(Pos2::ZERO, Vec2::new(400.0, 300.0))),
+                ..Default::default()
+            },
+            |ctx| {
+                CentralPanel::default().show(ctx, |ui| {
+                    ui.add_space(250.0);
+                    let response = ui.button("Reference");
+
+                    let floating = floating_area(
+                        ui,
+                        &response,
+                        FloatingAreaOptions::default()
+                            .placement(placement)
+                            .middleware(vec![Box::new(Flip::new(FlipOptions::default()))]),
+                        |ui| {
+                            ui.set_min_size(Vec2::new(100.0, 80.0));
+                            ui.label("Floating")
+                        },
+                    );
+
+                    result = Some((
+                        response.rect,
+                        floating.inner.response.rect,
+                        floating.position.placement,
+                    ));
+                });
+            },
+        );
+
+        result.expect("Panel should be shown.")
+    }
+
+    #[test]
+    fn test_floating_area() {
+        let ctx = Context::default();
+
+        // The first pass measures the floating area.
+        run(&ctx, Placement::Bottom);
+        let (reference, floating, placement) = run(&ctx, Placement::Bottom);
+
+        assert_eq!(placement, Placement::Top);
+        assert_eq!(floating.bottom(), reference.top());
+        assert_eq!(floating.center().x, reference.center().x);
+    }
+}
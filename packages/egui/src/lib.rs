@@ -0,0 +1,119 @@
+//! Rust port of [Floating UI](https://floating-ui.com/).
+//!
+//! This is the library to use Floating UI with [egui](https://www.egui.rs/), wrapping [`floating_ui_core`] with egui interface logic.
+//!
+//! See [the Rust Floating UI book](https://floating-ui.rustforweb.org/) for more documenation.
+
+mod floating_area;
+mod middleware;
+mod platform;
+mod types;
+
+pub use self::platform::Platform;
+pub use crate::floating_area::*;
+pub use crate::middleware::*;
+pub use crate::types::*;
+pub use floating_ui_core::{
+    ComputePositionError, ComputePositionReturn, Derivable, DerivableFn, DetectOverflowOptions,
+    ElementContext, Middleware, MiddlewareData, MiddlewareDataError, MiddlewareDataValue,
+    MiddlewareReturn, MiddlewareReturnData, MiddlewareState, MiddlewareTraceStep,
+    MiddlewareWithOptions, PlatformError, RootBoundary,
+};
+#[doc(no_inline)]
+pub use floating_ui_utils::{
+    AlignedPlacement, Alignment, Axis, ClientRectObject, Coords, Dimensions, ElementRects, Length,
+    Padding, PartialSideObject, Placement, Side, SideObject, VirtualElement,
+};
+
+use egui::Rect;
+use floating_ui_core::{
+    ComputePositionConfig as CoreComputePositionConfig, compute_position as compute_position_core,
+};
+
+/// Options for [`compute_position`].
+#[derive(Clone)]
+pub struct ComputePositionConfig {
+    /// The area the floating element is kept in, usually [`egui::Context::content_rect`].
+    pub viewport: Rect,
+
+    /// Clipping ancestor of the reference element, e.g. [`egui::Ui::clip_rect`].
+    ///
+    /// Defaults to `None`, so the floating element is only kept inside the viewport.
+    pub clip_rect: Option<Rect>,
+
+    /// Where to place the floating element relative to the reference element.
+    ///
+    /// Defaults to [`Placement::Bottom`].
+    pub placement: Option<Placement>,
+
+    /// Vector of middleware objects to modify the positioning or provide data for rendering.
+    ///
+    /// Defaults to an empty vector.
+    pub middleware: Option<MiddlewareVec>,
+
+    /// Whether to record every middleware invocation in [`ComputePositionReturn::trace`].
+    ///
+    /// Defaults to `false`.
+    pub trace: Option<bool>,
+}
+
+impl ComputePositionConfig {
+    pub fn new(viewport: Rect) -> Self {
+        ComputePositionConfig {
+            viewport,
+            clip_rect: None,
+            placement: None,
+            middleware: None,
+            trace: None,
+        }
+    }
+
+    /// Set `clip_rect` option.
+    pub fn clip_rect(mut self, value: Rect) -> Self {
+        self.clip_rect = Some(value);
+        self
+    }
+
+    /// Set `placement` option.
+    pub fn placement(mut self, value: Placement) -> Self {
+        self.placement = Some(value);
+        self
+    }
+
+    /// Set `middleware` option.
+    pub fn middleware(mut self, value: MiddlewareVec) -> Self {
+        self.middleware = Some(value);
+        self
+    }
+
+    /// Set `trace` option.
+    pub fn trace(mut self, value: bool) -> Self {
+        self.trace = Some(value);
+        self
+    }
+}
+
+/// Computes the `x` and `y` coordinates that will place the floating element next to a given reference element.
+pub fn compute_position(
+    reference: ElementOrVirtual,
+    floating: &Rect,
+    config: ComputePositionConfig,
+) -> ComputePositionReturn {
+    let platform = match config.clip_rect {
+        Some(clip_rect) => Platform::with_clip_rect(config.viewport, clip_rect),
+        None => Platform::new(config.viewport),
+    };
+
+    compute_position_core(
+        reference,
+        floating,
+        CoreComputePositionConfig {
+            platform: &platform,
+            placement: config.placement,
+            logical_placement: None,
+            strategy: None,
+            middleware: config.middleware,
+            trace: config.trace,
+        },
+    )
+}
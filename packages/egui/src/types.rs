@@ -0,0 +1,15 @@
+use egui::Rect;
+use floating_ui_core::{Boundary as CoreBoundary, Middleware};
+use floating_ui_utils::{
+    DefaultVirtualElement as CoreDefaultVirtualElement, ElementOrVirtual as CoreElementOrVirtual,
+    OwnedElementOrVirtual as CoreOwnedElementOrVirtual,
+};
+
+pub type Boundary = CoreBoundary<Rect>;
+
+pub type DefaultVirtualElement = CoreDefaultVirtualElement<Rect>;
+pub type ElementOrVirtual<'a> = CoreElementOrVirtual<'a, Rect>;
+pub type OwnedElementOrVirtual = CoreOwnedElementOrVirtual<Rect>;
+
+/// Vector of middleware used in [`ComputePositionConfig`][`crate::ComputePositionConfig`].
+pub type MiddlewareVec = Vec<Box<dyn Middleware<Rect, ()>>>;
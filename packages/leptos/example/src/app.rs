@@ -1,7 +1,7 @@
 use floating_ui_leptos::{
-    ARROW_NAME, Arrow, ArrowData, ArrowOptions, DetectOverflowOptions, Flip, FlipOptions,
-    MiddlewareVec, Offset, OffsetOptions, Padding, Placement, Shift, ShiftOptions, Side,
-    UseFloatingOptions, UseFloatingReturn, use_floating,
+    Arrow, ArrowData, ArrowOptions, DetectOverflowOptions, Flip, FlipOptions, MiddlewareVec,
+    Offset, OffsetOptions, Padding, Placement, Shift, ShiftOptions, Side, UseFloatingOptions,
+    UseFloatingReturn, use_floating,
 };
 use leptos::prelude::*;
 use leptos_node_ref::AnyNodeRef;
@@ -41,7 +41,7 @@ pub fn App() -> impl IntoView {
 
     let static_side = Signal::derive(move || placement.get().side().opposite());
     let arrow_data =
-        Signal::derive(move || -> Option<ArrowData> { middleware_data.get().get_as(ARROW_NAME) });
+        Signal::derive(move || middleware_data.get().get_typed::<ArrowData>().cloned());
     let arrow_x = Signal::derive(move || {
         arrow_data
             .get()
@@ -0,0 +1,121 @@
+use floating_ui_dom::{
+    FloatingArrowLayout, FloatingArrowOptions, MiddlewareData, Placement, dom::get_computed_style,
+    floating_arrow_clip_path_id,
+};
+use leptos::prelude::*;
+use leptos_node_ref::AnyNodeRef;
+use web_sys::wasm_bindgen::JsCast;
+
+/// SVG arrow which points from the floating element to the reference element.
+///
+/// Pass the same `node_ref` to [`Arrow`][crate::Arrow] and render the arrow inside the floating element.
+/// The arrow is positioned from the `placement` and `middleware_data` returned by [`use_floating`][crate::use_floating].
+///
+/// See [the Rust Floating UI book](https://floating-ui.rustforweb.org/frameworks/leptos.html#arrow) for more documentation.
+#[component]
+pub fn FloatingArrow(
+    /// The arrow element, also passed to [`Arrow`][crate::Arrow].
+    #[prop(into)]
+    node_ref: AnyNodeRef,
+    /// The final placement of the floating element.
+    #[prop(into)]
+    placement: Signal<Placement>,
+    /// The middleware data of the floating element.
+    #[prop(into)]
+    middleware_data: Signal<MiddlewareData>,
+    /// The width of the arrow. Defaults to `14`.
+    #[prop(into, optional)]
+    width: MaybeProp<f64>,
+    /// The height of the arrow. Defaults to `7`.
+    #[prop(into, optional)]
+    height: MaybeProp<f64>,
+    /// The radius of the rounded tip of the arrow. Defaults to `0`.
+    #[prop(into, optional)]
+    tip_radius: MaybeProp<f64>,
+    /// The width of the stroke, matching the border width of the floating element. Defaults to `0`.
+    #[prop(into, optional)]
+    stroke_width: MaybeProp<f64>,
+    /// A static CSS offset of the arrow from the aligned edge of the floating element.
+    #[prop(into, optional)]
+    static_offset: MaybeProp<String>,
+    /// A custom SVG path of the arrow, drawn pointing down.
+    #[prop(into, optional)]
+    d: MaybeProp<String>,
+    /// The fill color of the arrow, matching the background of the floating element.
+    #[prop(into, optional)]
+    fill: MaybeProp<String>,
+    /// The stroke color of the arrow, matching the border color of the floating element.
+    #[prop(into, optional)]
+    stroke: MaybeProp<String>,
+) -> impl IntoView {
+    let clip_path_id = floating_arrow_clip_path_id();
+    let (rtl, set_rtl) = signal(false);
+
+    Effect::new(move |_| {
+        if let Some(element) = node_ref
+            .get()
+            .and_then(|element| element.dyn_into::<web_sys::Element>().ok())
+        {
+            set_rtl.set(
+                get_computed_style(&element)
+                    .get_property_value("direction")
+                    .is_ok_and(|direction| direction == "rtl"),
+            );
+        }
+    });
+
+    let layout = Memo::new(move |_| {
+        let options = FloatingArrowOptions {
+            width: width.get(),
+            height: height.get(),
+            tip_radius: tip_radius.get(),
+            stroke_width: stroke_width.get(),
+            static_offset: static_offset.get(),
+            d: d.get(),
+        };
+
+        middleware_data.with(|middleware_data| {
+            FloatingArrowLayout::new(&options, placement.get(), middleware_data, rtl.get())
+        })
+    });
+
+    let clip_path = format!("url(#{clip_path_id})");
+
+    view! {
+        <svg
+            node_ref=node_ref
+            aria-hidden="true"
+            width=move || layout.get().width
+            height=move || layout.get().height
+            viewBox=move || layout.get().view_box
+            style=move || layout.get().style
+        >
+            <Show when=move || { layout.get().stroke_width > 0.0 }>
+                <path
+                    clip-path=clip_path.clone()
+                    fill="none"
+                    stroke=move || stroke.get()
+                    stroke-width=move || layout.get().stroke_width
+                    d=move || layout.get().d
+                />
+            </Show>
+            <path
+                fill=move || fill.get()
+                stroke=move || if layout.get().stroke_fill {
+                    fill.get().unwrap_or_else(|| "none".to_owned())
+                } else {
+                    "none".to_owned()
+                }
+                d=move || layout.get().d
+            />
+            <clipPath id=clip_path_id.clone()>
+                <rect
+                    x=move || layout.get().clip_rect.x
+                    y=move || layout.get().clip_rect.y
+                    width=move || layout.get().clip_rect.width
+                    height=move || layout.get().clip_rect.height
+                />
+            </clipPath>
+        </svg>
+    }
+}
@@ -1,13 +1,20 @@
 use std::{fmt::Display, rc::Rc};
 
+use std::ops::Deref;
+
 use floating_ui_dom::{
-    AutoUpdateOptions, ElementOrVirtual, Middleware, MiddlewareData, Placement, Strategy,
+    AutoUpdateOptions, ClientPointAxis, ComputePositionConfig, ComputePositionReturn,
+    ElementOrVirtual, LogicalPlacement, Middleware, MiddlewareData, Placement, SafePolygonOptions,
+    Strategy, TransitionDuration, TransitionStatus, TransitionStyles, TransitionStylesOptions,
     auto_update,
 };
 use leptos::{prelude::*, tachys::html::style::IntoStyle};
+use leptos_node_ref::AnyNodeRef;
 use send_wrapper::SendWrapper;
 use web_sys::{Element, Window};
 
+use crate::use_floating::VirtualElementOrNodeRef;
+
 pub type WhileElementsMountedFn =
     dyn Fn(ElementOrVirtual, &Element, Rc<dyn Fn()>) -> WhileElementsMountedCleanupFn;
 
@@ -28,6 +35,13 @@ pub struct UseFloatingOptions {
     /// Defaults to [`Placement::Bottom`].
     pub placement: MaybeProp<Placement>,
 
+    /// Writing-mode relative placement, which takes precedence over `placement`.
+    ///
+    /// Resolved with the computed `writing-mode` and `direction` of the floating element.
+    ///
+    /// Defaults to `None`.
+    pub logical_placement: MaybeProp<LogicalPlacement>,
+
     /// The strategy to use when positioning the floating element.
     ///
     /// Defaults to [`Strategy::Absolute`].
@@ -43,6 +57,14 @@ pub struct UseFloatingOptions {
     /// Defaults to `true`.
     pub transform: MaybeProp<bool>,
 
+    /// Whether to show the floating element in the top layer using the `popover` attribute.
+    ///
+    /// The popover is shown and hidden following `open`, the strategy is forced to [`Strategy::Fixed`]
+    /// and `while_elements_mounted` only runs while the popover is open.
+    ///
+    /// Defaults to `false`.
+    pub popover: MaybeProp<bool>,
+
     /// Callback to handle mounting/unmounting of the elements.
     ///
     /// Defaults to [`Option::None`].
@@ -62,6 +84,12 @@ impl UseFloatingOptions {
         self
     }
 
+    /// Set `logical_placement` option.
+    pub fn logical_placement<I: Into<MaybeProp<LogicalPlacement>>>(mut self, value: I) -> Self {
+        self.logical_placement = value.into();
+        self
+    }
+
     /// Set `strategy` option.
     pub fn strategy<I: Into<MaybeProp<Strategy>>>(mut self, value: I) -> Self {
         self.strategy = value.into();
@@ -80,6 +108,12 @@ impl UseFloatingOptions {
         self
     }
 
+    /// Set `popover` option.
+    pub fn popover<I: Into<MaybeProp<bool>>>(mut self, value: I) -> Self {
+        self.popover = value.into();
+        self
+    }
+
     /// Set `while_elements_mounted` option.
     pub fn while_elements_mounted<I: Into<MaybeProp<SendWrapper<Rc<WhileElementsMountedFn>>>>>(
         mut self,
@@ -167,10 +201,13 @@ impl UseFloatingOptions {
 #[derive(Clone, Debug, PartialEq)]
 pub struct FloatingStyles {
     pub position: Strategy,
+    pub margin: Option<String>,
+    pub inset: Option<String>,
     pub top: String,
     pub left: String,
     pub transform: Option<String>,
     pub will_change: Option<String>,
+    pub transform_origin: Option<String>,
 }
 
 impl FloatingStyles {
@@ -181,6 +218,14 @@ impl FloatingStyles {
         }
     }
 
+    pub fn style_margin(&self) -> Option<String> {
+        self.margin.clone()
+    }
+
+    pub fn style_inset(&self) -> Option<String> {
+        self.inset.clone()
+    }
+
     pub fn style_top(&self) -> String {
         self.top.clone()
     }
@@ -196,17 +241,27 @@ impl FloatingStyles {
     pub fn style_will_change(&self) -> Option<String> {
         self.will_change.clone()
     }
+
+    pub fn style_transform_origin(&self) -> Option<String> {
+        self.transform_origin.clone()
+    }
 }
 
 impl Display for FloatingStyles {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "position: {}; top: {}; left: {};{}{}",
+            "position: {};{}{} top: {}; left: {};{}{}{}",
             match self.position {
                 Strategy::Absolute => "absolute",
                 Strategy::Fixed => "fixed",
             },
+            self.margin
+                .as_ref()
+                .map_or("".to_owned(), |margin| format!(" margin: {margin};")),
+            self.inset
+                .as_ref()
+                .map_or("".to_owned(), |inset| format!(" inset: {inset};")),
             self.top,
             self.left,
             self.transform
@@ -218,6 +273,11 @@ impl Display for FloatingStyles {
                 .as_ref()
                 .map_or("".to_owned(), |will_change| format!(
                     " will-change: {will_change};"
+                )),
+            self.transform_origin
+                .as_ref()
+                .map_or("".to_owned(), |transform_origin| format!(
+                    " transform-origin: {transform_origin};"
                 ))
         )
     }
@@ -298,3 +358,393 @@ pub struct UseFloatingReturn {
     /// The function to update floating position manually.
     pub update: SendWrapper<Rc<dyn Fn()>>,
 }
+
+/// Entry for [`use_compute_positions`][crate::use_compute_positions::use_compute_positions].
+#[derive(Clone)]
+pub struct ComputePositionsEntry {
+    /// The reference element.
+    pub reference: VirtualElementOrNodeRef,
+
+    /// The floating element.
+    pub floating: AnyNodeRef,
+
+    /// Where to place the floating element relative to the reference element.
+    ///
+    /// Defaults to [`Placement::Bottom`].
+    pub placement: Option<Placement>,
+
+    /// The strategy to use when positioning the floating element.
+    ///
+    /// Defaults to [`Strategy::Absolute`].
+    pub strategy: Option<Strategy>,
+
+    /// Array of middleware objects to modify the positioning or provide data for rendering.
+    ///
+    /// Defaults to an empty vector.
+    pub middleware: Option<WrappedMiddleware>,
+}
+
+impl ComputePositionsEntry {
+    /// Create an entry for the given reference and floating element.
+    pub fn new<R: Into<VirtualElementOrNodeRef>>(reference: R, floating: AnyNodeRef) -> Self {
+        ComputePositionsEntry {
+            reference: reference.into(),
+            floating,
+            placement: None,
+            strategy: None,
+            middleware: None,
+        }
+    }
+
+    /// Set `placement` option.
+    pub fn placement(mut self, value: Placement) -> Self {
+        self.placement = Some(value);
+        self
+    }
+
+    /// Set `strategy` option.
+    pub fn strategy(mut self, value: Strategy) -> Self {
+        self.strategy = Some(value);
+        self
+    }
+
+    /// Set `middleware` option.
+    pub fn middleware(mut self, value: WrappedMiddleware) -> Self {
+        self.middleware = Some(value);
+        self
+    }
+
+    pub(crate) fn config(&self) -> ComputePositionConfig {
+        ComputePositionConfig {
+            placement: self.placement,
+            logical_placement: None,
+            strategy: self.strategy,
+            middleware: self
+                .middleware
+                .as_ref()
+                .map(|middleware| middleware.deref().clone()),
+            trace: None,
+            cache: None,
+        }
+    }
+}
+
+/// Return of [`use_compute_positions`][crate::use_compute_positions::use_compute_positions].
+pub struct UseComputePositionsReturn {
+    /// The positions in the order of the entries.
+    ///
+    /// A position is `None` if the elements of the entry are not mounted or could not be measured.
+    pub positions: Signal<Vec<Option<ComputePositionReturn>>>,
+
+    /// The function to update the positions manually.
+    pub update: SendWrapper<Rc<dyn Fn()>>,
+}
+
+/// Options for [`use_client_point`][`crate::use_client_point::use_client_point`].
+#[derive(Clone, Default)]
+pub struct UseClientPointOptions {
+    /// Whether the floating element follows the client point.
+    ///
+    /// Defaults to `true`.
+    pub enabled: MaybeProp<bool>,
+
+    /// Represents the open/close state of the floating element. The point is reset when closed.
+    ///
+    /// Defaults to `true`.
+    pub open: MaybeProp<bool>,
+
+    /// The axis on which the floating element follows the client point.
+    ///
+    /// Defaults to [`ClientPointAxis::Both`].
+    pub axis: MaybeProp<ClientPointAxis>,
+
+    /// Whether to only use the first client point after opening, instead of following the cursor.
+    ///
+    /// Defaults to `false`.
+    pub initial_only: MaybeProp<bool>,
+}
+
+impl UseClientPointOptions {
+    /// Set `enabled` option.
+    pub fn enabled<I: Into<MaybeProp<bool>>>(mut self, value: I) -> Self {
+        self.enabled = value.into();
+        self
+    }
+
+    /// Set `open` option.
+    pub fn open<I: Into<MaybeProp<bool>>>(mut self, value: I) -> Self {
+        self.open = value.into();
+        self
+    }
+
+    /// Set `axis` option.
+    pub fn axis<I: Into<MaybeProp<ClientPointAxis>>>(mut self, value: I) -> Self {
+        self.axis = value.into();
+        self
+    }
+
+    /// Set `initial_only` option.
+    pub fn initial_only<I: Into<MaybeProp<bool>>>(mut self, value: I) -> Self {
+        self.initial_only = value.into();
+        self
+    }
+}
+
+/// Return of [`use_client_point`][crate::use_client_point::use_client_point].
+pub struct UseClientPointReturn {
+    /// The reference to pass to [`use_floating`][crate::use_floating::use_floating].
+    ///
+    /// A virtual element at the client point, or the reference element until the pointer moved over it.
+    pub reference: Signal<VirtualElementOrNodeRef>,
+}
+
+/// Options for [`use_transition_status`][`crate::use_transition::use_transition_status`].
+#[derive(Clone, Default)]
+pub struct UseTransitionStatusOptions {
+    /// Represents the open/close state of the floating element.
+    ///
+    /// Defaults to `true`.
+    pub open: MaybeProp<bool>,
+
+    /// The duration of the transitions. The floating element stays mounted for the close duration.
+    ///
+    /// Defaults to `250` milliseconds for opening and closing.
+    pub duration: MaybeProp<TransitionDuration>,
+}
+
+impl UseTransitionStatusOptions {
+    /// Set `open` option.
+    pub fn open<I: Into<MaybeProp<bool>>>(mut self, value: I) -> Self {
+        self.open = value.into();
+        self
+    }
+
+    /// Set `duration` option.
+    pub fn duration<I: Into<MaybeProp<TransitionDuration>>>(mut self, value: I) -> Self {
+        self.duration = value.into();
+        self
+    }
+}
+
+/// Return of [`use_transition_status`][crate::use_transition::use_transition_status].
+pub struct UseTransitionStatusReturn {
+    /// Whether the floating element should be rendered, including while the close transition plays.
+    pub is_mounted: Signal<bool>,
+
+    /// The status of the transition.
+    pub status: Signal<TransitionStatus>,
+}
+
+/// Options for [`use_transition_styles`][`crate::use_transition::use_transition_styles`].
+#[derive(Clone, Default)]
+pub struct UseTransitionStylesOptions {
+    /// Represents the open/close state of the floating element.
+    ///
+    /// Defaults to `true`.
+    pub open: MaybeProp<bool>,
+
+    /// The final placement of the floating element, passed to the style functions.
+    ///
+    /// Defaults to [`Placement::Bottom`].
+    pub placement: MaybeProp<Placement>,
+
+    /// The duration and the styles of the transitions.
+    ///
+    /// Defaults to [`TransitionStylesOptions::default`].
+    pub styles: MaybeProp<TransitionStylesOptions>,
+}
+
+impl UseTransitionStylesOptions {
+    /// Set `open` option.
+    pub fn open<I: Into<MaybeProp<bool>>>(mut self, value: I) -> Self {
+        self.open = value.into();
+        self
+    }
+
+    /// Set `placement` option.
+    pub fn placement<I: Into<MaybeProp<Placement>>>(mut self, value: I) -> Self {
+        self.placement = value.into();
+        self
+    }
+
+    /// Set `styles` option.
+    pub fn styles<I: Into<MaybeProp<TransitionStylesOptions>>>(mut self, value: I) -> Self {
+        self.styles = value.into();
+        self
+    }
+}
+
+/// Return of [`use_transition_styles`][crate::use_transition::use_transition_styles].
+pub struct UseTransitionStylesReturn {
+    /// Whether the floating element should be rendered, including while the close transition plays.
+    pub is_mounted: Signal<bool>,
+
+    /// The status of the transition.
+    pub status: Signal<TransitionStatus>,
+
+    /// The styles to apply to the floating element, e.g. to its `style` attribute.
+    pub styles: Signal<TransitionStyles>,
+}
+
+/// Open and close delays for [`HoverDelay`].
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub struct HoverDelayValues {
+    /// The delay before opening in milliseconds.
+    ///
+    /// Defaults to `0`.
+    pub open: Option<u32>,
+
+    /// The delay before closing in milliseconds.
+    ///
+    /// Defaults to `0`.
+    pub close: Option<u32>,
+}
+
+impl HoverDelayValues {
+    /// Set `open` option.
+    pub fn open(mut self, value: u32) -> Self {
+        self.open = Some(value);
+        self
+    }
+
+    /// Set `close` option.
+    pub fn close(mut self, value: u32) -> Self {
+        self.close = Some(value);
+        self
+    }
+}
+
+/// Delay of a hover interaction in milliseconds.
+///
+/// A number (the same delay for opening and closing) or separate delays ([`HoverDelayValues`]).
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum HoverDelay {
+    Value(u32),
+    Values(HoverDelayValues),
+}
+
+impl HoverDelay {
+    /// The delay before opening in milliseconds.
+    pub fn open(&self) -> u32 {
+        match self {
+            HoverDelay::Value(value) => *value,
+            HoverDelay::Values(values) => values.open.unwrap_or(0),
+        }
+    }
+
+    /// The delay before closing in milliseconds.
+    pub fn close(&self) -> u32 {
+        match self {
+            HoverDelay::Value(value) => *value,
+            HoverDelay::Values(values) => values.close.unwrap_or(0),
+        }
+    }
+}
+
+impl Default for HoverDelay {
+    fn default() -> Self {
+        HoverDelay::Value(0)
+    }
+}
+
+impl From<u32> for HoverDelay {
+    fn from(value: u32) -> Self {
+        HoverDelay::Value(value)
+    }
+}
+
+/// Options for [`use_hover`][`crate::use_hover::use_hover`].
+#[derive(Clone, Default)]
+pub struct UseHoverOptions {
+    /// Whether the hover interaction is enabled.
+    ///
+    /// Defaults to `true`.
+    pub enabled: MaybeProp<bool>,
+
+    /// Represents the open/close state of the floating element.
+    ///
+    /// Defaults to `false`.
+    pub open: MaybeProp<bool>,
+
+    /// Called when the hover interaction opens or closes the floating element.
+    ///
+    /// Defaults to [`Option::None`].
+    pub on_open_change: Option<Callback<bool>>,
+
+    /// The final placement of the floating element, used by the safe polygon.
+    ///
+    /// Defaults to [`Placement::Bottom`].
+    pub placement: MaybeProp<Placement>,
+
+    /// The delay before opening and closing.
+    ///
+    /// Defaults to `0`.
+    pub delay: MaybeProp<HoverDelay>,
+
+    /// Waits until the pointer rests on the reference element for this many milliseconds before opening,
+    /// instead of opening when the pointer enters it. Ignored when an open delay is set.
+    ///
+    /// Defaults to `0` (disabled).
+    pub rest_ms: MaybeProp<u32>,
+
+    /// Whether to only open for mouse pointers, ignoring touch and pen input.
+    ///
+    /// Defaults to `false`.
+    pub mouse_only: MaybeProp<bool>,
+
+    /// Keeps the floating element open while the cursor moves from the reference element towards it, see [`SafePolygonOptions`].
+    ///
+    /// Defaults to [`Option::None`], which closes the floating element when the cursor leaves the reference element,
+    /// unless it moves directly onto the floating element.
+    pub safe_polygon: MaybeProp<SafePolygonOptions>,
+}
+
+impl UseHoverOptions {
+    /// Set `enabled` option.
+    pub fn enabled<I: Into<MaybeProp<bool>>>(mut self, value: I) -> Self {
+        self.enabled = value.into();
+        self
+    }
+
+    /// Set `open` option.
+    pub fn open<I: Into<MaybeProp<bool>>>(mut self, value: I) -> Self {
+        self.open = value.into();
+        self
+    }
+
+    /// Set `on_open_change` option.
+    pub fn on_open_change<F: Fn(bool) + Send + Sync + 'static>(mut self, value: F) -> Self {
+        self.on_open_change = Some(Callback::new(value));
+        self
+    }
+
+    /// Set `placement` option.
+    pub fn placement<I: Into<MaybeProp<Placement>>>(mut self, value: I) -> Self {
+        self.placement = value.into();
+        self
+    }
+
+    /// Set `delay` option.
+    pub fn delay<I: Into<MaybeProp<HoverDelay>>>(mut self, value: I) -> Self {
+        self.delay = value.into();
+        self
+    }
+
+    /// Set `rest_ms` option.
+    pub fn rest_ms<I: Into<MaybeProp<u32>>>(mut self, value: I) -> Self {
+        self.rest_ms = value.into();
+        self
+    }
+
+    /// Set `mouse_only` option.
+    pub fn mouse_only<I: Into<MaybeProp<bool>>>(mut self, value: I) -> Self {
+        self.mouse_only = value.into();
+        self
+    }
+
+    /// Set `safe_polygon` option.
+    pub fn safe_polygon<I: Into<MaybeProp<SafePolygonOptions>>>(mut self, value: I) -> Self {
+        self.safe_polygon = value.into();
+        self
+    }
+}
@@ -0,0 +1,106 @@
+use std::{
+    rc::Rc,
+    sync::{Arc, Mutex},
+};
+
+use floating_ui_dom::{
+    Coords, VirtualElement, track_client_point, virtual_element_from_client_point,
+};
+use leptos::prelude::*;
+use leptos_node_ref::AnyNodeRef;
+use send_wrapper::SendWrapper;
+use web_sys::wasm_bindgen::JsCast;
+
+use crate::types::{UseClientPointOptions, UseClientPointReturn};
+
+/// Positions the floating element at the cursor while the pointer moves over the reference element.
+///
+/// Pass the returned reference to [`use_floating`][crate::use_floating::use_floating].
+pub fn use_client_point(
+    reference: AnyNodeRef,
+    options: UseClientPointOptions,
+) -> UseClientPointReturn {
+    let enabled_option = move || options.enabled.get().unwrap_or(true);
+    let open_option = move || options.open.get().unwrap_or(true);
+    let axis_option = move || options.axis.get().unwrap_or_default();
+    let initial_only_option_untracked =
+        move || options.initial_only.get_untracked().unwrap_or(false);
+
+    let (point, set_point) = signal::<Option<Coords>>(None);
+
+    let reference_element = move || {
+        reference
+            .get()
+            .and_then(|reference| reference.dyn_into::<web_sys::Element>().ok())
+    };
+
+    _ = Effect::watch(
+        open_option,
+        move |open, _, _| {
+            // Capture a new initial point on the next open.
+            if !open {
+                set_point.set(None);
+            }
+        },
+        false,
+    );
+
+    type TrackingCleanupFn = Box<dyn Fn()>;
+    let tracking_cleanup: Arc<Mutex<Option<SendWrapper<TrackingCleanupFn>>>> =
+        Arc::new(Mutex::new(None));
+
+    let cleanup = Arc::new({
+        let tracking_cleanup = tracking_cleanup.clone();
+
+        move || {
+            if let Some(tracking_cleanup) = tracking_cleanup
+                .lock()
+                .expect("Lock should be acquired.")
+                .take()
+            {
+                tracking_cleanup();
+            }
+        }
+    });
+
+    Effect::new({
+        let cleanup = cleanup.clone();
+
+        move |_| {
+            cleanup();
+
+            if enabled_option()
+                && let Some(reference_element) = reference_element()
+            {
+                *tracking_cleanup.lock().expect("Lock should be acquired.") =
+                    Some(SendWrapper::new(track_client_point(
+                        &reference_element,
+                        Rc::new(move |coords| {
+                            if initial_only_option_untracked() && point.get_untracked().is_some() {
+                                return;
+                            }
+
+                            set_point.set(Some(coords));
+                        }),
+                    )));
+            }
+        }
+    });
+
+    on_cleanup(move || {
+        cleanup();
+    });
+
+    UseClientPointReturn {
+        reference: Signal::derive(move || {
+            match (enabled_option(), reference_element(), point.get()) {
+                (true, Some(reference_element), Some(point)) => (Box::new(
+                    virtual_element_from_client_point(&reference_element, point, axis_option()),
+                )
+                    as Box<dyn VirtualElement<web_sys::Element>>)
+                    .into(),
+                _ => reference.into(),
+            }
+        }),
+    }
+}
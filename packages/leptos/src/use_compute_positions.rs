@@ -0,0 +1,112 @@
+use std::rc::Rc;
+
+use floating_ui_dom::{ComputePositionReturn, OwnedElementOrVirtual, try_compute_positions};
+use leptos::prelude::*;
+use send_wrapper::SendWrapper;
+use web_sys::wasm_bindgen::JsCast;
+
+use crate::types::{ComputePositionsEntry, UseComputePositionsReturn};
+
+fn compute_entries(
+    entries: &[ComputePositionsEntry],
+    get_elements: impl Fn(&ComputePositionsEntry) -> Option<(OwnedElementOrVirtual, web_sys::Element)>,
+) -> Vec<Option<ComputePositionReturn>> {
+    let elements: Vec<_> = entries.iter().map(get_elements).collect();
+
+    let mut positions = try_compute_positions(entries.iter().zip(&elements).filter_map(
+        |(entry, elements)| {
+            elements
+                .as_ref()
+                .map(|(reference, floating)| (reference.into(), floating, entry.config()))
+        },
+    ))
+    .into_iter();
+
+    elements
+        .iter()
+        .map(|elements| {
+            elements
+                .as_ref()
+                .and_then(|_| positions.next().and_then(Result::ok))
+        })
+        .collect()
+}
+
+/// Computes the positions of many floating elements in one pass, sharing layout queries between them.
+///
+/// The positions are updated when the entries or their elements change. Call `update` to update the positions manually, e.g. from [`auto_update`][`floating_ui_dom::auto_update`].
+pub fn use_compute_positions(
+    entries: Signal<Vec<ComputePositionsEntry>>,
+) -> UseComputePositionsReturn {
+    let (positions, set_positions) = signal(vec![]);
+
+    Effect::new(move |_| {
+        set_positions.set(entries.with(|entries| {
+            compute_entries(entries, |entry| {
+                Some((
+                    entry.reference.get()?,
+                    entry.floating.get()?.dyn_into::<web_sys::Element>().ok()?,
+                ))
+            })
+        }));
+    });
+
+    let update = Rc::new(move || {
+        set_positions.set(entries.with_untracked(|entries| {
+            compute_entries(entries, |entry| {
+                Some((
+                    entry.reference.get_untracked()?,
+                    entry
+                        .floating
+                        .get_untracked()?
+                        .dyn_into::<web_sys::Element>()
+                        .ok()?,
+                ))
+            })
+        }));
+    });
+
+    UseComputePositionsReturn {
+        positions: positions.into(),
+        update: SendWrapper::new(update),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use floating_ui_dom::{Offset, OffsetOptions, Placement, Strategy};
+    use leptos_node_ref::AnyNodeRef;
+    use send_wrapper::SendWrapper;
+
+    use super::*;
+
+    #[test]
+    fn maps_entry_options_to_config() {
+        let entry = ComputePositionsEntry::new(AnyNodeRef::new(), AnyNodeRef::new())
+            .placement(Placement::TopStart)
+            .strategy(Strategy::Fixed)
+            .middleware(SendWrapper::new(vec![Box::new(Offset::new(
+                OffsetOptions::Value(8.0),
+            ))]));
+
+        let config = entry.config();
+
+        assert_eq!(config.placement, Some(Placement::TopStart));
+        assert_eq!(config.strategy, Some(Strategy::Fixed));
+        assert_eq!(
+            config.middleware.map(|middleware| middleware.len()),
+            Some(1)
+        );
+        assert!(config.cache.is_none());
+    }
+
+    #[test]
+    fn skips_entries_without_elements() {
+        let entries = vec![
+            ComputePositionsEntry::new(AnyNodeRef::new(), AnyNodeRef::new()),
+            ComputePositionsEntry::new(AnyNodeRef::new(), AnyNodeRef::new()),
+        ];
+
+        assert_eq!(compute_entries(&entries, |_| None), vec![None, None]);
+    }
+}
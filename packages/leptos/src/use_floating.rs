@@ -6,7 +6,8 @@ use std::{
 
 use floating_ui_dom::{
     ComputePositionConfig, MiddlewareData, OwnedElementOrVirtual, Placement, Strategy,
-    VirtualElement, compute_position,
+    TransformOriginData, VirtualElement, hide_popover, remove_popover, show_popover,
+    try_compute_position, while_popover_open,
 };
 use leptos::{html::ElementType, prelude::*};
 use leptos_node_ref::AnyNodeRef;
@@ -154,17 +155,25 @@ pub fn use_floating<R: Into<Reference>>(
     let reference: Reference = reference.into();
 
     let open_option = Signal::derive(move || options.open.get().unwrap_or(true));
+    let popover_option = move || options.popover.get().unwrap_or(false);
+    let popover_option_untracked = move || options.popover.get_untracked().unwrap_or(false);
     let placement_option_untracked = move || {
         options
             .placement
             .get_untracked()
             .unwrap_or(Placement::Bottom)
     };
+    let logical_placement_option_untracked = move || options.logical_placement.get_untracked();
     let strategy_option_untracked = move || {
-        options
-            .strategy
-            .get_untracked()
-            .unwrap_or(Strategy::Absolute)
+        if popover_option_untracked() {
+            // The containing block of elements in the top layer is the viewport.
+            Strategy::Fixed
+        } else {
+            options
+                .strategy
+                .get_untracked()
+                .unwrap_or(Strategy::Absolute)
+        }
     };
     let middleware_option_untracked = move || options.middleware.get_untracked();
     let transform_option = move || options.transform.get().unwrap_or(true);
@@ -179,10 +188,17 @@ pub fn use_floating<R: Into<Reference>>(
     let floating_styles = Memo::new(move |_| {
         let initial_styles = FloatingStyles {
             position: strategy.get(),
+            margin: popover_option().then(|| "0".to_owned()),
+            inset: popover_option().then(|| "auto".to_owned()),
             top: "0".to_owned(),
             left: "0".to_owned(),
             transform: None,
             will_change: None,
+            transform_origin: middleware_data.with(|middleware_data| {
+                middleware_data
+                    .get_typed::<TransformOriginData>()
+                    .map(TransformOriginData::transform_origin)
+            }),
         };
 
         match floating
@@ -222,15 +238,22 @@ pub fn use_floating<R: Into<Reference>>(
             {
                 let config = ComputePositionConfig {
                     placement: Some(placement_option_untracked()),
+                    logical_placement: logical_placement_option_untracked(),
                     strategy: Some(strategy_option_untracked()),
                     middleware: middleware_option_untracked()
                         .map(|middleware| middleware.deref().clone()),
+                    trace: None,
+                    cache: None,
                 };
 
                 let open = open_option.get_untracked();
 
-                let position =
-                    compute_position((&reference_element).into(), &floating_element, config);
+                let Ok(position) =
+                    try_compute_position((&reference_element).into(), &floating_element, config)
+                else {
+                    // The elements can not be measured, e.g. because they were removed during teardown.
+                    return;
+                };
                 set_x.set(position.x);
                 set_y.set(position.y);
                 set_strategy.set(position.strategy);
@@ -245,6 +268,58 @@ pub fn use_floating<R: Into<Reference>>(
         }
     });
 
+    // The floating element shown as popover, which is removed from the top layer when it changes or the `popover` option is disabled.
+    let popover_element: Arc<Mutex<Option<SendWrapper<web_sys::Element>>>> =
+        Arc::new(Mutex::new(None));
+
+    let remove_popover_element = Arc::new({
+        let popover_element = popover_element.clone();
+
+        move || {
+            if let Some(popover_element) = popover_element
+                .lock()
+                .expect("Lock should be acquired.")
+                .take()
+            {
+                remove_popover(&popover_element);
+            }
+        }
+    });
+
+    let sync_popover = Arc::new({
+        let remove_popover_element = remove_popover_element.clone();
+
+        move || {
+            let floating_element = floating
+                .get_untracked()
+                .and_then(|floating| floating.dyn_into::<web_sys::Element>().ok());
+
+            if popover_option_untracked()
+                && let Some(floating_element) = floating_element
+            {
+                if popover_element
+                    .lock()
+                    .expect("Lock should be acquired.")
+                    .as_deref()
+                    != Some(&floating_element)
+                {
+                    remove_popover_element();
+                }
+
+                if open_option.get_untracked() {
+                    show_popover(&floating_element);
+                } else {
+                    hide_popover(&floating_element);
+                }
+
+                *popover_element.lock().expect("Lock should be acquired.") =
+                    Some(SendWrapper::new(floating_element));
+            } else {
+                remove_popover_element();
+            }
+        }
+    });
+
     let while_elements_mounted_cleanup: Arc<
         Mutex<Option<SendWrapper<WhileElementsMountedCleanupFn>>>,
     > = Arc::new(Mutex::new(None));
@@ -266,10 +341,12 @@ pub fn use_floating<R: Into<Reference>>(
     let attach = Rc::new({
         let update = update.clone();
         let cleanup = cleanup.clone();
+        let sync_popover = sync_popover.clone();
         let while_elements_mounted_cleanup = while_elements_mounted_cleanup.clone();
 
         move || {
             cleanup();
+            sync_popover();
 
             match while_elements_mounted_untracked() {
                 Some(while_elements_mounted) => {
@@ -279,14 +356,33 @@ pub fn use_floating<R: Into<Reference>>(
                             .get_untracked()
                             .and_then(|floating| floating.dyn_into::<web_sys::Element>().ok())
                     {
-                        *while_elements_mounted_cleanup
-                            .lock()
-                            .expect("Lock should be acquired.") =
-                            Some(SendWrapper::new(while_elements_mounted(
+                        let while_elements_mounted_cleanup_fn = if popover_option_untracked() {
+                            let reference_element = Rc::new(reference_element);
+                            let open_floating_element = floating_element.clone();
+                            let update = update.clone();
+
+                            while_popover_open(
+                                &floating_element,
+                                Rc::new(move || {
+                                    while_elements_mounted(
+                                        (&*reference_element).into(),
+                                        &open_floating_element,
+                                        update.clone(),
+                                    )
+                                }),
+                            )
+                        } else {
+                            while_elements_mounted(
                                 (&reference_element).into(),
                                 &floating_element,
                                 update.clone(),
-                            )));
+                            )
+                        };
+
+                        *while_elements_mounted_cleanup
+                            .lock()
+                            .expect("Lock should be acquired.") =
+                            Some(SendWrapper::new(while_elements_mounted_cleanup_fn));
                     }
                 }
                 _ => {
@@ -347,8 +443,10 @@ pub fn use_floating<R: Into<Reference>>(
         move || open_option.get(),
         {
             let update = update.clone();
+            let sync_popover = sync_popover.clone();
 
             move |_, _, _| {
+                sync_popover();
                 update();
             }
         },
@@ -365,6 +463,17 @@ pub fn use_floating<R: Into<Reference>>(
         },
         false,
     );
+    _ = Effect::watch(
+        move || options.logical_placement.get(),
+        {
+            let update = update.clone();
+
+            move |_, _, _| {
+                update();
+            }
+        },
+        false,
+    );
     _ = Effect::watch(
         move || options.strategy.get(),
         {
@@ -387,6 +496,17 @@ pub fn use_floating<R: Into<Reference>>(
         },
         false,
     );
+    _ = Effect::watch(
+        move || options.popover.get(),
+        {
+            let attach = attach.clone();
+
+            move |_, _, _| {
+                attach();
+            }
+        },
+        false,
+    );
     _ = Effect::watch(
         move || options.while_elements_mounted.get(),
         move |_, _, _| {
@@ -397,6 +517,7 @@ pub fn use_floating<R: Into<Reference>>(
 
     on_cleanup(move || {
         cleanup();
+        remove_popover_element();
     });
 
     UseFloatingReturn {
@@ -0,0 +1,523 @@
+use std::{
+    cell::RefCell,
+    rc::Rc,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use floating_ui_dom::{
+    Coords, Placement, Rect, SafePolygonOptions, get_safe_polygon, get_safe_polygon_trough,
+    is_leaving_opposite_side, is_point_in_polygon,
+};
+use leptos::prelude::*;
+use leptos_node_ref::AnyNodeRef;
+use send_wrapper::SendWrapper;
+use web_sys::{
+    EventTarget, MouseEvent, PointerEvent,
+    wasm_bindgen::{JsCast, closure::Closure},
+};
+
+use crate::types::{HoverDelay, UseHoverOptions};
+
+type CleanupFn = Box<dyn Fn()>;
+
+/// The minimum speed in pixels per millisecond at which the cursor moves with intent.
+const INTENT_SPEED: f64 = 0.1;
+
+/// How long the cursor may rest inside the safe polygon before the floating element closes, in milliseconds.
+const INTENT_TIMEOUT: u64 = 40;
+
+#[derive(Default)]
+struct HoverState {
+    timeout: Option<TimeoutHandle>,
+    rest_timeout: Option<TimeoutHandle>,
+    pointer_type: Option<String>,
+    safe_polygon_cleanup: Option<CleanupFn>,
+}
+
+#[derive(Clone)]
+struct HoverContext {
+    state: Rc<RefCell<HoverState>>,
+    reference: AnyNodeRef,
+    floating: AnyNodeRef,
+    options: UseHoverOptions,
+}
+
+impl HoverContext {
+    fn reference_element(&self) -> Option<web_sys::Element> {
+        self.reference
+            .get_untracked()
+            .and_then(|reference| reference.dyn_into::<web_sys::Element>().ok())
+    }
+
+    fn floating_element(&self) -> Option<web_sys::Element> {
+        self.floating
+            .get_untracked()
+            .and_then(|floating| floating.dyn_into::<web_sys::Element>().ok())
+    }
+
+    fn is_open(&self) -> bool {
+        self.options.open.get_untracked().unwrap_or(false)
+    }
+
+    fn delay(&self) -> HoverDelay {
+        self.options.delay.get_untracked().unwrap_or_default()
+    }
+
+    fn rest_ms(&self) -> u32 {
+        self.options.rest_ms.get_untracked().unwrap_or(0)
+    }
+
+    fn is_ignored_pointer(&self) -> bool {
+        self.options.mouse_only.get_untracked().unwrap_or(false)
+            && self
+                .state
+                .borrow()
+                .pointer_type
+                .as_ref()
+                .is_some_and(|pointer_type| pointer_type != "mouse")
+    }
+
+    fn set_open(&self, open: bool) {
+        if let Some(on_open_change) = self.options.on_open_change {
+            on_open_change.run(open);
+        }
+    }
+
+    fn clear_timeout(&self) {
+        if let Some(timeout) = self.state.borrow_mut().timeout.take() {
+            timeout.clear();
+        }
+    }
+
+    fn clear_rest_timeout(&self) {
+        if let Some(rest_timeout) = self.state.borrow_mut().rest_timeout.take() {
+            rest_timeout.clear();
+        }
+    }
+
+    fn clear_safe_polygon(&self) {
+        // Release the borrow before running the cleanup.
+        let cleanup = self.state.borrow_mut().safe_polygon_cleanup.take();
+        if let Some(cleanup) = cleanup {
+            cleanup();
+        }
+    }
+
+    fn clear(&self) {
+        self.clear_timeout();
+        self.clear_rest_timeout();
+        self.clear_safe_polygon();
+    }
+
+    fn open_with_delay(&self) {
+        self.clear_timeout();
+
+        let open_delay = self.delay().open();
+        if open_delay == 0 {
+            if !self.is_open() {
+                self.set_open(true);
+            }
+        } else {
+            let context = self.clone();
+            self.state.borrow_mut().timeout = set_timeout_with_handle(
+                move || {
+                    if !context.is_open() {
+                        context.set_open(true);
+                    }
+                },
+                Duration::from_millis(open_delay as u64),
+            )
+            .ok();
+        }
+    }
+
+    fn close_with_delay(&self) {
+        self.clear_timeout();
+        self.clear_safe_polygon();
+
+        let close_delay = self.delay().close();
+        if close_delay == 0 {
+            self.set_open(false);
+        } else {
+            let context = self.clone();
+            self.state.borrow_mut().timeout = set_timeout_with_handle(
+                move || context.set_open(false),
+                Duration::from_millis(close_delay as u64),
+            )
+            .ok();
+        }
+    }
+
+    fn handle_reference_enter(&self, event: PointerEvent) {
+        self.state.borrow_mut().pointer_type = Some(event.pointer_type());
+
+        self.clear_timeout();
+        self.clear_safe_polygon();
+
+        if self.is_ignored_pointer() || (self.rest_ms() > 0 && self.delay().open() == 0) {
+            return;
+        }
+
+        self.open_with_delay();
+    }
+
+    fn handle_reference_move(&self) {
+        let rest_ms = self.rest_ms();
+        if rest_ms == 0 || self.is_open() || self.is_ignored_pointer() {
+            return;
+        }
+
+        // A moving cursor does not rest, so restart the timer.
+        self.clear_rest_timeout();
+
+        let context = self.clone();
+        self.state.borrow_mut().rest_timeout = set_timeout_with_handle(
+            move || {
+                if !context.is_open() {
+                    context.set_open(true);
+                }
+            },
+            Duration::from_millis(rest_ms as u64),
+        )
+        .ok();
+    }
+
+    fn handle_reference_leave(&self, event: PointerEvent) {
+        self.clear_rest_timeout();
+
+        if !self.is_open() {
+            // Cancel a pending open.
+            self.clear_timeout();
+            return;
+        }
+
+        if let Some(floating_element) = self.floating_element()
+            && contains_target(&floating_element, event.related_target())
+        {
+            return;
+        }
+
+        match self.options.safe_polygon.get_untracked() {
+            Some(safe_polygon) => self.track_safe_polygon(
+                safe_polygon,
+                Coords {
+                    x: event.client_x() as f64,
+                    y: event.client_y() as f64,
+                },
+            ),
+            None => self.close_with_delay(),
+        }
+    }
+
+    fn handle_floating_enter(&self) {
+        self.clear_timeout();
+    }
+
+    fn handle_floating_leave(&self, event: PointerEvent) {
+        if let Some(reference_element) = self.reference_element()
+            && contains_target(&reference_element, event.related_target())
+        {
+            return;
+        }
+
+        self.close_with_delay();
+    }
+
+    /// Keeps the floating element open while the cursor moves from `leave_point` through the safe polygon towards it.
+    fn track_safe_polygon(&self, options: SafePolygonOptions, leave_point: Coords) {
+        self.clear_safe_polygon();
+
+        let (Some(reference_element), Some(floating_element)) =
+            (self.reference_element(), self.floating_element())
+        else {
+            self.close_with_delay();
+            return;
+        };
+
+        let side = self
+            .options
+            .placement
+            .get_untracked()
+            .unwrap_or(Placement::Bottom)
+            .side();
+
+        if is_leaving_opposite_side(side, leave_point.clone(), &element_rect(&reference_element)) {
+            self.close_with_delay();
+            return;
+        }
+
+        let buffer = options.buffer.unwrap_or(0.5);
+        let require_intent = options.require_intent.unwrap_or(true);
+
+        let has_landed = Rc::new(RefCell::new(false));
+        let last_cursor: Rc<RefCell<Option<(Coords, f64)>>> = Rc::new(RefCell::new(None));
+        let intent_timeout: Rc<RefCell<Option<TimeoutHandle>>> = Rc::new(RefCell::new(None));
+
+        let clear_intent_timeout = {
+            let intent_timeout = intent_timeout.clone();
+
+            move || {
+                if let Some(intent_timeout) = intent_timeout.take() {
+                    intent_timeout.clear();
+                }
+            }
+        };
+
+        let handle_move = {
+            let context = self.clone();
+            let clear_intent_timeout = clear_intent_timeout.clone();
+
+            move |event: MouseEvent| {
+                clear_intent_timeout();
+
+                if !context.is_open() {
+                    context.clear_safe_polygon();
+                    return;
+                }
+
+                let target = event.target();
+                if contains_target(&floating_element, target.clone()) {
+                    has_landed.replace(true);
+                    return;
+                }
+                if contains_target(&reference_element, target) {
+                    return;
+                }
+
+                let point = Coords {
+                    x: event.client_x() as f64,
+                    y: event.client_y() as f64,
+                };
+                let reference_rect = element_rect(&reference_element);
+                let floating_rect = element_rect(&floating_element);
+
+                if is_point_in_polygon(
+                    point.clone(),
+                    &get_safe_polygon_trough(side, &reference_rect, &floating_rect),
+                ) {
+                    return;
+                }
+
+                if *has_landed.borrow() && !is_point_in_rect(&point, &reference_rect) {
+                    context.close_with_delay();
+                    return;
+                }
+
+                if require_intent {
+                    let time = event.time_stamp();
+                    let previous = last_cursor.replace(Some((point.clone(), time)));
+
+                    if let Some((previous_point, previous_time)) = previous {
+                        let elapsed = time - previous_time;
+                        if elapsed > 0.0 {
+                            let distance =
+                                (point.x - previous_point.x).hypot(point.y - previous_point.y);
+                            if distance / elapsed < INTENT_SPEED {
+                                context.close_with_delay();
+                                return;
+                            }
+                        }
+                    }
+                }
+
+                if !is_point_in_polygon(
+                    point,
+                    &get_safe_polygon(
+                        side,
+                        leave_point.clone(),
+                        &floating_rect,
+                        &reference_rect,
+                        buffer,
+                    ),
+                ) {
+                    context.close_with_delay();
+                } else if !*has_landed.borrow() && require_intent {
+                    let context = context.clone();
+                    intent_timeout.replace(
+                        set_timeout_with_handle(
+                            move || context.close_with_delay(),
+                            Duration::from_millis(INTENT_TIMEOUT),
+                        )
+                        .ok(),
+                    );
+                }
+            }
+        };
+
+        let document = document();
+        let remove_listener = add_event_listener(&document, "mousemove", handle_move);
+
+        self.state.borrow_mut().safe_polygon_cleanup = Some(Box::new(move || {
+            remove_listener();
+            clear_intent_timeout();
+        }));
+    }
+}
+
+/// Opens the floating element while the pointer hovers over the reference element.
+///
+/// The open state is controlled: `open` should reflect the state changed through `on_open_change`.
+/// Enable `safe_polygon` to keep the floating element open while the cursor moves from the reference element towards it.
+pub fn use_hover(reference: AnyNodeRef, floating: AnyNodeRef, options: UseHoverOptions) {
+    let enabled_option = move || options.enabled.get().unwrap_or(true);
+    let open_option = move || options.open.get().unwrap_or(false);
+
+    let context = SendWrapper::new(HoverContext {
+        state: Rc::new(RefCell::new(HoverState::default())),
+        reference,
+        floating,
+        options: options.clone(),
+    });
+
+    let reference_cleanup: Arc<Mutex<Option<SendWrapper<CleanupFn>>>> = Arc::new(Mutex::new(None));
+    let floating_cleanup: Arc<Mutex<Option<SendWrapper<CleanupFn>>>> = Arc::new(Mutex::new(None));
+
+    let cleanup_listeners = |listeners_cleanup: &Arc<Mutex<Option<SendWrapper<CleanupFn>>>>| {
+        if let Some(listeners_cleanup) = listeners_cleanup
+            .lock()
+            .expect("Lock should be acquired.")
+            .take()
+        {
+            listeners_cleanup();
+        }
+    };
+
+    Effect::new({
+        let context = context.clone();
+        let reference_cleanup = reference_cleanup.clone();
+
+        move |_| {
+            cleanup_listeners(&reference_cleanup);
+
+            if enabled_option()
+                && let Some(reference_element) = reference
+                    .get()
+                    .and_then(|reference| reference.dyn_into::<web_sys::Element>().ok())
+            {
+                let remove_listeners = [
+                    add_event_listener(&reference_element, "pointerenter", {
+                        let context = (*context).clone();
+                        move |event: PointerEvent| context.handle_reference_enter(event)
+                    }),
+                    add_event_listener(&reference_element, "mousemove", {
+                        let context = (*context).clone();
+                        move |_: MouseEvent| context.handle_reference_move()
+                    }),
+                    add_event_listener(&reference_element, "pointerleave", {
+                        let context = (*context).clone();
+                        move |event: PointerEvent| context.handle_reference_leave(event)
+                    }),
+                ];
+
+                *reference_cleanup.lock().expect("Lock should be acquired.") =
+                    Some(SendWrapper::new(Box::new(move || {
+                        for remove_listener in &remove_listeners {
+                            remove_listener();
+                        }
+                    })));
+            } else {
+                context.clear();
+            }
+        }
+    });
+
+    Effect::new({
+        let context = context.clone();
+        let floating_cleanup = floating_cleanup.clone();
+
+        move |_| {
+            cleanup_listeners(&floating_cleanup);
+
+            if enabled_option()
+                && let Some(floating_element) = floating
+                    .get()
+                    .and_then(|floating| floating.dyn_into::<web_sys::Element>().ok())
+            {
+                let remove_listeners = [
+                    add_event_listener(&floating_element, "pointerenter", {
+                        let context = (*context).clone();
+                        move |_: PointerEvent| context.handle_floating_enter()
+                    }),
+                    add_event_listener(&floating_element, "pointerleave", {
+                        let context = (*context).clone();
+                        move |event: PointerEvent| context.handle_floating_leave(event)
+                    }),
+                ];
+
+                *floating_cleanup.lock().expect("Lock should be acquired.") =
+                    Some(SendWrapper::new(Box::new(move || {
+                        for remove_listener in &remove_listeners {
+                            remove_listener();
+                        }
+                    })));
+            }
+        }
+    });
+
+    _ = Effect::watch(
+        open_option,
+        {
+            let context = context.clone();
+
+            move |open, _, _| {
+                // The floating element was closed by something other than the hover interaction.
+                if !open {
+                    context.clear();
+                }
+            }
+        },
+        false,
+    );
+
+    on_cleanup(move || {
+        cleanup_listeners(&reference_cleanup);
+        cleanup_listeners(&floating_cleanup);
+        context.clear();
+    });
+}
+
+fn add_event_listener<E: JsCast + 'static>(
+    target: &EventTarget,
+    event_type: &'static str,
+    handler: impl Fn(E) + 'static,
+) -> CleanupFn {
+    let closure: Closure<dyn Fn(web_sys::Event)> = Closure::new(move |event: web_sys::Event| {
+        handler(event.unchecked_into::<E>());
+    });
+
+    target
+        .add_event_listener_with_callback(event_type, closure.as_ref().unchecked_ref())
+        .expect("Event listener should be added.");
+
+    let target = target.clone();
+    Box::new(move || {
+        target
+            .remove_event_listener_with_callback(event_type, closure.as_ref().unchecked_ref())
+            .expect("Event listener should be removed.");
+    })
+}
+
+fn contains_target(element: &web_sys::Element, target: Option<EventTarget>) -> bool {
+    target
+        .and_then(|target| target.dyn_into::<web_sys::Node>().ok())
+        .is_some_and(|target| element.contains(Some(&target)))
+}
+
+fn element_rect(element: &web_sys::Element) -> Rect {
+    let rect = element.get_bounding_client_rect();
+
+    Rect {
+        x: rect.x(),
+        y: rect.y(),
+        width: rect.width(),
+        height: rect.height(),
+    }
+}
+
+fn is_point_in_rect(point: &Coords, rect: &Rect) -> bool {
+    point.x >= rect.x
+        && point.x <= rect.x + rect.width
+        && point.y >= rect.y
+        && point.y <= rect.y + rect.height
+}
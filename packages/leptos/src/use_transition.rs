@@ -0,0 +1,139 @@
+use std::time::Duration;
+
+use floating_ui_dom::{
+    Placement, TransitionStatus, TransitionStylesOptions, get_initial_transition_styles,
+    get_transition_styles,
+};
+use leptos::prelude::*;
+use leptos_node_ref::AnyNodeRef;
+
+use crate::types::{
+    UseTransitionStatusOptions, UseTransitionStatusReturn, UseTransitionStylesOptions,
+    UseTransitionStylesReturn,
+};
+
+/// Provides the status of the transition to apply CSS transitions to a floating element, correctly handling placement-aware transitions.
+///
+/// The floating element should only be rendered while `is_mounted` is `true`, so it stays mounted while the close transition plays.
+pub fn use_transition_status(
+    floating: AnyNodeRef,
+    options: UseTransitionStatusOptions,
+) -> UseTransitionStatusReturn {
+    let open_option = move || options.open.get().unwrap_or(true);
+    let close_duration_option = move || options.duration.get().unwrap_or_default().close() as u64;
+
+    let (is_mounted, set_is_mounted) = signal(options.open.get_untracked().unwrap_or(true));
+    let (status, set_status) = signal(TransitionStatus::Unmounted);
+
+    let timeout_handle = StoredValue::new(None::<TimeoutHandle>);
+    let frame_handle = StoredValue::new(None::<AnimationFrameRequestHandle>);
+
+    let clear_timeout = move || {
+        if let Some(timeout_handle) = timeout_handle.try_update_value(Option::take).flatten() {
+            timeout_handle.clear();
+        }
+    };
+    let cancel_frame = move || {
+        if let Some(frame_handle) = frame_handle.try_update_value(Option::take).flatten() {
+            frame_handle.cancel();
+        }
+    };
+
+    // Delay unmounting until the close transition has finished.
+    Effect::new(move |_| {
+        clear_timeout();
+
+        if open_option() {
+            set_is_mounted.set(true);
+        } else if is_mounted.get_untracked() {
+            let handle = set_timeout_with_handle(
+                move || set_is_mounted.set(false),
+                Duration::from_millis(close_duration_option()),
+            )
+            .ok();
+            timeout_handle.set_value(handle);
+        }
+    });
+
+    Effect::new(move |_| {
+        if !is_mounted.get() && status.get_untracked() == TransitionStatus::Close {
+            set_status.set(TransitionStatus::Unmounted);
+        }
+    });
+
+    Effect::new(move |_| {
+        let open = open_option();
+        if floating.get().is_none() {
+            return;
+        }
+
+        cancel_frame();
+
+        if open {
+            set_status.set(TransitionStatus::Initial);
+
+            let handle =
+                request_animation_frame_with_handle(move || set_status.set(TransitionStatus::Open))
+                    .ok();
+            frame_handle.set_value(handle);
+        } else {
+            set_status.set(TransitionStatus::Close);
+        }
+    });
+
+    on_cleanup(move || {
+        clear_timeout();
+        cancel_frame();
+    });
+
+    UseTransitionStatusReturn {
+        is_mounted: Signal::derive(move || open_option() || is_mounted.get()),
+        status: status.into(),
+    }
+}
+
+/// Provides styles to apply CSS transitions to a floating element, correctly handling placement-aware transitions.
+///
+/// The floating element should only be rendered while `is_mounted` is `true`, so it stays mounted while the close transition plays.
+pub fn use_transition_styles(
+    floating: AnyNodeRef,
+    options: UseTransitionStylesOptions,
+) -> UseTransitionStylesReturn {
+    let placement_option = move || options.placement.get().unwrap_or(Placement::Bottom);
+    let styles_option = move || options.styles.get().unwrap_or_default();
+
+    let UseTransitionStatusReturn { is_mounted, status } = use_transition_status(
+        floating,
+        UseTransitionStatusOptions::default()
+            .open(options.open)
+            .duration(MaybeProp::derive(move || {
+                options
+                    .styles
+                    .with(|styles| styles.as_ref().and_then(|styles| styles.duration))
+            })),
+    );
+
+    let (styles, set_styles) = signal(get_initial_transition_styles(
+        options
+            .placement
+            .get_untracked()
+            .unwrap_or(Placement::Bottom),
+        &options.styles.get_untracked().unwrap_or_default(),
+    ));
+
+    Effect::new(move |_| {
+        let status = status.get();
+        let placement = placement_option();
+        let options: TransitionStylesOptions = styles_option();
+
+        set_styles.update(|styles| {
+            *styles = get_transition_styles(status, placement, &options, styles);
+        });
+    });
+
+    UseTransitionStylesReturn {
+        is_mounted,
+        status,
+        styles: styles.into(),
+    }
+}
@@ -1,8 +1,8 @@
 use convert_case::{Case, Casing};
 use floating_ui_leptos::{
-    ARROW_NAME, Arrow, ArrowData, ArrowOptions, DetectOverflowOptions, MiddlewareVec, Offset,
-    OffsetOptions, Padding, Placement, Shift, ShiftOptions, Side, UseFloatingOptions,
-    UseFloatingReturn, use_floating,
+    Arrow, ArrowData, ArrowOptions, DetectOverflowOptions, MiddlewareVec, Offset, OffsetOptions,
+    Padding, Placement, Shift, ShiftOptions, Side, UseFloatingOptions, UseFloatingReturn,
+    use_floating,
 };
 use leptos::prelude::*;
 use leptos_node_ref::AnyNodeRef;
@@ -62,10 +62,7 @@ pub fn Arrow() -> impl IntoView {
 
     let static_side = move || resultant_placement.get().side().opposite();
 
-    let arrow_data = move || {
-        let arrow_data: Option<ArrowData> = middleware_data.get().get_as(ARROW_NAME);
-        arrow_data
-    };
+    let arrow_data = move || middleware_data.get().get_typed::<ArrowData>().cloned();
     let arrow_x = move || arrow_data().and_then(|arrow_data| arrow_data.x);
     let arrow_y = move || arrow_data().and_then(|arrow_data| arrow_data.y);
     let center_offset_value = move || arrow_data().map(|arrow_data| arrow_data.center_offset);
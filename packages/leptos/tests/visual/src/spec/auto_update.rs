@@ -37,6 +37,7 @@ pub fn AutoUpdate() -> impl IntoView {
         element_resize: Some(false),
         layout_shift: None,
         animation_frame: Some(false),
+        cache: None,
     });
     let (reference_size, set_reference_size) = signal(200);
     let (floating_size, set_floating_size) = signal(100);
@@ -1,7 +1,7 @@
 use convert_case::{Case, Casing};
 use floating_ui_leptos::{
-    ApplyState, HIDE_NAME, Hide, HideData, HideOptions, HideStrategy, MiddlewareState,
-    MiddlewareVec, Placement, Shift, ShiftOptions, Size, SizeOptions, Strategy, UseFloatingOptions,
+    ApplyState, Hide, HideData, HideOptions, HideStrategy, MiddlewareState, MiddlewareVec,
+    Placement, Shift, ShiftOptions, Size, SizeOptions, Strategy, UseFloatingOptions,
     UseFloatingReturn, use_floating,
 };
 use leptos::prelude::*;
@@ -95,7 +95,7 @@ pub fn Hide() -> impl IntoView {
             })),
     );
 
-    let hide_data = Signal::derive(move || middleware_data.get().get_as::<HideData>(HIDE_NAME));
+    let hide_data = Signal::derive(move || middleware_data.get().get_typed::<HideData>().cloned());
     let reference_hidden = Signal::derive(move || {
         hide_data
             .get()
@@ -0,0 +1,185 @@
+//! Rust port of [Floating UI](https://floating-ui.com/).
+//!
+//! This is the library to use Floating UI with [Ratatui](https://ratatui.rs/), wrapping [`floating_ui_core`] with terminal cell grid logic.
+//!
+//! See [the Rust Floating UI book](https://floating-ui.rustforweb.org/) for more documenation.
+
+mod middleware;
+mod platform;
+mod types;
+mod widget;
+
+pub use self::platform::Platform;
+pub use crate::middleware::*;
+pub use crate::types::*;
+pub use crate::widget::*;
+pub use floating_ui_core::{
+    ComputePositionError, ComputePositionReturn, Derivable, DerivableFn, DetectOverflowOptions,
+    ElementContext, Middleware, MiddlewareData, MiddlewareDataError, MiddlewareDataValue,
+    MiddlewareReturn, MiddlewareReturnData, MiddlewareState, MiddlewareTraceStep,
+    MiddlewareWithOptions, PlatformError, RootBoundary,
+};
+#[doc(no_inline)]
+pub use floating_ui_utils::{
+    AlignedPlacement, Alignment, Axis, ClientRectObject, Coords, Dimensions, ElementRects, Length,
+    Padding, PartialSideObject, Placement, Side, SideObject, VirtualElement,
+};
+
+use floating_ui_core::{
+    ComputePositionConfig as CoreComputePositionConfig, compute_position as compute_position_core,
+};
+use ratatui::layout::Rect;
+
+/// Options for [`compute_position`].
+#[derive(Clone)]
+pub struct ComputePositionConfig {
+    /// The area the floating element is kept in, usually the area of the terminal frame.
+    pub viewport: Rect,
+
+    /// Where to place the floating element relative to the reference element.
+    ///
+    /// Defaults to [`Placement::Bottom`].
+    pub placement: Option<Placement>,
+
+    /// Vector of middleware objects to modify the positioning or provide data for rendering.
+    ///
+    /// Defaults to an empty vector.
+    pub middleware: Option<MiddlewareVec>,
+
+    /// Whether to record every middleware invocation in [`ComputePositionReturn::trace`].
+    ///
+    /// Defaults to `false`.
+    pub trace: Option<bool>,
+}
+
+impl ComputePositionConfig {
+    pub fn new(viewport: Rect) -> Self {
+        ComputePositionConfig {
+            viewport,
+            placement: None,
+            middleware: None,
+            trace: None,
+        }
+    }
+
+    /// Set `placement` option.
+    pub fn placement(mut self, value: Placement) -> Self {
+        self.placement = Some(value);
+        self
+    }
+
+    /// Set `middleware` option.
+    pub fn middleware(mut self, value: MiddlewareVec) -> Self {
+        self.middleware = Some(value);
+        self
+    }
+
+    /// Set `trace` option.
+    pub fn trace(mut self, value: bool) -> Self {
+        self.trace = Some(value);
+        self
+    }
+}
+
+/// Computes the `x` and `y` coordinates that will place the floating element next to a given reference element.
+///
+/// The coordinates are not rounded, use [`round_to_cells`] to get the area of the floating element.
+pub fn compute_position(
+    reference: ElementOrVirtual,
+    floating: &Rect,
+    config: ComputePositionConfig,
+) -> ComputePositionReturn {
+    let platform = Platform::new(config.viewport);
+
+    compute_position_core(
+        reference,
+        floating,
+        CoreComputePositionConfig {
+            platform: &platform,
+            placement: config.placement,
+            logical_placement: None,
+            strategy: None,
+            middleware: config.middleware,
+            trace: config.trace,
+        },
+    )
+}
+
+/// Rounds the computed position to integer cells and returns the area of the floating element, kept inside the viewport.
+pub fn round_to_cells(x: f64, y: f64, floating: Rect, viewport: Rect) -> Rect {
+    let width = floating.width.min(viewport.width);
+    let height = floating.height.min(viewport.height);
+
+    Rect {
+        x: round_to_cell(x, viewport.x, viewport.right() - width),
+        y: round_to_cell(y, viewport.y, viewport.bottom() - height),
+        width,
+        height,
+    }
+}
+
+fn round_to_cell(value: f64, min: u16, max: u16) -> u16 {
+    value.round().clamp(min as f64, max as f64) as u16
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const VIEWPORT: Rect = Rect {
+        x: 0,
+        y: 0,
+        width: 80,
+        height: 24,
+    };
+
+    #[test]
+    fn test_compute_position() {
+        let reference = Rect::new(10, 5, 20, 1);
+        let floating = Rect::new(0, 0, 10, 4);
+
+        let position = compute_position(
+            (&reference).into(),
+            &floating,
+            ComputePositionConfig::new(VIEWPORT),
+        );
+        assert_eq!(
+            round_to_cells(position.x, position.y, floating, VIEWPORT),
+            Rect::new(15, 6, 10, 4)
+        );
+    }
+
+    #[test]
+    fn test_flip_and_shift() {
+        let reference = Rect::new(75, 22, 4, 1);
+        let floating = Rect::new(0, 0, 10, 4);
+
+        let position = compute_position(
+            (&reference).into(),
+            &floating,
+            ComputePositionConfig::new(VIEWPORT).middleware(vec![
+                Box::new(Flip::new(FlipOptions::default())),
+                Box::new(Shift::new(ShiftOptions::default())),
+            ]),
+        );
+        assert_eq!(position.placement, Placement::Top);
+        assert_eq!(
+            round_to_cells(position.x, position.y, floating, VIEWPORT),
+            Rect::new(70, 18, 10, 4)
+        );
+    }
+
+    #[test]
+    fn test_round_to_cells() {
+        let floating = Rect::new(0, 0, 10, 4);
+
+        assert_eq!(
+            round_to_cells(12.5, 3.4, floating, VIEWPORT),
+            Rect::new(13, 3, 10, 4)
+        );
+        assert_eq!(
+            round_to_cells(-3.0, 30.0, floating, VIEWPORT),
+            Rect::new(0, 20, 10, 4)
+        );
+    }
+}
@@ -0,0 +1,113 @@
+use floating_ui_core::{
+    Boundary, DetectOverflowOptions, GetClippingRectArgs, GetElementRectsArgs, MiddlewareState,
+    Platform as CorePlatform, RootBoundary, detect_overflow,
+};
+use floating_ui_utils::{
+    Dimensions, ElementOrVirtual, ElementRects, Rect as FloatingRect, SideObject,
+};
+use ratatui::layout::Rect;
+
+/// Platform interface methods to work with a terminal cell grid.
+///
+/// Elements are [`Rect`]s in cells. The terminal frame is the viewport and the only clipping ancestor.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Platform {
+    viewport: Rect,
+}
+
+impl Platform {
+    /// Create a platform with the given viewport, usually the area of the terminal frame.
+    pub fn new(viewport: Rect) -> Self {
+        Platform { viewport }
+    }
+
+    /// The viewport of this platform.
+    pub fn viewport(&self) -> Rect {
+        self.viewport
+    }
+}
+
+impl CorePlatform<Rect, ()> for Platform {
+    fn get_element_rects(&self, args: GetElementRectsArgs<Rect>) -> ElementRects {
+        ElementRects {
+            reference: match args.reference {
+                ElementOrVirtual::Element(reference) => to_floating_rect(*reference),
+                ElementOrVirtual::VirtualElement(virtual_element) => {
+                    let rect = virtual_element.get_bounding_client_rect();
+                    FloatingRect {
+                        x: rect.x,
+                        y: rect.y,
+                        width: rect.width,
+                        height: rect.height,
+                    }
+                }
+            },
+            floating: FloatingRect {
+                x: 0.0,
+                y: 0.0,
+                width: args.floating.width as f64,
+                height: args.floating.height as f64,
+            },
+        }
+    }
+
+    fn get_clipping_rect(&self, args: GetClippingRectArgs<Rect>) -> FloatingRect {
+        let root_boundary = match args.root_boundary {
+            RootBoundary::Viewport | RootBoundary::LayoutViewport | RootBoundary::Document => {
+                to_floating_rect(self.viewport)
+            }
+            RootBoundary::Rect(rect) => rect,
+        };
+
+        let clipping_elements = match args.boundary {
+            Boundary::ClippingAncestors => vec![],
+            Boundary::Element(element) => vec![element],
+            Boundary::Elements(elements) => elements,
+        };
+
+        clipping_elements.into_iter().map(to_floating_rect).fold(
+            root_boundary,
+            |accumulator, rect| {
+                let left = accumulator.x.max(rect.x);
+                let top = accumulator.y.max(rect.y);
+                let right = (accumulator.x + accumulator.width).min(rect.x + rect.width);
+                let bottom = (accumulator.y + accumulator.height).min(rect.y + rect.height);
+
+                FloatingRect {
+                    x: left,
+                    y: top,
+                    width: right - left,
+                    height: bottom - top,
+                }
+            },
+        )
+    }
+
+    fn get_dimensions(&self, element: &Rect) -> Dimensions {
+        Dimensions {
+            width: element.width as f64,
+            height: element.height as f64,
+        }
+    }
+
+    fn is_rtl(&self, _element: &Rect) -> Option<bool> {
+        Some(false)
+    }
+
+    fn detect_overflow(
+        &self,
+        state: MiddlewareState<Rect, ()>,
+        options: DetectOverflowOptions<Rect>,
+    ) -> SideObject {
+        detect_overflow(state, options)
+    }
+}
+
+pub(crate) fn to_floating_rect(rect: Rect) -> FloatingRect {
+    FloatingRect {
+        x: rect.x as f64,
+        y: rect.y as f64,
+        width: rect.width as f64,
+        height: rect.height as f64,
+    }
+}
@@ -0,0 +1,111 @@
+use floating_ui_utils::Placement;
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    widgets::{Block, Clear, Widget},
+};
+
+use crate::{ComputePositionConfig, MiddlewareVec, compute_position, round_to_cells};
+
+/// Widget which renders another widget at the position computed for a reference area, e.g. a popup, autocomplete menu or tooltip.
+///
+/// The area passed to [`Widget::render`] is used as viewport. The floating area is cleared before rendering.
+pub struct Floating<'a, W> {
+    reference: Rect,
+    width: u16,
+    height: u16,
+    widget: W,
+    block: Option<Block<'a>>,
+    placement: Option<Placement>,
+    middleware: Option<MiddlewareVec>,
+}
+
+impl<'a, W: Widget> Floating<'a, W> {
+    /// Create a floating widget of the given size, placed next to the reference area.
+    pub fn new(reference: Rect, width: u16, height: u16, widget: W) -> Self {
+        Floating {
+            reference,
+            width,
+            height,
+            widget,
+            block: None,
+            placement: None,
+            middleware: None,
+        }
+    }
+
+    /// Set the block which is rendered around the widget. The size of the floating widget includes the block.
+    pub fn block(mut self, value: Block<'a>) -> Self {
+        self.block = Some(value);
+        self
+    }
+
+    /// Set `placement` option.
+    pub fn placement(mut self, value: Placement) -> Self {
+        self.placement = Some(value);
+        self
+    }
+
+    /// Set `middleware` option.
+    pub fn middleware(mut self, value: MiddlewareVec) -> Self {
+        self.middleware = Some(value);
+        self
+    }
+
+    /// Computes the area of the floating widget in the given viewport.
+    pub fn area(&self, viewport: Rect) -> Rect {
+        let floating = Rect::new(0, 0, self.width, self.height);
+
+        let mut config = ComputePositionConfig::new(viewport);
+        config.placement = self.placement;
+        config.middleware = self.middleware.clone();
+
+        let position = compute_position((&self.reference).into(), &floating, config);
+        round_to_cells(position.x, position.y, floating, viewport)
+    }
+}
+
+impl<W: Widget> Widget for Floating<'_, W> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let area = self.area(area);
+        Clear.render(area, buf);
+
+        match self.block {
+            Some(block) => {
+                let inner = block.inner(area);
+                block.render(area, buf);
+                self.widget.render(inner, buf);
+            }
+            None => self.widget.render(area, buf),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::widgets::Paragraph;
+
+    use super::*;
+
+    #[test]
+    fn test_render() {
+        let area = Rect::new(0, 0, 20, 6);
+        let mut buf = Buffer::empty(area);
+
+        Floating::new(Rect::new(2, 1, 4, 1), 6, 3, Paragraph::new("Hi"))
+            .block(Block::bordered())
+            .render(area, &mut buf);
+
+        assert_eq!(
+            buf,
+            Buffer::with_lines([
+                "                    ",
+                "                    ",
+                " ┌────┐             ",
+                " │Hi  │             ",
+                " └────┘             ",
+                "                    ",
+            ])
+        );
+    }
+}
@@ -0,0 +1,288 @@
+//! Rust port of [Floating UI](https://floating-ui.com/).
+//!
+//! This is the library to use Floating UI with [Taffy](https://github.com/DioxusLabs/taffy) layout trees, wrapping [`floating_ui_core`] with layout tree logic.
+//!
+//! See [the Rust Floating UI book](https://floating-ui.rustforweb.org/) for more documenation.
+
+mod middleware;
+mod platform;
+mod types;
+
+use std::fmt::Debug;
+
+pub use self::platform::Platform;
+pub use crate::middleware::*;
+pub use crate::types::*;
+pub use floating_ui_core::{
+    ComputePositionError, ComputePositionReturn, Derivable, DerivableFn, DetectOverflowOptions,
+    ElementContext, Middleware, MiddlewareData, MiddlewareDataError, MiddlewareDataValue,
+    MiddlewareReturn, MiddlewareReturnData, MiddlewareState, MiddlewareTraceStep,
+    MiddlewareWithOptions, PlatformError, RootBoundary,
+};
+#[doc(no_inline)]
+pub use floating_ui_utils::{
+    AlignedPlacement, Alignment, Axis, ClientRectObject, Coords, Dimensions, ElementRects, Length,
+    Padding, PartialSideObject, Placement, Rect, Side, SideObject, VirtualElement,
+};
+
+use floating_ui_core::{
+    ComputePositionConfig as CoreComputePositionConfig, compute_position as compute_position_core,
+};
+use taffy::{NodeId, TaffyTree};
+
+/// Options for [`compute_position`].
+#[derive(Clone, Default)]
+pub struct ComputePositionConfig {
+    /// The area the floating node is kept in.
+    ///
+    /// Defaults to the border box of the root of the tree.
+    pub viewport: Option<Rect>,
+
+    /// Where to place the floating node relative to the reference node.
+    ///
+    /// Defaults to [`Placement::Bottom`].
+    pub placement: Option<Placement>,
+
+    /// Vector of middleware objects to modify the positioning or provide data for rendering.
+    ///
+    /// Defaults to an empty vector.
+    pub middleware: Option<MiddlewareVec>,
+
+    /// Whether to record every middleware invocation in [`ComputePositionReturn::trace`].
+    ///
+    /// Defaults to `false`.
+    pub trace: Option<bool>,
+}
+
+impl ComputePositionConfig {
+    /// Set `viewport` option.
+    pub fn viewport(mut self, value: Rect) -> Self {
+        self.viewport = Some(value);
+        self
+    }
+
+    /// Set `placement` option.
+    pub fn placement(mut self, value: Placement) -> Self {
+        self.placement = Some(value);
+        self
+    }
+
+    /// Set `middleware` option.
+    pub fn middleware(mut self, value: MiddlewareVec) -> Self {
+        self.middleware = Some(value);
+        self
+    }
+
+    /// Set `trace` option.
+    pub fn trace(mut self, value: bool) -> Self {
+        self.trace = Some(value);
+        self
+    }
+}
+
+/// Computes the `x` and `y` coordinates that will place the floating node next to a given reference node.
+///
+/// The coordinates are relative to the padding box of the parent of the floating node, so they can be used as `inset` of an absolutely positioned node.
+/// The layout of the tree must be computed first and all nodes must exist in the tree.
+pub fn compute_position<NodeContext: Debug>(
+    tree: &TaffyTree<NodeContext>,
+    reference: ElementOrVirtual,
+    floating: &NodeId,
+    config: ComputePositionConfig,
+) -> ComputePositionReturn {
+    let platform = platform(tree, config.viewport.clone());
+
+    compute_position_core(
+        reference,
+        floating,
+        CoreComputePositionConfig {
+            platform: &platform,
+            placement: config.placement,
+            logical_placement: None,
+            strategy: None,
+            middleware: config.middleware,
+            trace: config.trace,
+        },
+    )
+}
+
+fn platform<NodeContext: Debug>(
+    tree: &TaffyTree<NodeContext>,
+    viewport: Option<Rect>,
+) -> Platform<'_, NodeContext> {
+    match viewport {
+        Some(viewport) => Platform::with_viewport(tree, viewport),
+        None => Platform::new(tree),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use taffy::{
+        AvailableSpace, Overflow, Point, Position, Size, Style,
+        prelude::{TaffyAuto, length},
+    };
+
+    use super::*;
+
+    struct Tree {
+        tree: TaffyTree,
+        container: NodeId,
+        reference: NodeId,
+        floating: NodeId,
+    }
+
+    fn tree(reference_top: f32) -> Tree {
+        let mut tree: TaffyTree = TaffyTree::new();
+
+        let reference = tree
+            .new_leaf(Style {
+                position: Position::Absolute,
+                inset: taffy::Rect {
+                    left: length(50.0),
+                    top: length(reference_top),
+                    right: taffy::LengthPercentageAuto::AUTO,
+                    bottom: taffy::LengthPercentageAuto::AUTO,
+                },
+                size: Size {
+                    width: length(100.0),
+                    height: length(20.0),
+                },
+                ..Default::default()
+            })
+            .unwrap();
+        let floating = tree
+            .new_leaf(Style {
+                position: Position::Absolute,
+                size: Size {
+                    width: length(60.0),
+                    height: length(40.0),
+                },
+                ..Default::default()
+            })
+            .unwrap();
+        let container = tree
+            .new_with_children(
+                Style {
+                    position: Position::Absolute,
+                    inset: taffy::Rect {
+                        left: length(100.0),
+                        top: length(100.0),
+                        right: taffy::LengthPercentageAuto::AUTO,
+                        bottom: taffy::LengthPercentageAuto::AUTO,
+                    },
+                    size: Size {
+                        width: length(300.0),
+                        height: length(200.0),
+                    },
+                    border: taffy::Rect {
+                        left: length(5.0),
+                        right: length(5.0),
+                        top: length(5.0),
+                        bottom: length(5.0),
+                    },
+                    overflow: Point {
+                        x: Overflow::Hidden,
+                        y: Overflow::Hidden,
+                    },
+                    ..Default::default()
+                },
+                &[reference, floating],
+            )
+            .unwrap();
+        let root = tree
+            .new_with_children(
+                Style {
+                    size: Size {
+                        width: length(800.0),
+                        height: length(600.0),
+                    },
+                    ..Default::default()
+                },
+                &[container],
+            )
+            .unwrap();
+
+        tree.compute_layout(
+            root,
+            Size {
+                width: AvailableSpace::Definite(800.0),
+                height: AvailableSpace::Definite(600.0),
+            },
+        )
+        .unwrap();
+
+        Tree {
+            tree,
+            container,
+            reference,
+            floating,
+        }
+    }
+
+    #[test]
+    fn test_compute_position() {
+        let Tree {
+            tree,
+            reference,
+            floating,
+            ..
+        } = tree(10.0);
+
+        let position = compute_position(
+            &tree,
+            (&reference).into(),
+            &floating,
+            ComputePositionConfig::default(),
+        );
+        assert_eq!((position.x, position.y), (70.0, 30.0));
+    }
+
+    #[test]
+    fn test_clipping_ancestors() {
+        let Tree {
+            tree,
+            container,
+            reference,
+            floating,
+        } = tree(150.0);
+
+        let platform = Platform::new(&tree);
+        assert_eq!(
+            floating_ui_core::Platform::get_clipping_rect(
+                &platform,
+                floating_ui_core::GetClippingRectArgs {
+                    element: &floating,
+                    boundary: Boundary::ClippingAncestors,
+                    root_boundary: RootBoundary::Viewport,
+                    strategy: floating_ui_utils::Strategy::Absolute,
+                }
+            ),
+            Rect {
+                x: 105.0,
+                y: 105.0,
+                width: 290.0,
+                height: 190.0,
+            }
+        );
+        assert_eq!(
+            platform.get_bounding_client_rect(container),
+            Rect {
+                x: 100.0,
+                y: 100.0,
+                width: 300.0,
+                height: 200.0,
+            }
+        );
+
+        let position = compute_position(
+            &tree,
+            (&reference).into(),
+            &floating,
+            ComputePositionConfig::default()
+                .middleware(vec![Box::new(Flip::new(FlipOptions::default()))]),
+        );
+        assert_eq!(position.placement, Placement::Top);
+        assert_eq!((position.x, position.y), (70.0, 110.0));
+    }
+}
@@ -0,0 +1,98 @@
+use floating_ui_core::middleware::{
+    Arrow as CoreArrow, AutoPlacement as CoreAutoPlacement, Flip as CoreFlip, Hide as CoreHide,
+    Inline as CoreInline, Inner as CoreInner, MiddlewareSpec as CoreMiddlewareSpec,
+    Offset as CoreOffset, Shift as CoreShift, Size as CoreSize,
+    TransformOrigin as CoreTransformOrigin,
+};
+use taffy::NodeId;
+
+pub use floating_ui_core::middleware::{
+    ARROW_NAME, AUTO_PLACEMENT_NAME, ApplyState, ArrowData, ArrowOptions, AutoPlacementData,
+    AutoPlacementDataOverflow, AutoPlacementOptions, CrossAxis, DefaultLimiter, FLIP_NAME,
+    FallbackStrategy, FlipData, FlipDataOverflow, FlipOptions, HIDE_NAME, HideData, HideOptions,
+    HideStrategy, INLINE_NAME, INNER_NAME, InlineOptions, InnerData, InnerListState, InnerOptions,
+    LimitShift, LimitShiftOffset, LimitShiftOffsetValues, LimitShiftOptions, OFFSET_NAME,
+    OffsetData, OffsetOptions, OffsetOptionsValues, SHIFT_NAME, SIZE_NAME, ShiftData, ShiftOptions,
+    SizeOptions, TRANSFORM_ORIGIN_NAME, TransformOriginData, TransformOriginOptions,
+};
+
+/// Arrow middleware.
+///
+/// Provides data to position an inner element of the floating element so that it appears centered to the reference element.
+///
+/// See [the Rust Floating UI book](https://floating-ui.rustforweb.org/middleware/arrow.html) for more documentation.
+pub type Arrow<'a> = CoreArrow<'a, NodeId, ()>;
+
+/// Auto placement middleware.
+///
+/// Optimizes the visibility of the floating element by choosing the placement that has the most space available automatically,
+/// without needing to specify a preferred placement.
+///
+/// Alternative to [`Flip`].
+///
+/// See [the Rust Floating UI book](https://floating-ui.rustforweb.org/middleware/auto-placement.html) for more documentation.
+pub type AutoPlacement<'a> = CoreAutoPlacement<'a, NodeId, ()>;
+
+/// Flip middleware.
+///
+/// Optimizes the visibility of the floating element by flipping the `placement` in order to keep it in view when the preferred placement(s) will overflow the clipping boundary.
+/// Alternative to [`AutoPlacement`].
+///
+/// See [the Rust Floating UI book](https://floating-ui.rustforweb.org/middleware/flip.html) for more documentation.
+pub type Flip<'a> = CoreFlip<'a, NodeId, ()>;
+
+/// Hide middleware.
+///
+/// Provides data to hide the floating element in applicable situations,
+/// such as when it is not in the same clipping context as the reference element.
+///
+/// See [the Rust Floating UI book](https://floating-ui.rustforweb.org/middleware/hide.html) for more documentation.
+pub type Hide<'a> = CoreHide<'a, NodeId, ()>;
+
+/// Inline middleware.
+///
+/// Provides improved positioning for inline reference elements that can span over multiple lines, such as hyperlinks or range selections.
+///
+/// See [the Rust Floating UI book](https://floating-ui.rustforweb.org/middleware/inline.html) for more documentation.
+pub type Inline<'a> = CoreInline<'a, NodeId, ()>;
+
+/// Inner middleware.
+///
+/// Positions the floating element so that the selected item of a list inside it appears over the reference element,
+/// such as in macOS-style select menus.
+///
+/// See [the Rust Floating UI book](https://floating-ui.rustforweb.org/middleware/inner.html) for more documentation.
+pub type Inner<'a> = CoreInner<'a, NodeId, ()>;
+
+/// Offset middleware.
+///
+/// Modifies the placement by translating the floating element along the specified axes.
+///
+/// See [the Rust Floating UI book](https://floating-ui.rustforweb.org/middleware/offset.html) for more documentation.
+pub type Offset<'a> = CoreOffset<'a, NodeId, ()>;
+
+/// Shift middleware.
+///
+/// Optimizes the visibility of the floating element by shifting it in order to keep it in view when it will overflow the clipping boundary.
+///
+/// See [the Rust Floating UI book](https://floating-ui.rustforweb.org/middleware/shift.html) for more documentation.
+pub type Shift<'a> = CoreShift<'a, NodeId, ()>;
+
+/// Size middleware.
+///
+/// Provides data that allows you to change the size of the floating element -
+/// for instance, prevent it from overflowing the clipping boundary or match the width of the reference element.
+///
+/// See [the Rust Floating UI book](https://floating-ui.rustforweb.org/middleware/size.html) for more documentation.
+pub type Size<'a> = CoreSize<'a, NodeId, ()>;
+
+/// Transform origin middleware.
+///
+/// Provides data to set the `transform-origin` of the floating element to the side facing the reference element,
+/// or to the tip of the arrow element if it is centered.
+///
+/// See [the Rust Floating UI book](https://floating-ui.rustforweb.org/middleware/transform-origin.html) for more documentation.
+pub type TransformOrigin<'a> = CoreTransformOrigin<'a, NodeId, ()>;
+
+/// Declarative description of a middleware, e.g. stored in a configuration file.
+pub type MiddlewareSpec = CoreMiddlewareSpec<NodeId, ()>;
@@ -0,0 +1,287 @@
+use std::fmt::Debug;
+
+use floating_ui_core::{
+    Boundary, ConvertOffsetParentRelativeRectToViewportRelativeRectArgs, DetectOverflowOptions,
+    GetClippingRectArgs, GetElementRectsArgs, MiddlewareState, Platform as CorePlatform,
+    RootBoundary, detect_overflow,
+};
+use floating_ui_utils::{
+    ClientRectObject, Coords, Dimensions, ElementOrVirtual, ElementOrWindow, ElementRects, Length,
+    OwnedElementOrWindow, Rect, SideObject,
+};
+use taffy::{Layout, NodeId, Overflow, TaffyTree};
+
+/// Platform interface methods to work with a [`TaffyTree`].
+///
+/// Elements are nodes of the tree, measured by their computed [`Layout`]. Nodes are positioned relative to their parent, so the parent of the floating node is its offset parent.
+/// Nodes with an overflow style other than [`Overflow::Visible`] are clipping ancestors. Taffy does not store scroll offsets, so all nodes are unscrolled.
+///
+/// All nodes must exist in the tree, Taffy panics when a removed node is measured.
+#[derive(Debug)]
+pub struct Platform<'a, NodeContext: Debug = ()> {
+    tree: &'a TaffyTree<NodeContext>,
+    viewport: Option<Rect>,
+}
+
+impl<'a, NodeContext: Debug> Platform<'a, NodeContext> {
+    /// Create a platform for the given tree. The viewport is the border box of the root of the tree.
+    pub fn new(tree: &'a TaffyTree<NodeContext>) -> Self {
+        Platform {
+            tree,
+            viewport: None,
+        }
+    }
+
+    /// Create a platform for the given tree and viewport.
+    pub fn with_viewport(tree: &'a TaffyTree<NodeContext>, viewport: Rect) -> Self {
+        Platform {
+            tree,
+            viewport: Some(viewport),
+        }
+    }
+
+    /// The border box of the node, relative to the root of the tree.
+    pub fn get_bounding_client_rect(&self, node: NodeId) -> Rect {
+        let layout = self.layout(node);
+        let origin = self.origin(node);
+
+        Rect {
+            x: origin.x,
+            y: origin.y,
+            width: layout.size.width as f64,
+            height: layout.size.height as f64,
+        }
+    }
+
+    fn layout(&self, node: NodeId) -> &Layout {
+        self.tree
+            .layout(node)
+            .expect("Node should exist in the tree.")
+    }
+
+    fn ancestors(&self, node: NodeId) -> impl Iterator<Item = NodeId> + '_ {
+        let mut current = self.tree.parent(node);
+        std::iter::from_fn(move || {
+            let node = current?;
+            current = self.tree.parent(node);
+            Some(node)
+        })
+    }
+
+    fn root(&self, node: NodeId) -> NodeId {
+        self.ancestors(node).last().unwrap_or(node)
+    }
+
+    fn origin(&self, node: NodeId) -> Coords {
+        std::iter::once(node)
+            .chain(self.ancestors(node))
+            .map(|node| self.layout(node).location)
+            .fold(Coords::new(0.0), |origin, location| Coords {
+                x: origin.x + location.x as f64,
+                y: origin.y + location.y as f64,
+            })
+    }
+
+    /// Origin of the padding box, which absolutely positioned children are placed relative to.
+    fn padding_box_origin(&self, node: NodeId) -> Coords {
+        let layout = self.layout(node);
+        let origin = self.origin(node);
+
+        Coords {
+            x: origin.x + layout.border.left as f64,
+            y: origin.y + layout.border.top as f64,
+        }
+    }
+
+    fn client_rect(&self, node: NodeId) -> Rect {
+        let layout = self.layout(node);
+        let origin = self.padding_box_origin(node);
+
+        Rect {
+            x: origin.x,
+            y: origin.y,
+            width: (layout.size.width
+                - layout.border.left
+                - layout.border.right
+                - layout.scrollbar_size.width) as f64,
+            height: (layout.size.height
+                - layout.border.top
+                - layout.border.bottom
+                - layout.scrollbar_size.height) as f64,
+        }
+    }
+
+    fn element_or_virtual_rect(&self, element: ElementOrVirtual<NodeId>) -> Rect {
+        match element {
+            ElementOrVirtual::Element(element) => self.get_bounding_client_rect(*element),
+            ElementOrVirtual::VirtualElement(virtual_element) => {
+                let rect = virtual_element.get_bounding_client_rect();
+                Rect {
+                    x: rect.x,
+                    y: rect.y,
+                    width: rect.width,
+                    height: rect.height,
+                }
+            }
+        }
+    }
+}
+
+impl<NodeContext: Debug> CorePlatform<NodeId, ()> for Platform<'_, NodeContext> {
+    fn get_element_rects(&self, args: GetElementRectsArgs<NodeId>) -> ElementRects {
+        let rect = self.element_or_virtual_rect(args.reference);
+        let dimensions = self.get_dimensions(args.floating);
+
+        ElementRects {
+            reference: match self.tree.parent(*args.floating) {
+                Some(offset_parent) => {
+                    let origin = self.padding_box_origin(offset_parent);
+                    Rect {
+                        x: rect.x - origin.x,
+                        y: rect.y - origin.y,
+                        ..rect
+                    }
+                }
+                None => rect,
+            },
+            floating: Rect {
+                x: 0.0,
+                y: 0.0,
+                width: dimensions.width,
+                height: dimensions.height,
+            },
+        }
+    }
+
+    fn get_clipping_rect(&self, args: GetClippingRectArgs<NodeId>) -> Rect {
+        let root_boundary = match args.root_boundary {
+            RootBoundary::Viewport | RootBoundary::LayoutViewport => self
+                .viewport
+                .clone()
+                .unwrap_or_else(|| self.get_bounding_client_rect(self.root(*args.element))),
+            RootBoundary::Document => self.get_bounding_client_rect(self.root(*args.element)),
+            RootBoundary::Rect(rect) => rect,
+        };
+
+        let clipping_elements: Vec<(NodeId, bool, bool)> = match args.boundary {
+            Boundary::ClippingAncestors => self
+                .ancestors(*args.element)
+                .filter_map(|node| {
+                    let overflow = self
+                        .tree
+                        .style(node)
+                        .expect("Node should exist in the tree.")
+                        .overflow;
+                    let clip_x = overflow.x != Overflow::Visible;
+                    let clip_y = overflow.y != Overflow::Visible;
+
+                    (clip_x || clip_y).then_some((node, clip_x, clip_y))
+                })
+                .collect(),
+            Boundary::Element(element) => vec![(element, true, true)],
+            Boundary::Elements(elements) => elements
+                .into_iter()
+                .map(|element| (element, true, true))
+                .collect(),
+        };
+
+        clipping_elements
+            .into_iter()
+            .fold(root_boundary, |accumulator, (node, clip_x, clip_y)| {
+                let rect = self.client_rect(node);
+
+                let (x, width) = match clip_x {
+                    true => intersect(accumulator.x, accumulator.width, rect.x, rect.width),
+                    false => (accumulator.x, accumulator.width),
+                };
+                let (y, height) = match clip_y {
+                    true => intersect(accumulator.y, accumulator.height, rect.y, rect.height),
+                    false => (accumulator.y, accumulator.height),
+                };
+
+                Rect {
+                    x,
+                    y,
+                    width,
+                    height,
+                }
+            })
+    }
+
+    fn get_dimensions(&self, element: &NodeId) -> Dimensions {
+        let layout = self.layout(*element);
+        Dimensions {
+            width: layout.size.width as f64,
+            height: layout.size.height as f64,
+        }
+    }
+
+    fn convert_offset_parent_relative_rect_to_viewport_relative_rect(
+        &self,
+        args: ConvertOffsetParentRelativeRectToViewportRelativeRectArgs<NodeId, ()>,
+    ) -> Option<Rect> {
+        Some(match args.offset_parent {
+            Some(ElementOrWindow::Element(offset_parent)) => {
+                let origin = self.padding_box_origin(*offset_parent);
+                Rect {
+                    x: args.rect.x + origin.x,
+                    y: args.rect.y + origin.y,
+                    ..args.rect
+                }
+            }
+            Some(ElementOrWindow::Window(_)) | None => args.rect,
+        })
+    }
+
+    fn get_offset_parent(&self, element: &NodeId) -> Option<OwnedElementOrWindow<NodeId, ()>> {
+        Some(match self.tree.parent(*element) {
+            Some(parent) => OwnedElementOrWindow::Element(parent),
+            None => OwnedElementOrWindow::Window(()),
+        })
+    }
+
+    fn get_document_element(&self, element: &NodeId) -> Option<NodeId> {
+        Some(self.root(*element))
+    }
+
+    fn get_client_rects(&self, element: ElementOrVirtual<NodeId>) -> Option<Vec<ClientRectObject>> {
+        Some(match element {
+            ElementOrVirtual::Element(element) => {
+                vec![self.get_bounding_client_rect(*element).into()]
+            }
+            ElementOrVirtual::VirtualElement(virtual_element) => virtual_element
+                .get_client_rects()
+                .unwrap_or_else(|| vec![virtual_element.get_bounding_client_rect()]),
+        })
+    }
+
+    fn is_rtl(&self, _element: &NodeId) -> Option<bool> {
+        Some(false)
+    }
+
+    fn get_scale(&self, _element: &NodeId) -> Option<Coords> {
+        Some(Coords::new(1.0))
+    }
+
+    fn get_client_length(&self, element: &NodeId, length: Length) -> Option<f64> {
+        let rect = self.client_rect(*element);
+        Some(match length {
+            Length::Width => rect.width,
+            Length::Height => rect.height,
+        })
+    }
+
+    fn detect_overflow(
+        &self,
+        state: MiddlewareState<NodeId, ()>,
+        options: DetectOverflowOptions<NodeId>,
+    ) -> SideObject {
+        detect_overflow(state, options)
+    }
+}
+
+fn intersect(a_start: f64, a_length: f64, b_start: f64, b_length: f64) -> (f64, f64) {
+    let start = a_start.max(b_start);
+    let end = (a_start + a_length).min(b_start + b_length);
+    (start, end - start)
+}
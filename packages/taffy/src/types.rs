@@ -0,0 +1,15 @@
+use floating_ui_core::{Boundary as CoreBoundary, Middleware};
+use floating_ui_utils::{
+    DefaultVirtualElement as CoreDefaultVirtualElement, ElementOrVirtual as CoreElementOrVirtual,
+    OwnedElementOrVirtual as CoreOwnedElementOrVirtual,
+};
+use taffy::NodeId;
+
+pub type Boundary = CoreBoundary<NodeId>;
+
+pub type DefaultVirtualElement = CoreDefaultVirtualElement<NodeId>;
+pub type ElementOrVirtual<'a> = CoreElementOrVirtual<'a, NodeId>;
+pub type OwnedElementOrVirtual = CoreOwnedElementOrVirtual<NodeId>;
+
+/// Vector of middleware used in [`ComputePositionConfig`][`crate::ComputePositionConfig`].
+pub type MiddlewareVec = Vec<Box<dyn Middleware<NodeId, ()>>>;
@@ -105,6 +105,10 @@ pub fn get_node_name(node_or_window: DomNodeOrWindow) -> String {
 }
 
 pub fn get_window(node: Option<&Node>) -> Window {
+    try_get_window(node).expect("Window should exist.")
+}
+
+pub fn try_get_window(node: Option<&Node>) -> Option<Window> {
     match node {
         Some(node) => match node.owner_document() {
             Some(document) => document.default_view(),
@@ -112,20 +116,20 @@ pub fn get_window(node: Option<&Node>) -> Window {
         },
         None => window(),
     }
-    .expect("Window should exist.")
 }
 
 pub fn get_document_element(node_or_window: Option<DomNodeOrWindow>) -> Element {
+    try_get_document_element(node_or_window).expect("Document should have document element.")
+}
+
+pub fn try_get_document_element(node_or_window: Option<DomNodeOrWindow>) -> Option<Element> {
     let document = match node_or_window {
         Some(DomNodeOrWindow::Node(node)) => node.owner_document(),
         Some(DomNodeOrWindow::Window(window)) => window.document(),
-        None => get_window(None).document(),
-    }
-    .expect("Node or window should have document.");
+        None => try_get_window(None)?.document(),
+    }?;
 
-    document
-        .document_element()
-        .expect("Document should have document element.")
+    document.document_element()
 }
 
 pub fn is_element(node: &Node) -> bool {
@@ -139,7 +143,11 @@ pub fn is_html_element(node: &Node) -> bool {
 const OVERFLOW_VALUES: [&str; 5] = ["auto", "scroll", "overlay", "hidden", "clip"];
 
 pub fn is_overflow_element(element: &Element) -> bool {
-    let style = get_computed_style(element);
+    try_is_overflow_element(element).expect("Element should have computed style.")
+}
+
+pub fn try_is_overflow_element(element: &Element) -> Option<bool> {
+    let style = try_get_computed_style(element)?;
     let overflow = style.get_property_value("overflow").unwrap_or_default();
     let overflow_x = style.get_property_value("overflow-x").unwrap_or_default();
     let overflow_y = style.get_property_value("overflow-y").unwrap_or_default();
@@ -147,11 +155,13 @@ pub fn is_overflow_element(element: &Element) -> bool {
 
     let overflow_combined = format!("{overflow}{overflow_x}{overflow_y}");
 
-    OVERFLOW_VALUES
-        .into_iter()
-        .any(|s| overflow_combined.contains(s))
-        && display != "inline"
-        && display != "contents"
+    Some(
+        OVERFLOW_VALUES
+            .into_iter()
+            .any(|s| overflow_combined.contains(s))
+            && display != "inline"
+            && display != "contents",
+    )
 }
 
 pub fn is_table_element(element: &Element) -> bool {
@@ -202,46 +212,57 @@ fn is_not_none(value: Result<String, JsValue>) -> bool {
 }
 
 pub fn is_containing_block(element: ElementOrCss) -> bool {
+    try_is_containing_block(element).expect("Element should have computed style.")
+}
+
+pub fn try_is_containing_block(element: ElementOrCss) -> Option<bool> {
     let css = match element {
-        ElementOrCss::Element(element) => get_computed_style(element),
+        ElementOrCss::Element(element) => try_get_computed_style(element)?,
         ElementOrCss::Css(css) => css,
     };
 
     // https://developer.mozilla.org/en-US/docs/Web/CSS/Containing_block#identifying_the_containing_block
     // https://drafts.csswg.org/css-transforms-2/#individual-transforms
-    is_not_none(css.get_property_value("transform"))
-        || is_not_none(css.get_property_value("translate"))
-        || is_not_none(css.get_property_value("scale"))
-        || is_not_none(css.get_property_value("rotate"))
-        || is_not_none(css.get_property_value("perspective"))
-        || (!is_web_kit()
-            && (is_not_none(css.get_property_value("backdrop-filter"))
-                || is_not_none(css.get_property_value("filter"))))
-        || WILL_CHANGE_VALUES.contains(
-            &css.get_property_value("will-change")
-                .unwrap_or_default()
-                .as_str(),
-        )
-        || CONTAIN_VALUES.contains(
-            &css.get_property_value("contain")
-                .unwrap_or_default()
-                .as_str(),
-        )
+    Some(
+        is_not_none(css.get_property_value("transform"))
+            || is_not_none(css.get_property_value("translate"))
+            || is_not_none(css.get_property_value("scale"))
+            || is_not_none(css.get_property_value("rotate"))
+            || is_not_none(css.get_property_value("perspective"))
+            || (!is_web_kit()
+                && (is_not_none(css.get_property_value("backdrop-filter"))
+                    || is_not_none(css.get_property_value("filter"))))
+            || WILL_CHANGE_VALUES.contains(
+                &css.get_property_value("will-change")
+                    .unwrap_or_default()
+                    .as_str(),
+            )
+            || CONTAIN_VALUES.contains(
+                &css.get_property_value("contain")
+                    .unwrap_or_default()
+                    .as_str(),
+            ),
+    )
 }
 
 pub fn get_containing_block(element: &Element) -> Option<HtmlElement> {
-    let mut current_node = get_parent_node(element);
+    try_get_containing_block(element).expect("Element should have computed style.")
+}
+
+/// Returns `None` if the document or computed style of an ancestor is not available.
+pub fn try_get_containing_block(element: &Element) -> Option<Option<HtmlElement>> {
+    let mut current_node = try_get_parent_node(element)?;
 
     while !is_last_traversable_node(&current_node) {
         match current_node.dyn_into::<HtmlElement>() {
             Ok(element) => {
-                if is_containing_block((&element).into()) {
-                    return Some(element);
+                if try_is_containing_block((&element).into())? {
+                    return Some(Some(element));
                 } else if is_top_layer(&element) {
-                    return None;
+                    return Some(None);
                 }
 
-                current_node = get_parent_node(&element);
+                current_node = try_get_parent_node(&element)?;
             }
             _ => {
                 break;
@@ -249,7 +270,7 @@ pub fn get_containing_block(element: &Element) -> Option<HtmlElement> {
         }
     }
 
-    None
+    Some(None)
 }
 
 pub fn is_web_kit() -> bool {
@@ -263,10 +284,14 @@ pub fn is_last_traversable_node(node: &Node) -> bool {
 }
 
 pub fn get_computed_style(element: &Element) -> CssStyleDeclaration {
-    get_window(Some(element))
+    try_get_computed_style(element).expect("Element should have computed style.")
+}
+
+pub fn try_get_computed_style(element: &Element) -> Option<CssStyleDeclaration> {
+    try_get_window(Some(element))?
         .get_computed_style(element)
-        .expect("Valid element.")
-        .expect("Element should have computed style.")
+        .ok()
+        .flatten()
 }
 
 #[derive(Clone, Debug)]
@@ -285,21 +310,29 @@ impl NodeScroll {
 }
 
 pub fn get_node_scroll(element_or_window: DomElementOrWindow) -> NodeScroll {
-    match element_or_window {
+    try_get_node_scroll(element_or_window).expect("Window should have scroll position.")
+}
+
+pub fn try_get_node_scroll(element_or_window: DomElementOrWindow) -> Option<NodeScroll> {
+    Some(match element_or_window {
         DomElementOrWindow::Element(element) => NodeScroll {
             scroll_left: element.scroll_left() as f64,
             scroll_top: element.scroll_top() as f64,
         },
         DomElementOrWindow::Window(window) => NodeScroll {
-            scroll_left: window.scroll_x().expect("Window should have scroll x."),
-            scroll_top: window.scroll_y().expect("Window should have scroll y."),
+            scroll_left: window.scroll_x().ok()?,
+            scroll_top: window.scroll_y().ok()?,
         },
-    }
+    })
 }
 
 pub fn get_parent_node(node: &Node) -> Node {
+    try_get_parent_node(node).expect("Document should have document element.")
+}
+
+pub fn try_get_parent_node(node: &Node) -> Option<Node> {
     if get_node_name(node.into()) == "html" {
-        return node.clone();
+        return Some(node.clone());
     }
 
     let element = node.dyn_ref::<Element>();
@@ -322,35 +355,37 @@ pub fn get_parent_node(node: &Node) -> Node {
                         result = shadow_root.host().into();
                     } else {
                         // Fallback.
-                        result = get_document_element(Some(node.into())).into();
+                        result = try_get_document_element(Some(node.into()))?.into();
                     }
                 }
             }
         }
     }
 
-    match node.dyn_ref::<ShadowRoot>() {
+    Some(match node.dyn_ref::<ShadowRoot>() {
         Some(shadow_root) => shadow_root.host().into(),
         None => result,
-    }
+    })
 }
 
 pub fn get_nearest_overflow_ancestor(node: &Node) -> HtmlElement {
-    let parent_node = get_parent_node(node);
+    try_get_nearest_overflow_ancestor(node).expect("Document should have body.")
+}
+
+pub fn try_get_nearest_overflow_ancestor(node: &Node) -> Option<HtmlElement> {
+    let parent_node = try_get_parent_node(node)?;
 
     if is_last_traversable_node(&parent_node) {
         node.owner_document()
             .as_ref()
-            .or(node.dyn_ref::<Document>())
-            .expect("Node should be document or have owner document.")
+            .or(node.dyn_ref::<Document>())?
             .body()
-            .expect("Document should have body.")
     } else if is_html_element(&parent_node)
-        && is_overflow_element(parent_node.unchecked_ref::<Element>())
+        && try_is_overflow_element(parent_node.unchecked_ref::<Element>())?
     {
-        parent_node.unchecked_into()
+        Some(parent_node.unchecked_into())
     } else {
-        get_nearest_overflow_ancestor(&parent_node)
+        try_get_nearest_overflow_ancestor(&parent_node)
     }
 }
 
@@ -363,15 +398,25 @@ pub enum OverflowAncestor {
 
 pub fn get_overflow_ancestors(
     node: &Node,
-    mut list: Vec<OverflowAncestor>,
+    list: Vec<OverflowAncestor>,
     traverse_iframe: bool,
 ) -> Vec<OverflowAncestor> {
-    let scrollable_ancestor = get_nearest_overflow_ancestor(node);
+    try_get_overflow_ancestors(node, list, traverse_iframe)
+        .expect("Element should have computed style.")
+}
+
+/// Returns `None` if the window, document or computed style of an ancestor is not available.
+pub fn try_get_overflow_ancestors(
+    node: &Node,
+    mut list: Vec<OverflowAncestor>,
+    traverse_iframe: bool,
+) -> Option<Vec<OverflowAncestor>> {
+    let scrollable_ancestor = try_get_nearest_overflow_ancestor(node)?;
     let is_body = node
         .owner_document()
         .and_then(|document| document.body())
         .is_some_and(|body| scrollable_ancestor == body);
-    let window = get_window(Some(&scrollable_ancestor));
+    let window = try_get_window(Some(&scrollable_ancestor))?;
 
     if is_body {
         let frame_element = get_frame_element(&window);
@@ -382,24 +427,29 @@ pub fn get_overflow_ancestors(
             list.push(OverflowAncestor::VisualViewport(visual_viewport));
         }
 
-        if is_overflow_element(&scrollable_ancestor) {
+        if try_is_overflow_element(&scrollable_ancestor)? {
             list.push(OverflowAncestor::Element(scrollable_ancestor.into()));
         }
 
         if let Some(frame_element) = frame_element
             && traverse_iframe
         {
-            list.append(&mut get_overflow_ancestors(&frame_element, vec![], true))
+            list.append(&mut try_get_overflow_ancestors(
+                &frame_element,
+                vec![],
+                true,
+            )?)
         }
 
-        list
+        Some(list)
     } else {
-        let mut other_list = get_overflow_ancestors(&scrollable_ancestor, vec![], traverse_iframe);
+        let mut other_list =
+            try_get_overflow_ancestors(&scrollable_ancestor, vec![], traverse_iframe)?;
 
         list.push(OverflowAncestor::Element(scrollable_ancestor.into()));
         list.append(&mut other_list);
 
-        list
+        Some(list)
     }
 }
 
@@ -408,11 +458,8 @@ pub fn get_frame_element(window: &Window) -> Option<Element> {
         .parent()
         .ok()
         .flatten()
-        .and_then(|_| {
-            window
-                .frame_element()
-                .expect("Window should have frame element option.")
-        })
+        // Cross-origin frames are treated as the top window.
+        .and_then(|_| window.frame_element().ok().flatten())
         .and_then(|frame_element| {
             Object::get_prototype_of(&frame_element)
                 .is_truthy()
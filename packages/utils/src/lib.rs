@@ -6,21 +6,109 @@
 //!
 //! See [@floating-ui/utils](https://www.npmjs.com/package/@floating-ui/utils) for the original package.
 
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+extern crate alloc;
+
 #[cfg(feature = "dom")]
 pub mod dom;
 
-use std::rc::Rc;
+use alloc::{boxed::Box, rc::Rc, string::String, vec, vec::Vec};
+use core::any::Any;
+use core::error::Error;
+use core::fmt::{self, Display};
+use core::str::FromStr;
 
-use dyn_derive::dyn_trait;
 use serde::{Deserialize, Serialize};
 
-#[derive(Copy, Clone, Debug, PartialEq)]
+/// Error returned when parsing the string form of an enum, e.g. [`Placement`], fails.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ParseError {
+    /// Name of the parsed type.
+    pub name: &'static str,
+    /// The invalid value.
+    pub value: String,
+    /// The valid values.
+    pub expected: &'static [&'static str],
+}
+
+impl ParseError {
+    pub fn new(name: &'static str, value: &str, expected: &'static [&'static str]) -> Self {
+        ParseError {
+            name,
+            value: value.into(),
+            expected,
+        }
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Invalid {} `{}`, expected one of: ",
+            self.name, self.value
+        )?;
+        for (index, expected) in self.expected.iter().enumerate() {
+            if index > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "`{expected}`")?;
+        }
+        write!(f, ".")
+    }
+}
+
+impl Error for ParseError {}
+
+/// Implements the upstream string forms of an enum, using [`Display`] and [`FromStr`].
+macro_rules! string_enum {
+    ($type:ident, $name:literal, { $($variant:ident => $value:literal),+ $(,)? }) => {
+        impl $type {
+            /// The string forms of all variants.
+            pub const VALUES: &'static [&'static str] = &[$($value),+];
+
+            /// The string form of the variant.
+            pub fn as_str(&self) -> &'static str {
+                match self {
+                    $($type::$variant => $value),+
+                }
+            }
+        }
+
+        impl Display for $type {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str(self.as_str())
+            }
+        }
+
+        impl FromStr for $type {
+            type Err = ParseError;
+
+            fn from_str(value: &str) -> Result<Self, Self::Err> {
+                match value {
+                    $($value => Ok($type::$variant),)+
+                    _ => Err(ParseError::new($name, value, Self::VALUES)),
+                }
+            }
+        }
+    };
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
 pub enum Alignment {
     Start,
     End,
 }
 
-#[derive(Copy, Clone, Debug, PartialEq)]
+string_enum!(Alignment, "alignment", {
+    Start => "start",
+    End => "end",
+});
+
+#[derive(Copy, Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
 pub enum Side {
     Top,
     Right,
@@ -28,6 +116,13 @@ pub enum Side {
     Left,
 }
 
+string_enum!(Side, "side", {
+    Top => "top",
+    Right => "right",
+    Bottom => "bottom",
+    Left => "left",
+});
+
 impl Side {
     pub fn opposite(&self) -> Side {
         match self {
@@ -48,7 +143,8 @@ impl Side {
     }
 }
 
-#[derive(Copy, Clone, Debug, PartialEq)]
+#[derive(Copy, Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
 pub enum AlignedPlacement {
     TopStart,
     TopEnd,
@@ -60,7 +156,19 @@ pub enum AlignedPlacement {
     LeftEnd,
 }
 
+string_enum!(AlignedPlacement, "aligned placement", {
+    TopStart => "top-start",
+    TopEnd => "top-end",
+    RightStart => "right-start",
+    RightEnd => "right-end",
+    BottomStart => "bottom-start",
+    BottomEnd => "bottom-end",
+    LeftStart => "left-start",
+    LeftEnd => "left-end",
+});
+
 #[derive(Copy, Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
 pub enum Placement {
     Top,
     TopStart,
@@ -76,6 +184,21 @@ pub enum Placement {
     LeftEnd,
 }
 
+string_enum!(Placement, "placement", {
+    Top => "top",
+    TopStart => "top-start",
+    TopEnd => "top-end",
+    Right => "right",
+    RightStart => "right-start",
+    RightEnd => "right-end",
+    Bottom => "bottom",
+    BottomStart => "bottom-start",
+    BottomEnd => "bottom-end",
+    Left => "left",
+    LeftStart => "left-start",
+    LeftEnd => "left-end",
+});
+
 impl Placement {
     pub fn alignment(&self) -> Option<Alignment> {
         match self {
@@ -165,18 +288,30 @@ impl From<(Side, Option<Alignment>)> for Placement {
     }
 }
 
-#[derive(Copy, Clone, Debug, PartialEq)]
+#[derive(Copy, Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
 pub enum Strategy {
     Absolute,
     Fixed,
 }
 
-#[derive(Copy, Clone, Debug, PartialEq)]
+string_enum!(Strategy, "strategy", {
+    Absolute => "absolute",
+    Fixed => "fixed",
+});
+
+#[derive(Copy, Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
 pub enum Axis {
     X,
     Y,
 }
 
+string_enum!(Axis, "axis", {
+    X => "x",
+    Y => "y",
+});
+
 impl Axis {
     pub fn opposite(&self) -> Axis {
         match self {
@@ -193,6 +328,219 @@ impl Axis {
     }
 }
 
+/// Writing mode of an element, see [`writing-mode`](https://developer.mozilla.org/en-US/docs/Web/CSS/writing-mode).
+#[derive(Copy, Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum WritingMode {
+    #[default]
+    HorizontalTb,
+    VerticalRl,
+    VerticalLr,
+    /// Like [`WritingMode::VerticalLr`], but lines of text run from bottom to top.
+    SidewaysLr,
+}
+
+string_enum!(WritingMode, "writing mode", {
+    HorizontalTb => "horizontal-tb",
+    VerticalRl => "vertical-rl",
+    VerticalLr => "vertical-lr",
+    SidewaysLr => "sideways-lr",
+});
+
+impl WritingMode {
+    /// The physical axis along which lines of text run.
+    pub fn inline_axis(&self) -> Axis {
+        match self {
+            WritingMode::HorizontalTb => Axis::X,
+            WritingMode::VerticalRl | WritingMode::VerticalLr | WritingMode::SidewaysLr => Axis::Y,
+        }
+    }
+
+    /// The physical axis along which lines of text are stacked.
+    pub fn block_axis(&self) -> Axis {
+        self.inline_axis().opposite()
+    }
+
+    /// Whether the logical start of the physical axis is on its right or bottom side.
+    pub fn is_axis_reversed(&self, axis: Axis, rtl: bool) -> bool {
+        match (self, axis) {
+            (WritingMode::HorizontalTb, Axis::X) => rtl,
+            (WritingMode::HorizontalTb, Axis::Y) => false,
+            (WritingMode::VerticalRl, Axis::X) => true,
+            (WritingMode::VerticalLr | WritingMode::SidewaysLr, Axis::X) => false,
+            (WritingMode::VerticalRl | WritingMode::VerticalLr, Axis::Y) => rtl,
+            (WritingMode::SidewaysLr, Axis::Y) => !rtl,
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum LogicalSide {
+    BlockStart,
+    BlockEnd,
+    InlineStart,
+    InlineEnd,
+}
+
+string_enum!(LogicalSide, "logical side", {
+    BlockStart => "block-start",
+    BlockEnd => "block-end",
+    InlineStart => "inline-start",
+    InlineEnd => "inline-end",
+});
+
+impl LogicalSide {
+    pub fn opposite(&self) -> LogicalSide {
+        match self {
+            LogicalSide::BlockStart => LogicalSide::BlockEnd,
+            LogicalSide::BlockEnd => LogicalSide::BlockStart,
+            LogicalSide::InlineStart => LogicalSide::InlineEnd,
+            LogicalSide::InlineEnd => LogicalSide::InlineStart,
+        }
+    }
+
+    /// Resolves the logical side to a physical side for the given writing mode and text direction.
+    pub fn resolve(&self, writing_mode: WritingMode, rtl: bool) -> Side {
+        let (axis, is_start) = match self {
+            LogicalSide::BlockStart => (writing_mode.block_axis(), true),
+            LogicalSide::BlockEnd => (writing_mode.block_axis(), false),
+            LogicalSide::InlineStart => (writing_mode.inline_axis(), true),
+            LogicalSide::InlineEnd => (writing_mode.inline_axis(), false),
+        };
+
+        match (axis, is_start != writing_mode.is_axis_reversed(axis, rtl)) {
+            (Axis::X, true) => Side::Left,
+            (Axis::X, false) => Side::Right,
+            (Axis::Y, true) => Side::Top,
+            (Axis::Y, false) => Side::Bottom,
+        }
+    }
+
+    /// The logical side which resolves to the physical side for the given writing mode and text direction.
+    pub fn from_side(side: Side, writing_mode: WritingMode, rtl: bool) -> LogicalSide {
+        [
+            LogicalSide::BlockStart,
+            LogicalSide::BlockEnd,
+            LogicalSide::InlineStart,
+            LogicalSide::InlineEnd,
+        ]
+        .into_iter()
+        .find(|logical_side| logical_side.resolve(writing_mode, rtl) == side)
+        .expect("Every side should have a logical side.")
+    }
+}
+
+/// Writing-mode relative placement, resolved to a [`Placement`] with [`LogicalPlacement::resolve`].
+///
+/// The alignment is kept as is, since [`Alignment`] is already relative to the writing mode and text direction.
+#[derive(Copy, Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum LogicalPlacement {
+    BlockStart,
+    BlockStartStart,
+    BlockStartEnd,
+    BlockEnd,
+    BlockEndStart,
+    BlockEndEnd,
+    InlineStart,
+    InlineStartStart,
+    InlineStartEnd,
+    InlineEnd,
+    InlineEndStart,
+    InlineEndEnd,
+}
+
+string_enum!(LogicalPlacement, "logical placement", {
+    BlockStart => "block-start",
+    BlockStartStart => "block-start-start",
+    BlockStartEnd => "block-start-end",
+    BlockEnd => "block-end",
+    BlockEndStart => "block-end-start",
+    BlockEndEnd => "block-end-end",
+    InlineStart => "inline-start",
+    InlineStartStart => "inline-start-start",
+    InlineStartEnd => "inline-start-end",
+    InlineEnd => "inline-end",
+    InlineEndStart => "inline-end-start",
+    InlineEndEnd => "inline-end-end",
+});
+
+impl LogicalPlacement {
+    pub fn alignment(&self) -> Option<Alignment> {
+        match self {
+            LogicalPlacement::BlockStart => None,
+            LogicalPlacement::BlockStartStart => Some(Alignment::Start),
+            LogicalPlacement::BlockStartEnd => Some(Alignment::End),
+            LogicalPlacement::BlockEnd => None,
+            LogicalPlacement::BlockEndStart => Some(Alignment::Start),
+            LogicalPlacement::BlockEndEnd => Some(Alignment::End),
+            LogicalPlacement::InlineStart => None,
+            LogicalPlacement::InlineStartStart => Some(Alignment::Start),
+            LogicalPlacement::InlineStartEnd => Some(Alignment::End),
+            LogicalPlacement::InlineEnd => None,
+            LogicalPlacement::InlineEndStart => Some(Alignment::Start),
+            LogicalPlacement::InlineEndEnd => Some(Alignment::End),
+        }
+    }
+
+    pub fn side(&self) -> LogicalSide {
+        match self {
+            LogicalPlacement::BlockStart
+            | LogicalPlacement::BlockStartStart
+            | LogicalPlacement::BlockStartEnd => LogicalSide::BlockStart,
+            LogicalPlacement::BlockEnd
+            | LogicalPlacement::BlockEndStart
+            | LogicalPlacement::BlockEndEnd => LogicalSide::BlockEnd,
+            LogicalPlacement::InlineStart
+            | LogicalPlacement::InlineStartStart
+            | LogicalPlacement::InlineStartEnd => LogicalSide::InlineStart,
+            LogicalPlacement::InlineEnd
+            | LogicalPlacement::InlineEndStart
+            | LogicalPlacement::InlineEndEnd => LogicalSide::InlineEnd,
+        }
+    }
+
+    /// Resolves the logical placement to a physical placement for the given writing mode and text direction.
+    pub fn resolve(&self, writing_mode: WritingMode, rtl: bool) -> Placement {
+        get_placement(self.side().resolve(writing_mode, rtl), self.alignment())
+    }
+
+    /// The logical placement which resolves to the physical placement for the given writing mode and text direction.
+    pub fn from_placement(
+        placement: Placement,
+        writing_mode: WritingMode,
+        rtl: bool,
+    ) -> LogicalPlacement {
+        (
+            LogicalSide::from_side(placement.side(), writing_mode, rtl),
+            placement.alignment(),
+        )
+            .into()
+    }
+}
+
+impl From<(LogicalSide, Option<Alignment>)> for LogicalPlacement {
+    fn from(value: (LogicalSide, Option<Alignment>)) -> Self {
+        match value {
+            (LogicalSide::BlockStart, None) => LogicalPlacement::BlockStart,
+            (LogicalSide::BlockStart, Some(Alignment::Start)) => LogicalPlacement::BlockStartStart,
+            (LogicalSide::BlockStart, Some(Alignment::End)) => LogicalPlacement::BlockStartEnd,
+            (LogicalSide::BlockEnd, None) => LogicalPlacement::BlockEnd,
+            (LogicalSide::BlockEnd, Some(Alignment::Start)) => LogicalPlacement::BlockEndStart,
+            (LogicalSide::BlockEnd, Some(Alignment::End)) => LogicalPlacement::BlockEndEnd,
+            (LogicalSide::InlineStart, None) => LogicalPlacement::InlineStart,
+            (LogicalSide::InlineStart, Some(Alignment::Start)) => {
+                LogicalPlacement::InlineStartStart
+            }
+            (LogicalSide::InlineStart, Some(Alignment::End)) => LogicalPlacement::InlineStartEnd,
+            (LogicalSide::InlineEnd, None) => LogicalPlacement::InlineEnd,
+            (LogicalSide::InlineEnd, Some(Alignment::Start)) => LogicalPlacement::InlineEndStart,
+            (LogicalSide::InlineEnd, Some(Alignment::End)) => LogicalPlacement::InlineEndEnd,
+        }
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct Coords {
     pub x: f64,
@@ -226,13 +574,13 @@ impl Coords {
     }
 }
 
-#[derive(Copy, Clone, Debug, PartialEq)]
+#[derive(Copy, Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub enum Length {
     Width,
     Height,
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct Dimensions {
     pub width: f64,
     pub height: f64,
@@ -266,7 +614,7 @@ impl SideObject {
     }
 }
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct PartialSideObject {
     pub top: Option<f64>,
     pub right: Option<f64>,
@@ -274,7 +622,7 @@ pub struct PartialSideObject {
     pub left: Option<f64>,
 }
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct Rect {
     pub x: f64,
     pub y: f64,
@@ -298,13 +646,17 @@ impl Rect {
     }
 }
 
-#[derive(Clone, Debug, PartialEq)]
+/// Padding on all sides or per side.
+///
+/// Serialized as a number or an object with optional sides.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
 pub enum Padding {
     All(f64),
     PerSide(PartialSideObject),
 }
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ClientRectObject {
     pub x: f64,
     pub y: f64,
@@ -373,17 +725,35 @@ cfg_if::cfg_if! {
     }
 }
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ElementRects {
     pub reference: Rect,
     pub floating: Rect,
 }
 
+/// Object-safe [`PartialEq`], used to compare trait objects.
+///
+/// Implemented for all types which implement [`PartialEq`].
+pub trait DynPartialEq: Any {
+    fn as_dyn_any(&self) -> &dyn Any;
+
+    fn dyn_eq(&self, other: &dyn Any) -> bool;
+}
+
+impl<T: Any + PartialEq> DynPartialEq for T {
+    fn as_dyn_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn dyn_eq(&self, other: &dyn Any) -> bool {
+        other.downcast_ref::<T>() == Some(self)
+    }
+}
+
 /// Custom positioning reference element.
 ///
 /// See [the Rust Floating UI book](https://floating-ui.rustforweb.org/virtual-elements.html) for more documentation.
-#[dyn_trait]
-pub trait VirtualElement<Element: 'static>: Clone + PartialEq {
+pub trait VirtualElement<Element: 'static>: VirtualElementClone<Element> + DynPartialEq {
     fn get_bounding_client_rect(&self) -> ClientRectObject;
 
     fn get_client_rects(&self) -> Option<Vec<ClientRectObject>>;
@@ -391,8 +761,35 @@ pub trait VirtualElement<Element: 'static>: Clone + PartialEq {
     fn context_element(&self) -> Option<Element>;
 }
 
-#[dyn_trait]
-pub trait GetBoundingClientRectCloneable: Clone {
+/// Object-safe [`Clone`] for [`VirtualElement`].
+///
+/// Implemented for all virtual elements which implement [`Clone`].
+pub trait VirtualElementClone<Element: 'static> {
+    fn clone_box(&self) -> Box<dyn VirtualElement<Element>>;
+}
+
+impl<Element: 'static, T> VirtualElementClone<Element> for T
+where
+    T: VirtualElement<Element> + Clone + 'static,
+{
+    fn clone_box(&self) -> Box<dyn VirtualElement<Element>> {
+        Box::new(self.clone())
+    }
+}
+
+impl<Element: 'static> Clone for Box<dyn VirtualElement<Element>> {
+    fn clone(&self) -> Self {
+        (**self).clone_box()
+    }
+}
+
+impl<Element: 'static> PartialEq for dyn VirtualElement<Element> {
+    fn eq(&self, other: &Self) -> bool {
+        self.dyn_eq(other.as_dyn_any())
+    }
+}
+
+pub trait GetBoundingClientRectCloneable {
     fn call(&self) -> ClientRectObject;
 }
 
@@ -405,8 +802,7 @@ where
     }
 }
 
-#[dyn_trait]
-pub trait GetClientRectsCloneable: Clone {
+pub trait GetClientRectsCloneable {
     fn call(&self) -> Vec<ClientRectObject>;
 }
 
@@ -528,7 +924,7 @@ impl<'a, Element: Clone> From<&'a OwnedElementOrVirtual<Element>>
     }
 }
 
-#[derive(Clone)]
+#[derive(Clone, PartialEq)]
 pub enum OwnedElementOrVirtual<Element: 'static> {
     Element(Element),
     VirtualElement(Box<dyn VirtualElement<Element>>),
@@ -545,6 +941,17 @@ impl<Element: 'static> OwnedElementOrVirtual<Element> {
     }
 }
 
+impl<Element: Clone> From<ElementOrVirtual<'_, Element>> for OwnedElementOrVirtual<Element> {
+    fn from(value: ElementOrVirtual<'_, Element>) -> Self {
+        match value {
+            ElementOrVirtual::Element(element) => OwnedElementOrVirtual::Element(element.clone()),
+            ElementOrVirtual::VirtualElement(virtual_element) => {
+                OwnedElementOrVirtual::VirtualElement(virtual_element)
+            }
+        }
+    }
+}
+
 impl<Element> From<Element> for OwnedElementOrVirtual<Element> {
     fn from(value: Element) -> Self {
         OwnedElementOrVirtual::Element(value)
@@ -574,12 +981,23 @@ impl<'a, Element, Window> From<&'a OwnedElementOrWindow<Element, Window>>
     }
 }
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub enum OwnedElementOrWindow<Element, Window> {
     Element(Element),
     Window(Window),
 }
 
+impl<Element: Clone, Window: Clone> From<ElementOrWindow<'_, Element, Window>>
+    for OwnedElementOrWindow<Element, Window>
+{
+    fn from(value: ElementOrWindow<'_, Element, Window>) -> Self {
+        match value {
+            ElementOrWindow::Element(element) => OwnedElementOrWindow::Element(element.clone()),
+            ElementOrWindow::Window(window) => OwnedElementOrWindow::Window(window.clone()),
+        }
+    }
+}
+
 pub const ALL_PLACEMENTS: [Placement; 12] = [
     Placement::Top,
     Placement::TopStart,
@@ -629,26 +1047,41 @@ pub fn get_alignment_axis(placement: Placement) -> Axis {
     get_opposite_axis(get_side_axis(placement))
 }
 
+/// Returns whether the logical start of the physical axis is on its right or bottom side.
+///
+/// Defaults to [`WritingMode::HorizontalTb`] and left-to-right text.
+pub fn is_axis_reversed(axis: Axis, rtl: Option<bool>, writing_mode: Option<WritingMode>) -> bool {
+    writing_mode
+        .unwrap_or_default()
+        .is_axis_reversed(axis, rtl.unwrap_or(false))
+}
+
 pub fn get_alignment_sides(
     placement: Placement,
     rects: &ElementRects,
     rtl: Option<bool>,
+) -> (Side, Side) {
+    get_alignment_sides_with_writing_mode(placement, rects, rtl, None)
+}
+
+/// Version of [`get_alignment_sides`] which respects the writing mode.
+pub fn get_alignment_sides_with_writing_mode(
+    placement: Placement,
+    rects: &ElementRects,
+    rtl: Option<bool>,
+    writing_mode: Option<WritingMode>,
 ) -> (Side, Side) {
     let alignment = get_alignment(placement);
     let alignment_axis = get_alignment_axis(placement);
     let length = get_axis_length(alignment_axis);
-
-    let mut main_alignment_side = match (alignment_axis, alignment) {
-        (Axis::X, Some(Alignment::Start)) => match rtl {
-            Some(true) => Side::Left,
-            _ => Side::Right,
-        },
-        (Axis::X, _) => match rtl {
-            Some(true) => Side::Right,
-            _ => Side::Left,
-        },
-        (Axis::Y, Some(Alignment::Start)) => Side::Bottom,
-        (Axis::Y, _) => Side::Top,
+    let is_start = (alignment == Some(Alignment::Start))
+        != is_axis_reversed(alignment_axis, rtl, writing_mode);
+
+    let mut main_alignment_side = match (alignment_axis, is_start) {
+        (Axis::X, true) => Side::Right,
+        (Axis::X, false) => Side::Left,
+        (Axis::Y, true) => Side::Bottom,
+        (Axis::Y, false) => Side::Top,
     };
 
     if rects.reference.length(length) > rects.floating.length(length) {
@@ -678,30 +1111,24 @@ const TB_SIDE_LIST: [Side; 2] = [Side::Top, Side::Bottom];
 const BT_SIDE_LIST: [Side; 2] = [Side::Bottom, Side::Top];
 
 pub fn get_side_list(side: Side, is_start: bool, rtl: Option<bool>) -> [Side; 2] {
-    match side {
-        Side::Top | Side::Bottom => match rtl {
-            Some(true) => {
-                if is_start {
-                    RL_SIDE_LIST
-                } else {
-                    LR_SIDE_LIST
-                }
-            }
-            _ => {
-                if is_start {
-                    LR_SIDE_LIST
-                } else {
-                    RL_SIDE_LIST
-                }
-            }
-        },
-        Side::Right | Side::Left => {
-            if is_start {
-                TB_SIDE_LIST
-            } else {
-                BT_SIDE_LIST
-            }
-        }
+    get_side_list_with_writing_mode(side, is_start, rtl, None)
+}
+
+/// Version of [`get_side_list`] which respects the writing mode.
+pub fn get_side_list_with_writing_mode(
+    side: Side,
+    is_start: bool,
+    rtl: Option<bool>,
+    writing_mode: Option<WritingMode>,
+) -> [Side; 2] {
+    let alignment_axis = get_opposite_axis(side.axis());
+    let is_start = is_start != is_axis_reversed(alignment_axis, rtl, writing_mode);
+
+    match (alignment_axis, is_start) {
+        (Axis::X, true) => LR_SIDE_LIST,
+        (Axis::X, false) => RL_SIDE_LIST,
+        (Axis::Y, true) => TB_SIDE_LIST,
+        (Axis::Y, false) => BT_SIDE_LIST,
     }
 }
 
@@ -714,12 +1141,24 @@ pub fn get_opposite_axis_placements(
     flip_alignment: bool,
     direction: Option<Alignment>,
     rtl: Option<bool>,
+) -> Vec<Placement> {
+    get_opposite_axis_placements_with_writing_mode(placement, flip_alignment, direction, rtl, None)
+}
+
+/// Version of [`get_opposite_axis_placements`] which respects the writing mode.
+pub fn get_opposite_axis_placements_with_writing_mode(
+    placement: Placement,
+    flip_alignment: bool,
+    direction: Option<Alignment>,
+    rtl: Option<bool>,
+    writing_mode: Option<WritingMode>,
 ) -> Vec<Placement> {
     let alignment = get_alignment(placement);
-    let side_list = get_side_list(
+    let side_list = get_side_list_with_writing_mode(
         get_side(placement),
         direction.is_some_and(|d| d == Alignment::Start),
         rtl,
+        writing_mode,
     );
 
     let mut list: Vec<Placement> = side_list
@@ -780,7 +1219,12 @@ pub fn rect_to_client_rect(rect: Rect) -> ClientRectObject {
 
 #[cfg(test)]
 mod tests {
-    use super::{Alignment, Placement, get_opposite_axis_placements};
+    use alloc::string::ToString;
+
+    use super::{
+        ALL_PLACEMENTS, Alignment, LogicalPlacement, ParseError, Placement, Side, WritingMode,
+        get_opposite_axis_placements,
+    };
 
     #[test]
     fn side_top() {
@@ -1142,4 +1586,94 @@ mod tests {
             vec![Placement::Bottom, Placement::Top]
         );
     }
+
+    #[test]
+    fn logical_placement_resolve() {
+        let cases = [
+            (
+                WritingMode::HorizontalTb,
+                false,
+                [Side::Top, Side::Bottom, Side::Left, Side::Right],
+            ),
+            (
+                WritingMode::HorizontalTb,
+                true,
+                [Side::Top, Side::Bottom, Side::Right, Side::Left],
+            ),
+            (
+                WritingMode::VerticalRl,
+                false,
+                [Side::Right, Side::Left, Side::Top, Side::Bottom],
+            ),
+            (
+                WritingMode::VerticalRl,
+                true,
+                [Side::Right, Side::Left, Side::Bottom, Side::Top],
+            ),
+            (
+                WritingMode::VerticalLr,
+                false,
+                [Side::Left, Side::Right, Side::Top, Side::Bottom],
+            ),
+            (
+                WritingMode::VerticalLr,
+                true,
+                [Side::Left, Side::Right, Side::Bottom, Side::Top],
+            ),
+            (
+                WritingMode::SidewaysLr,
+                false,
+                [Side::Left, Side::Right, Side::Bottom, Side::Top],
+            ),
+            (
+                WritingMode::SidewaysLr,
+                true,
+                [Side::Left, Side::Right, Side::Top, Side::Bottom],
+            ),
+        ];
+
+        for (writing_mode, rtl, sides) in cases {
+            let logical_sides = [
+                LogicalPlacement::BlockStart,
+                LogicalPlacement::BlockEnd,
+                LogicalPlacement::InlineStartStart,
+                LogicalPlacement::InlineEndEnd,
+            ];
+            for (logical_placement, side) in logical_sides.into_iter().zip(sides) {
+                let placement = logical_placement.resolve(writing_mode, rtl);
+                assert_eq!(
+                    placement.side(),
+                    side,
+                    "{logical_placement} in {writing_mode}"
+                );
+                assert_eq!(placement.alignment(), logical_placement.alignment());
+                assert_eq!(
+                    LogicalPlacement::from_placement(placement, writing_mode, rtl),
+                    logical_placement
+                );
+            }
+        }
+
+        assert_eq!(
+            "block-end-start".parse::<LogicalPlacement>(),
+            Ok(LogicalPlacement::BlockEndStart)
+        );
+    }
+
+    #[test]
+    fn placement_string_forms() {
+        for placement in ALL_PLACEMENTS {
+            assert_eq!(placement.to_string().parse::<Placement>(), Ok(placement));
+        }
+        assert_eq!(Placement::BottomStart.to_string(), "bottom-start");
+
+        let error = "bottom-center".parse::<Placement>().unwrap_err();
+        assert_eq!(
+            error,
+            ParseError::new("placement", "bottom-center", Placement::VALUES)
+        );
+        assert!(error.to_string().starts_with(
+            "Invalid placement `bottom-center`, expected one of: `top`, `top-start`,"
+        ));
+    }
 }
@@ -1,7 +1,7 @@
 use floating_ui_yew::{
-    ARROW_NAME, Arrow, ArrowData, ArrowOptions, DetectOverflowOptions, Flip, FlipOptions,
-    MiddlewareVec, Offset, OffsetOptions, Padding, Placement, Shift, ShiftOptions, Side,
-    UseFloatingOptions, UseFloatingReturn, use_auto_update, use_floating,
+    Arrow, ArrowData, ArrowOptions, DetectOverflowOptions, Flip, FlipOptions, MiddlewareVec,
+    Offset, OffsetOptions, Padding, Placement, Shift, ShiftOptions, Side, UseFloatingOptions,
+    UseFloatingReturn, use_auto_update, use_floating,
 };
 use yew::prelude::*;
 
@@ -40,10 +40,9 @@ pub fn App() -> Html {
     );
 
     let static_side = placement.side().opposite();
-    let arrow_data = use_memo(
-        middleware_data,
-        move |middleware_data| -> Option<ArrowData> { middleware_data.get_as(ARROW_NAME) },
-    );
+    let arrow_data = use_memo(middleware_data, move |middleware_data| {
+        middleware_data.get_typed::<ArrowData>().cloned()
+    });
     let arrow_x = use_memo(arrow_data.clone(), |arrow_data| {
         arrow_data
             .as_ref()
@@ -0,0 +1,126 @@
+use floating_ui_dom::{
+    FloatingArrowLayout, FloatingArrowOptions, MiddlewareData, Placement, dom::get_computed_style,
+    floating_arrow_clip_path_id,
+};
+use yew::prelude::*;
+
+/// Props for [`FloatingArrow`].
+#[derive(Clone, PartialEq, Properties)]
+pub struct FloatingArrowProps {
+    /// The arrow element, also passed to [`Arrow`][crate::Arrow].
+    pub node_ref: NodeRef,
+
+    /// The final placement of the floating element.
+    pub placement: Placement,
+
+    /// The middleware data of the floating element.
+    pub middleware_data: MiddlewareData,
+
+    /// The width of the arrow. Defaults to `14`.
+    #[prop_or_default]
+    pub width: Option<f64>,
+
+    /// The height of the arrow. Defaults to `7`.
+    #[prop_or_default]
+    pub height: Option<f64>,
+
+    /// The radius of the rounded tip of the arrow. Defaults to `0`.
+    #[prop_or_default]
+    pub tip_radius: Option<f64>,
+
+    /// The width of the stroke, matching the border width of the floating element. Defaults to `0`.
+    #[prop_or_default]
+    pub stroke_width: Option<f64>,
+
+    /// A static CSS offset of the arrow from the aligned edge of the floating element.
+    #[prop_or_default]
+    pub static_offset: Option<String>,
+
+    /// A custom SVG path of the arrow, drawn pointing down.
+    #[prop_or_default]
+    pub d: Option<String>,
+
+    /// The fill color of the arrow, matching the background of the floating element.
+    #[prop_or_default]
+    pub fill: Option<String>,
+
+    /// The stroke color of the arrow, matching the border color of the floating element.
+    #[prop_or_default]
+    pub stroke: Option<String>,
+}
+
+/// SVG arrow which points from the floating element to the reference element.
+///
+/// Pass the same `node_ref` to [`Arrow`][crate::Arrow] and render the arrow inside the floating element.
+/// The arrow is positioned from the `placement` and `middleware_data` returned by [`use_floating`][crate::use_floating].
+///
+/// See [the Rust Floating UI book](https://floating-ui.rustforweb.org/frameworks/yew.html) for more documentation.
+#[function_component]
+pub fn FloatingArrow(props: &FloatingArrowProps) -> Html {
+    let clip_path_id = use_memo((), |_| floating_arrow_clip_path_id());
+    let rtl = use_state_eq(|| false);
+
+    use_effect_with(props.node_ref.clone(), {
+        let rtl = rtl.clone();
+
+        move |node_ref| {
+            if let Some(element) = node_ref.cast::<web_sys::Element>() {
+                rtl.set(
+                    get_computed_style(&element)
+                        .get_property_value("direction")
+                        .is_ok_and(|direction| direction == "rtl"),
+                );
+            }
+        }
+    });
+
+    let layout = FloatingArrowLayout::new(
+        &FloatingArrowOptions {
+            width: props.width,
+            height: props.height,
+            tip_radius: props.tip_radius,
+            stroke_width: props.stroke_width,
+            static_offset: props.static_offset.clone(),
+            d: props.d.clone(),
+        },
+        props.placement,
+        &props.middleware_data,
+        *rtl,
+    );
+
+    let fill_stroke = if layout.stroke_fill {
+        props.fill.clone().unwrap_or_else(|| "none".to_owned())
+    } else {
+        "none".to_owned()
+    };
+
+    html! {
+        <svg
+            ref={props.node_ref.clone()}
+            aria-hidden="true"
+            width={layout.width.to_string()}
+            height={layout.height.to_string()}
+            viewBox={layout.view_box.clone()}
+            style={layout.style.clone()}
+        >
+            if layout.stroke_width > 0.0 {
+                <path
+                    clip-path={format!("url(#{clip_path_id})")}
+                    fill="none"
+                    stroke={props.stroke.clone()}
+                    stroke-width={layout.stroke_width.to_string()}
+                    d={layout.d.clone()}
+                />
+            }
+            <path fill={props.fill.clone()} stroke={fill_stroke} d={layout.d.clone()} />
+            <clipPath id={(*clip_path_id).clone()}>
+                <rect
+                    x={layout.clip_rect.x.to_string()}
+                    y={layout.clip_rect.y.to_string()}
+                    width={layout.clip_rect.width.to_string()}
+                    height={layout.clip_rect.height.to_string()}
+                />
+            </clipPath>
+        </svg>
+    }
+}
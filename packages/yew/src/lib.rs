@@ -5,28 +5,43 @@
 //! See [the Rust Floating UI book](https://floating-ui.rustforweb.org/frameworks/yew.html) for more documenation.
 
 mod arrow;
+mod floating_arrow;
 mod types;
 mod use_auto_update;
+mod use_client_point;
 mod use_floating;
+mod use_transition;
 mod utils;
 
 pub use arrow::*;
+pub use floating_arrow::*;
 pub use types::*;
 pub use use_auto_update::*;
+pub use use_client_point::*;
 pub use use_floating::*;
+pub use use_transition::*;
 
 #[doc(no_inline)]
 pub use floating_ui_dom::{
     ARROW_NAME, AUTO_PLACEMENT_NAME, AlignedPlacement, Alignment, ApplyState, ArrowData,
     AutoPlacement, AutoPlacementData, AutoPlacementDataOverflow, AutoPlacementOptions,
-    AutoUpdateOptions, Axis, Boundary, ClientRectObject, ComputePositionConfig,
-    ComputePositionReturn, Coords, DefaultLimiter, DefaultVirtualElement, Derivable, DerivableFn,
-    DetectOverflowOptions, Dimensions, ElementContext, ElementOrVirtual, ElementRects, FLIP_NAME,
-    FallbackStrategy, Flip, FlipData, FlipDataOverflow, FlipOptions, HIDE_NAME, Hide, HideData,
-    HideOptions, HideStrategy, INLINE_NAME, Inline, InlineOptions, Length, LimitShift,
-    LimitShiftOffset, LimitShiftOffsetValues, LimitShiftOptions, Middleware, MiddlewareData,
-    MiddlewareReturn, MiddlewareState, MiddlewareVec, MiddlewareWithOptions, OFFSET_NAME, Offset,
-    OffsetData, OffsetOptions, OffsetOptionsValues, Padding, Placement, Rect, RootBoundary,
-    SHIFT_NAME, SIZE_NAME, Shift, ShiftData, ShiftOptions, Side, Size, SizeOptions, Strategy,
-    VirtualElement, auto_update, compute_position, dom,
+    AutoUpdateOptions, Axis, Boundary, ClientPointAxis, ClientRectObject, ComputePositionConfig,
+    ComputePositionError, ComputePositionReturn, ComputePositionSpec, Coords, DefaultLimiter,
+    DefaultVirtualElement, Derivable, DerivableFn, DetectOverflowOptions, Dimensions,
+    ElementContext, ElementOrVirtual, ElementRects, FLIP_NAME, FallbackStrategy, Flip, FlipData,
+    FlipDataOverflow, FlipOptions, FloatingArrowLayout, FloatingArrowOptions, HIDE_NAME, Hide,
+    HideData, HideOptions, HideStrategy, INLINE_NAME, INNER_NAME, Inline, InlineOptions, Inner,
+    InnerData, InnerListState, InnerOptions, Length, LimitShift, LimitShiftOffset,
+    LimitShiftOffsetValues, LimitShiftOptions, LogicalPlacement, Middleware, MiddlewareData,
+    MiddlewareDataError, MiddlewareDataValue, MiddlewareReturn, MiddlewareReturnData,
+    MiddlewareSpec, MiddlewareState, MiddlewareTraceStep, MiddlewareVec, MiddlewareWithOptions,
+    OFFSET_NAME, Offset, OffsetData, OffsetOptions, OffsetOptionsValues, Padding, Placement,
+    PlatformCache, PlatformError, Rect, RootBoundary, SHIFT_NAME, SIZE_NAME, Shift, ShiftData,
+    ShiftOptions, Side, Size, SizeOptions, Strategy, TRANSFORM_ORIGIN_NAME, TransformOrigin,
+    TransformOriginData, TransformOriginOptions, TransitionDuration, TransitionDurationValues,
+    TransitionStatus, TransitionStyles, TransitionStylesFn, TransitionStylesOptions,
+    VirtualElement, apply_inner_data, auto_update, compute_position, compute_positions, dom,
+    get_inner_list_state, track_client_point, try_compute_position, try_compute_positions,
+    virtual_element_from_client_point, virtual_element_from_mouse_event,
+    virtual_element_from_point, virtual_element_from_range, virtual_element_from_selection,
 };
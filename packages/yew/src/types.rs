@@ -1,9 +1,14 @@
 use std::{fmt::Display, ops::Deref, rc::Rc};
 
-use floating_ui_dom::{ElementOrVirtual, Middleware, MiddlewareData, Placement, Strategy};
+use floating_ui_dom::{
+    ClientPointAxis, ElementOrVirtual, LogicalPlacement, Middleware, MiddlewareData, Placement,
+    Strategy, TransitionDuration, TransitionStatus, TransitionStyles, TransitionStylesOptions,
+};
 use web_sys::{Element, Window};
 use yew::{Callback, UseStateHandle};
 
+use crate::use_floating::VirtualElementOrNodeRef;
+
 pub type WhileElementsMountedFn =
     dyn Fn(ElementOrVirtual, &Element, Rc<dyn Fn()>) -> Rc<WhileElementsMountedCleanupFn>;
 
@@ -22,6 +27,13 @@ pub struct UseFloatingOptions {
     /// Defaults to [`Placement::Bottom`].
     pub placement: Option<Placement>,
 
+    /// Writing-mode relative placement, which takes precedence over `placement`.
+    ///
+    /// Resolved with the computed `writing-mode` and `direction` of the floating element.
+    ///
+    /// Defaults to `None`.
+    pub logical_placement: Option<LogicalPlacement>,
+
     /// The strategy to use when positioning the floating element.
     ///
     /// Defaults to [`Strategy::Absolute`].
@@ -37,6 +49,14 @@ pub struct UseFloatingOptions {
     /// Defaults to `true`.
     pub transform: Option<bool>,
 
+    /// Whether to show the floating element in the top layer using the `popover` attribute.
+    ///
+    /// The popover is shown and hidden following `open`, the strategy is forced to [`Strategy::Fixed`]
+    /// and `while_elements_mounted` only runs while the popover is open.
+    ///
+    /// Defaults to `false`.
+    pub popover: Option<bool>,
+
     /// Callback to handle mounting/unmounting of the elements.
     ///
     /// Defaults to [`Option::None`].
@@ -56,6 +76,12 @@ impl UseFloatingOptions {
         self
     }
 
+    /// Set `logical_placement` option.
+    pub fn logical_placement(mut self, value: LogicalPlacement) -> Self {
+        self.logical_placement = Some(value);
+        self
+    }
+
     /// Set `strategy` option.
     pub fn strategy(mut self, value: Strategy) -> Self {
         self.strategy = Some(value);
@@ -74,6 +100,12 @@ impl UseFloatingOptions {
         self
     }
 
+    /// Set `popover` option.
+    pub fn popover(mut self, value: bool) -> Self {
+        self.popover = Some(value);
+        self
+    }
+
     /// Set `while_elements_mounted` option.
     pub fn while_elements_mounted(mut self, value: Rc<WhileElementsMountedFn>) -> Self {
         self.while_elements_mounted = Some(value);
@@ -81,14 +113,67 @@ impl UseFloatingOptions {
     }
 }
 
+/// Options for [`use_client_point`][`crate::use_client_point::use_client_point`].
+#[derive(Clone, Default, PartialEq)]
+pub struct UseClientPointOptions {
+    /// Whether the floating element follows the client point.
+    ///
+    /// Defaults to `true`.
+    pub enabled: Option<bool>,
+
+    /// Represents the open/close state of the floating element. The point is reset when closed.
+    ///
+    /// Defaults to `true`.
+    pub open: Option<bool>,
+
+    /// The axis on which the floating element follows the client point.
+    ///
+    /// Defaults to [`ClientPointAxis::Both`].
+    pub axis: Option<ClientPointAxis>,
+
+    /// Whether to only use the first client point after opening, instead of following the cursor.
+    ///
+    /// Defaults to `false`.
+    pub initial_only: Option<bool>,
+}
+
+impl UseClientPointOptions {
+    /// Set `enabled` option.
+    pub fn enabled(mut self, value: bool) -> Self {
+        self.enabled = Some(value);
+        self
+    }
+
+    /// Set `open` option.
+    pub fn open(mut self, value: bool) -> Self {
+        self.open = Some(value);
+        self
+    }
+
+    /// Set `axis` option.
+    pub fn axis(mut self, value: ClientPointAxis) -> Self {
+        self.axis = Some(value);
+        self
+    }
+
+    /// Set `initial_only` option.
+    pub fn initial_only(mut self, value: bool) -> Self {
+        self.initial_only = Some(value);
+        self
+    }
+}
+
 /// CSS styles to apply to the floating element to position it.
 #[derive(Clone, Debug, PartialEq)]
 pub struct FloatingStyles {
     pub position: Strategy,
+    pub margin: Option<String>,
+    pub inset: Option<String>,
     pub top: String,
     pub left: String,
     pub transform: Option<String>,
     pub will_change: Option<String>,
+    pub transform_origin: Option<String>,
 }
 
 impl FloatingStyles {
@@ -99,6 +184,14 @@ impl FloatingStyles {
         }
     }
 
+    pub fn style_margin(&self) -> Option<String> {
+        self.margin.clone()
+    }
+
+    pub fn style_inset(&self) -> Option<String> {
+        self.inset.clone()
+    }
+
     pub fn style_top(&self) -> String {
         self.top.clone()
     }
@@ -114,17 +207,27 @@ impl FloatingStyles {
     pub fn style_will_change(&self) -> Option<String> {
         self.will_change.clone()
     }
+
+    pub fn style_transform_origin(&self) -> Option<String> {
+        self.transform_origin.clone()
+    }
 }
 
 impl Display for FloatingStyles {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "position: {}; top: {}; left: {};{}{}",
+            "position: {};{}{} top: {}; left: {};{}{}{}",
             match self.position {
                 Strategy::Absolute => "absolute",
                 Strategy::Fixed => "fixed",
             },
+            self.margin
+                .as_ref()
+                .map_or("".to_owned(), |margin| format!(" margin: {margin};")),
+            self.inset
+                .as_ref()
+                .map_or("".to_owned(), |inset| format!(" inset: {inset};")),
             self.top,
             self.left,
             self.transform
@@ -136,6 +239,11 @@ impl Display for FloatingStyles {
                 .as_ref()
                 .map_or("".to_owned(), |will_change| format!(
                     " will-change: {will_change};"
+                )),
+            self.transform_origin
+                .as_ref()
+                .map_or("".to_owned(), |transform_origin| format!(
+                    " transform-origin: {transform_origin};"
                 ))
         )
     }
@@ -168,6 +276,102 @@ pub struct UseFloatingReturn {
     pub update: Callback<()>,
 }
 
+/// Return of [`use_client_point`][crate::use_client_point::use_client_point].
+pub struct UseClientPointReturn {
+    /// The reference to pass to [`use_floating`][crate::use_floating::use_floating].
+    ///
+    /// A virtual element at the client point, or the reference element until the pointer moved over it.
+    pub reference: VirtualElementOrNodeRef,
+}
+
+/// Options for [`use_transition_status`][`crate::use_transition::use_transition_status`].
+#[derive(Clone, Default, PartialEq)]
+pub struct UseTransitionStatusOptions {
+    /// Represents the open/close state of the floating element.
+    ///
+    /// Defaults to `true`.
+    pub open: Option<bool>,
+
+    /// The duration of the transitions. The floating element stays mounted for the close duration.
+    ///
+    /// Defaults to `250` milliseconds for opening and closing.
+    pub duration: Option<TransitionDuration>,
+}
+
+impl UseTransitionStatusOptions {
+    /// Set `open` option.
+    pub fn open(mut self, value: bool) -> Self {
+        self.open = Some(value);
+        self
+    }
+
+    /// Set `duration` option.
+    pub fn duration<D: Into<TransitionDuration>>(mut self, value: D) -> Self {
+        self.duration = Some(value.into());
+        self
+    }
+}
+
+/// Return of [`use_transition_status`][crate::use_transition::use_transition_status].
+pub struct UseTransitionStatusReturn {
+    /// Whether the floating element should be rendered, including while the close transition plays.
+    pub is_mounted: bool,
+
+    /// The status of the transition.
+    pub status: TransitionStatus,
+}
+
+/// Options for [`use_transition_styles`][`crate::use_transition::use_transition_styles`].
+#[derive(Clone, Default, PartialEq)]
+pub struct UseTransitionStylesOptions {
+    /// Represents the open/close state of the floating element.
+    ///
+    /// Defaults to `true`.
+    pub open: Option<bool>,
+
+    /// The final placement of the floating element, passed to the style functions.
+    ///
+    /// Defaults to [`Placement::Bottom`].
+    pub placement: Option<Placement>,
+
+    /// The duration and the styles of the transitions.
+    ///
+    /// Defaults to [`TransitionStylesOptions::default`].
+    pub styles: Option<TransitionStylesOptions>,
+}
+
+impl UseTransitionStylesOptions {
+    /// Set `open` option.
+    pub fn open(mut self, value: bool) -> Self {
+        self.open = Some(value);
+        self
+    }
+
+    /// Set `placement` option.
+    pub fn placement(mut self, value: Placement) -> Self {
+        self.placement = Some(value);
+        self
+    }
+
+    /// Set `styles` option.
+    pub fn styles(mut self, value: TransitionStylesOptions) -> Self {
+        self.styles = Some(value);
+        self
+    }
+}
+
+/// Return of [`use_transition_styles`][crate::use_transition::use_transition_styles].
+pub struct UseTransitionStylesReturn {
+    /// Whether the floating element should be rendered, including while the close transition plays.
+    pub is_mounted: bool,
+
+    /// The status of the transition.
+    pub status: TransitionStatus,
+
+    /// The styles to apply to the floating element, e.g. to its `style` attribute.
+    pub styles: TransitionStyles,
+}
+
 pub struct ShallowRc<T: ?Sized>(Rc<T>);
 
 impl<T: ?Sized> Clone for ShallowRc<T> {
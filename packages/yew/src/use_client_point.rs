@@ -0,0 +1,95 @@
+use std::rc::Rc;
+
+use floating_ui_dom::{
+    Coords, VirtualElement, track_client_point, virtual_element_from_client_point,
+};
+use yew::prelude::*;
+
+use crate::{
+    types::{UseClientPointOptions, UseClientPointReturn},
+    use_floating::VirtualElementOrNodeRef,
+};
+
+/// Positions the floating element at the cursor while the pointer moves over the reference element.
+///
+/// Pass the returned reference to [`use_floating`][crate::use_floating::use_floating].
+#[hook]
+pub fn use_client_point(
+    reference: NodeRef,
+    options: UseClientPointOptions,
+) -> UseClientPointReturn {
+    let enabled = options.enabled.unwrap_or(true);
+    let open = options.open.unwrap_or(true);
+    let axis = options.axis.unwrap_or_default();
+    let initial_only = options.initial_only.unwrap_or(false);
+
+    let point = use_state_eq(|| None::<Coords>);
+    // The state handle in the pointer callback is not updated, so track whether a point was captured separately.
+    let has_point = use_mut_ref(|| false);
+
+    use_effect_with(open, {
+        let point = point.clone();
+        let has_point = has_point.clone();
+
+        move |open| {
+            // Capture a new initial point on the next open.
+            if !open {
+                point.set(None);
+                *has_point.borrow_mut() = false;
+            }
+        }
+    });
+
+    use_effect_with((reference.clone(), enabled, initial_only), {
+        let point = point.clone();
+
+        move |(reference, enabled, initial_only)| {
+            let initial_only = *initial_only;
+
+            let cleanup = enabled
+                .then(|| reference.cast::<web_sys::Element>())
+                .flatten()
+                .map(|reference_element| {
+                    track_client_point(
+                        &reference_element,
+                        Rc::new(move |coords| {
+                            if initial_only && *has_point.borrow() {
+                                return;
+                            }
+
+                            *has_point.borrow_mut() = true;
+                            point.set(Some(coords));
+                        }),
+                    )
+                });
+
+            move || {
+                if let Some(cleanup) = cleanup {
+                    cleanup();
+                }
+            }
+        }
+    });
+
+    let reference = use_memo(
+        (reference, enabled, axis, (*point).clone()),
+        |(reference, enabled, axis, point)| match (
+            enabled,
+            reference.cast::<web_sys::Element>(),
+            point.clone(),
+        ) {
+            (true, Some(reference_element), Some(point)) => VirtualElementOrNodeRef::from(
+                Box::new(virtual_element_from_client_point(
+                    &reference_element,
+                    point,
+                    *axis,
+                )) as Box<dyn VirtualElement<web_sys::Element>>,
+            ),
+            _ => reference.clone().into(),
+        },
+    );
+
+    UseClientPointReturn {
+        reference: (*reference).clone(),
+    }
+}
@@ -2,7 +2,8 @@ use std::{cell::RefCell, rc::Rc};
 
 use floating_ui_dom::{
     ComputePositionConfig, MiddlewareData, OwnedElementOrVirtual, Placement, Strategy,
-    VirtualElement, compute_position,
+    TransformOriginData, VirtualElement, hide_popover, remove_popover, show_popover,
+    try_compute_position, while_popover_open,
 };
 use web_sys::wasm_bindgen::JsCast;
 use yew::{NodeRef, hook, use_callback, use_effect_with, use_memo, use_mut_ref, use_state_eq};
@@ -64,9 +65,21 @@ pub fn use_floating(
     let placement_option = use_memo(options.placement, |placement| {
         placement.unwrap_or(Placement::Bottom)
     });
-    let strategy_option = use_memo(options.strategy, |strategy| {
-        strategy.unwrap_or(Strategy::Absolute)
+    let logical_placement_option = use_memo(options.logical_placement, |logical_placement| {
+        *logical_placement
     });
+    let popover_option = use_memo(options.popover, |popover| popover.unwrap_or(false));
+    let strategy_option = use_memo(
+        (options.strategy, *popover_option),
+        |(strategy, popover)| {
+            if *popover {
+                // The containing block of elements in the top layer is the viewport.
+                Strategy::Fixed
+            } else {
+                strategy.unwrap_or(Strategy::Absolute)
+            }
+        },
+    );
     let transform_option = use_memo(options.transform, |transform| transform.unwrap_or(true));
 
     let x = use_state_eq(|| 0.0);
@@ -79,17 +92,24 @@ pub fn use_floating(
         (
             floating.clone(),
             transform_option,
+            popover_option.clone(),
             x.clone(),
             y.clone(),
             strategy.clone(),
+            middleware_data.clone(),
         ),
-        |(floating, transform_option, x, y, strategy)| {
+        |(floating, transform_option, popover_option, x, y, strategy, middleware_data)| {
             let initial_styles = FloatingStyles {
                 position: **strategy,
+                margin: popover_option.then(|| "0".to_owned()),
+                inset: popover_option.then(|| "auto".to_owned()),
                 top: "0".to_owned(),
                 left: "0".to_owned(),
                 transform: None,
                 will_change: None,
+                transform_origin: middleware_data
+                    .get_typed::<TransformOriginData>()
+                    .map(TransformOriginData::transform_origin),
             };
 
             match floating.get() {
@@ -122,6 +142,7 @@ pub fn use_floating(
             reference.clone(),
             floating.clone(),
             placement_option.clone(),
+            logical_placement_option.clone(),
             strategy_option.clone(),
             middleware_option.clone(),
             x.clone(),
@@ -139,6 +160,7 @@ pub fn use_floating(
                 reference,
                 floating,
                 placement_option,
+                logical_placement_option,
                 strategy_option,
                 middleware_option,
                 x,
@@ -153,19 +175,25 @@ pub fn use_floating(
                 {
                     let config = ComputePositionConfig {
                         placement: Some(**placement_option),
+                        logical_placement: **logical_placement_option,
                         strategy: Some(**strategy_option),
                         middleware: Some((**middleware_option).clone()),
+                        trace: None,
+                        cache: None,
                     };
 
                     let open = *open_option;
 
-                    let position = compute_position(
+                    let Ok(position) = try_compute_position(
                         (&reference_element).into(),
                         floating_element
                             .dyn_ref()
                             .expect("Floating element should be an Element."),
                         config,
-                    );
+                    ) else {
+                        // The elements can not be measured, e.g. because they were removed during teardown.
+                        return;
+                    };
                     x.set(position.x);
                     y.set(position.y);
                     strategy.set(position.strategy);
@@ -181,6 +209,38 @@ pub fn use_floating(
         },
     );
 
+    // The floating element shown as popover, which is removed from the top layer when it changes or the `popover` option is disabled.
+    let popover_element: Rc<RefCell<Option<web_sys::Element>>> = use_mut_ref(|| None);
+
+    let sync_popover = use_callback(
+        (
+            floating.clone(),
+            open_option.clone(),
+            popover_option.clone(),
+            popover_element.clone(),
+        ),
+        |_, (floating, open_option, popover_option, popover_element)| {
+            if **popover_option && let Some(floating_element) = floating.cast::<web_sys::Element>()
+            {
+                if popover_element.borrow().as_ref() != Some(&floating_element)
+                    && let Some(previous_element) = popover_element.take()
+                {
+                    remove_popover(&previous_element);
+                }
+
+                if **open_option {
+                    show_popover(&floating_element);
+                } else {
+                    hide_popover(&floating_element);
+                }
+
+                popover_element.replace(Some(floating_element));
+            } else if let Some(previous_element) = popover_element.take() {
+                remove_popover(&previous_element);
+            }
+        },
+    );
+
     let while_elements_mounted_cleanup: Rc<
         RefCell<Option<ShallowRc<WhileElementsMountedCleanupFn>>>,
     > = use_mut_ref(|| None);
@@ -198,41 +258,66 @@ pub fn use_floating(
         (
             reference.clone(),
             floating.clone(),
+            popover_option,
             while_elements_mounted_option,
             while_elements_mounted_cleanup,
         ),
         {
             let update = update.clone();
             let cleanup = cleanup.clone();
+            let sync_popover = sync_popover.clone();
 
             move |_: (),
                   (
                 reference,
                 floating,
+                popover_option,
                 while_elements_mounted_option,
                 while_elements_mounted_cleanup,
             )| {
                 cleanup.emit(());
+                sync_popover.emit(());
 
                 if let Some(while_elements_mounted) = while_elements_mounted_option {
                     if let Some(reference_element) = reference.get()
-                        && let Some(floating_element) = floating.get()
+                        && let Some(floating_element) = floating.cast::<web_sys::Element>()
                     {
-                        while_elements_mounted_cleanup.replace(Some(ShallowRc::from(
-                            (**while_elements_mounted)(
-                                (&reference_element).into(),
-                                floating_element
-                                    .dyn_ref()
-                                    .expect("Floating element should be an Element."),
-                                Rc::new({
-                                    let update = update.clone();
-
-                                    move || {
-                                        update.emit(());
-                                    }
-                                }),
-                            ),
-                        )));
+                        let update: Rc<dyn Fn()> = Rc::new({
+                            let update = update.clone();
+
+                            move || {
+                                update.emit(());
+                            }
+                        });
+
+                        let while_elements_mounted_cleanup_fn: Rc<WhileElementsMountedCleanupFn> =
+                            if **popover_option {
+                                let reference_element = Rc::new(reference_element);
+                                let open_floating_element = floating_element.clone();
+                                let while_elements_mounted = while_elements_mounted.clone();
+
+                                Rc::from(while_popover_open(
+                                    &floating_element,
+                                    Rc::new(move || {
+                                        let cleanup = (**while_elements_mounted)(
+                                            (&*reference_element).into(),
+                                            &open_floating_element,
+                                            update.clone(),
+                                        );
+
+                                        Box::new(move || cleanup())
+                                    }),
+                                ))
+                            } else {
+                                (**while_elements_mounted)(
+                                    (&reference_element).into(),
+                                    &floating_element,
+                                    update,
+                                )
+                            };
+
+                        while_elements_mounted_cleanup
+                            .replace(Some(ShallowRc::from(while_elements_mounted_cleanup_fn)));
                     }
                 } else {
                     update.emit(());
@@ -254,11 +339,14 @@ pub fn use_floating(
         (
             open_option.clone(),
             placement_option,
+            logical_placement_option,
             strategy_option,
             middleware_option,
             update.clone(),
+            sync_popover,
         ),
-        |(_, _, _, _, update)| {
+        |(_, _, _, _, _, update, sync_popover)| {
+            sync_popover.emit(());
             update.emit(());
         },
     );
@@ -274,6 +362,10 @@ pub fn use_floating(
     use_effect_with((), move |_| {
         move || {
             cleanup.emit(());
+
+            if let Some(popover_element) = popover_element.take() {
+                remove_popover(&popover_element);
+            }
         }
     });
 
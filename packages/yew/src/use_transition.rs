@@ -0,0 +1,140 @@
+use floating_ui_dom::{
+    Placement, TransitionStatus, get_initial_transition_styles, get_transition_styles,
+    schedule::{request_animation_frame, set_timeout},
+};
+use yew::prelude::*;
+
+use crate::types::{
+    UseTransitionStatusOptions, UseTransitionStatusReturn, UseTransitionStylesOptions,
+    UseTransitionStylesReturn,
+};
+
+/// Provides the status of the transition to apply CSS transitions to a floating element, correctly handling placement-aware transitions.
+///
+/// The floating element should only be rendered while `is_mounted` is `true`, so it stays mounted while the close transition plays.
+#[hook]
+pub fn use_transition_status(
+    floating: NodeRef,
+    options: UseTransitionStatusOptions,
+) -> UseTransitionStatusReturn {
+    let open = options.open.unwrap_or(true);
+    let close_duration = options.duration.unwrap_or_default().close();
+
+    let delayed_is_mounted = use_state_eq(|| open);
+    let status = use_state_eq(|| TransitionStatus::Unmounted);
+
+    let is_mounted = open || *delayed_is_mounted;
+
+    // Delay unmounting until the close transition has finished.
+    use_effect_with((open, close_duration), {
+        let delayed_is_mounted = delayed_is_mounted.clone();
+
+        move |(open, close_duration)| {
+            let cancel = if *open {
+                delayed_is_mounted.set(true);
+                None
+            } else {
+                Some(set_timeout(
+                    move || delayed_is_mounted.set(false),
+                    *close_duration,
+                ))
+            };
+
+            move || {
+                if let Some(cancel) = cancel {
+                    cancel();
+                }
+            }
+        }
+    });
+
+    use_effect_with(is_mounted, {
+        let status = status.clone();
+
+        move |is_mounted| {
+            if !is_mounted && *status == TransitionStatus::Close {
+                status.set(TransitionStatus::Unmounted);
+            }
+        }
+    });
+
+    // The floating element is rendered once mounted, so the effect runs again when it is available.
+    use_effect_with((open, is_mounted), {
+        let status = status.clone();
+
+        move |(open, _)| {
+            let cancel = floating.get().and_then(|_| {
+                if *open {
+                    status.set(TransitionStatus::Initial);
+
+                    Some(request_animation_frame(move || {
+                        status.set(TransitionStatus::Open)
+                    }))
+                } else {
+                    status.set(TransitionStatus::Close);
+                    None
+                }
+            });
+
+            move || {
+                if let Some(cancel) = cancel {
+                    cancel();
+                }
+            }
+        }
+    });
+
+    UseTransitionStatusReturn {
+        is_mounted,
+        status: if !is_mounted && *status == TransitionStatus::Close {
+            TransitionStatus::Unmounted
+        } else {
+            *status
+        },
+    }
+}
+
+/// Provides styles to apply CSS transitions to a floating element, correctly handling placement-aware transitions.
+///
+/// The floating element should only be rendered while `is_mounted` is `true`, so it stays mounted while the close transition plays.
+#[hook]
+pub fn use_transition_styles(
+    floating: NodeRef,
+    options: UseTransitionStylesOptions,
+) -> UseTransitionStylesReturn {
+    let placement = options.placement.unwrap_or(Placement::Bottom);
+    let styles_options = options.styles.unwrap_or_default();
+
+    let UseTransitionStatusReturn { is_mounted, status } = use_transition_status(
+        floating,
+        UseTransitionStatusOptions {
+            open: options.open,
+            duration: styles_options.duration,
+        },
+    );
+
+    let styles = use_state_eq({
+        let styles_options = styles_options.clone();
+
+        move || get_initial_transition_styles(placement, &styles_options)
+    });
+
+    use_effect_with((status, placement, styles_options), {
+        let styles = styles.clone();
+
+        move |(status, placement, styles_options)| {
+            styles.set(get_transition_styles(
+                *status,
+                *placement,
+                styles_options,
+                &styles,
+            ));
+        }
+    });
+
+    UseTransitionStylesReturn {
+        is_mounted,
+        status,
+        styles: (*styles).clone(),
+    }
+}
@@ -1,8 +1,8 @@
 use convert_case::{Case, Casing};
 use floating_ui_yew::{
-    ARROW_NAME, Arrow as ArrowMiddleware, ArrowData, ArrowOptions, DetectOverflowOptions,
-    MiddlewareVec, Offset, OffsetOptions, Padding, Placement, Shift, ShiftOptions, Side,
-    UseFloatingOptions, UseFloatingReturn, use_auto_update, use_floating,
+    Arrow as ArrowMiddleware, ArrowData, ArrowOptions, DetectOverflowOptions, MiddlewareVec,
+    Offset, OffsetOptions, Padding, Placement, Shift, ShiftOptions, Side, UseFloatingOptions,
+    UseFloatingReturn, use_auto_update, use_floating,
 };
 use yew::prelude::*;
 
@@ -67,8 +67,7 @@ pub fn Arrow() -> Html {
     let static_side = resultant_placement.side().opposite();
 
     let arrow_data = use_memo(middleware_data, |middleware_data| {
-        let arrow_data: Option<ArrowData> = middleware_data.get_as(ARROW_NAME);
-        arrow_data
+        middleware_data.get_typed::<ArrowData>().cloned()
     });
     let arrow_x = use_memo(arrow_data.clone(), |arrow_data| {
         arrow_data